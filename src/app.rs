@@ -1,10 +1,30 @@
-use crate::config::Config;
+use crate::command::Command;
+use crate::config::{AvrVolumeUnit, Bookmark, Config, DeviceConfig, HeosVolumeUnit};
+use crate::event::KeyChord;
+use crate::heos::protocol::PLAYLISTS_SID;
 use crate::heos::{
-    AvrEvent, AvrHandle, BrowseItem, HeosEvent, HeosHandle, MusicSource, MuteState,
-    NowPlayingMedia, PlayState, Player, PlayerState, QueueItem, RepeatMode, ShuffleMode,
-    SurroundMode,
+    AvrEvent, AvrHandle, AvrVolume, AvrZone, BrowseItem, DiscoveredDevice, DiscoveryProgress,
+    DynamicCompression, DynamicVolume, EcoMode, Group, HdmiMonitor, HeosError, HeosEvent,
+    HeosHandle, ImaxMode, InputSource, MultEq, MusicSource, MuteState, NowPlayingMedia, PlayState,
+    Player, PlayerState, QueueItem, QuickSelect, QuickSelectSlot, RepeatMode, Restorer,
+    SearchCriteria, ServiceOption, ShuffleMode, SignalField, SurroundMode, SurroundParameter, Tone,
+    Upmixer, REFERENCE_LEVEL_OFFSETS,
 };
+use crate::history::History;
+use crate::i18n::{Messages, Msg};
+use crate::playlist::{self, LocalPlaylist, PlaylistEntry, LOCAL_PLAYLISTS_SID};
+use crate::plugin::PluginHost;
+use crate::stats::Stats;
+use crate::ui::{self, audyssey::AudysseySetting, theme::Theme};
 use anyhow::Result;
+use chrono::{DateTime, Local};
+use ratatui::style::Color;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::process::{self, Stdio};
+use std::time::{Duration, Instant};
+use tokio::task::AbortHandle;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum View {
@@ -14,11 +34,205 @@ pub enum View {
     Queue,
     Browse,
     Inputs,
+    AvrInputs,
+    HdmiMonitor,
     SurroundModes,
+    Upmixers,
+    GroupMixer,
+    Groups,
     SoundSettings,
+    DynamicCompression,
+    SleepTimer,
+    Audyssey,
+    DynamicVolume,
+    ToneSlider,
+    AvrPresets,
+    AvrQuickSelect,
+    Bookmarks,
+    PluginActions,
+    NowPlaying,
+    Stats,
+    History,
+    Favorites,
+    QuickSelects,
+    PlayerInfo,
+    SearchCriteria,
+    BrowseMenu,
+    ServiceOptions,
     Help,
 }
 
+/// What a soft volume ramp ends in, with `ui.soft_volume`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fade {
+    Mute,
+    Unmute,
+    Stop,
+}
+
+/// How a player relates to others, shown next to it in Devices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerRole {
+    Alone,
+    GroupLeader,
+    GroupMember,
+    /// The half of a stereo pair that plays and takes commands
+    PairPrimary,
+    /// The other half, controlled through the primary
+    PairSecondary { primary: i64 },
+}
+
+/// A yes/no question shown over the current view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confirm {
+    ClearQueue,
+    AvrStandby,
+}
+
+impl Confirm {
+    pub fn question(&self) -> Msg {
+        match self {
+            Confirm::ClearQueue => Msg::ConfirmClearQueue,
+            Confirm::AvrStandby => Msg::ConfirmAvrStandby,
+        }
+    }
+}
+
+/// What the text entered in a prompt is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    Command,
+    ExportQueue,
+    ImportUrls,
+    SignInUser,
+    SignInPassword,
+    Search,
+    Volume,
+    PlayUrl,
+}
+
+impl PromptKind {
+    pub fn title(&self) -> Msg {
+        match self {
+            PromptKind::Command => Msg::PromptCommand,
+            PromptKind::ExportQueue => Msg::PromptExportQueue,
+            PromptKind::ImportUrls => Msg::PromptImportUrls,
+            PromptKind::SignInUser => Msg::PromptSignInUser,
+            PromptKind::SignInPassword => Msg::PromptSignInPassword,
+            PromptKind::Search => Msg::PromptSearch,
+            PromptKind::Volume => Msg::PromptVolume,
+            PromptKind::PlayUrl => Msg::PromptPlayUrl,
+        }
+    }
+
+    /// Input is hidden on screen
+    pub fn is_secret(&self) -> bool {
+        matches!(self, PromptKind::SignInPassword)
+    }
+}
+
+/// A single-line text input shown over the current view
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    pub kind: PromptKind,
+    pub input: String,
+}
+
+/// Services whose stations take thumbs up/down: Pandora and iHeartRadio
+const THUMBS_SIDS: [i64; 2] = [1, 7];
+
+/// Tracks of a queue carried over by [`App::transfer_playback`]
+const TRANSFER_QUEUE_LIMIT: u32 = 100;
+
+/// Items left below the selection when the next browse page is requested
+const BROWSE_PREFETCH_MARGIN: usize = 20;
+
+/// How much of the open browse container is loaded; only set for HEOS sources
+#[derive(Debug, Clone)]
+pub struct BrowsePaging {
+    pub sid: i64,
+    pub cid: Option<String>,
+    /// Items in the whole container
+    pub total: usize,
+    /// A follow-up page has been requested
+    pub loading: bool,
+}
+
+/// One level of the browse hierarchy; `cid` is `None` at the top of a source
+#[derive(Debug, Clone)]
+pub struct BrowseLevel {
+    pub sid: i64,
+    pub cid: Option<String>,
+    pub name: String,
+    /// Criterion `scid` and query of a level holding search results
+    pub search: Option<(i64, String)>,
+}
+
+impl BrowseLevel {
+    fn key(&self) -> BrowseKey {
+        (self.sid, self.cid.clone(), self.search.clone())
+    }
+}
+
+/// Identifies a browsed list in the cache: `sid`, `cid` and any search
+type BrowseKey = (i64, Option<String>, Option<(i64, String)>);
+
+/// A browse level as it was left, so going back to it needs no refetch
+#[derive(Debug, Clone)]
+pub struct CachedBrowse {
+    items: Vec<BrowseItem>,
+    selected: usize,
+    paging: Option<BrowsePaging>,
+    service_options: Vec<ServiceOption>,
+    fetched: Instant,
+}
+
+/// What to do with a playable Browse item, offered when it's selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowseAction {
+    /// Browse into the container instead
+    Open,
+    PlayNow,
+    PlayNext,
+    AddToEnd,
+    ReplaceAndPlay,
+    /// Replace the queue with the whole playlist and start at this track
+    PlayFromHere,
+}
+
+impl BrowseAction {
+    pub fn label(&self) -> Msg {
+        match self {
+            BrowseAction::Open => Msg::BrowseActionOpen,
+            BrowseAction::PlayNow => Msg::BrowseActionPlayNow,
+            BrowseAction::PlayNext => Msg::BrowseActionPlayNext,
+            BrowseAction::AddToEnd => Msg::BrowseActionAddToEnd,
+            BrowseAction::ReplaceAndPlay => Msg::BrowseActionReplaceAndPlay,
+            BrowseAction::PlayFromHere => Msg::BrowseActionPlayFromHere,
+        }
+    }
+
+    /// Status shown once done, formatted with the item name
+    pub fn done(&self) -> Msg {
+        match self {
+            BrowseAction::PlayNext => Msg::QueuedNext,
+            BrowseAction::AddToEnd => Msg::ContainerQueued,
+            _ => Msg::ContainerPlaying,
+        }
+    }
+
+    /// `aid` of `browse/add_to_queue`
+    fn aid(&self) -> Option<u8> {
+        match self {
+            BrowseAction::PlayNow => Some(1),
+            BrowseAction::PlayNext => Some(2),
+            BrowseAction::AddToEnd => Some(3),
+            BrowseAction::ReplaceAndPlay => Some(4),
+            BrowseAction::Open | BrowseAction::PlayFromHere => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConnectionState {
     Disconnected,
@@ -26,52 +240,304 @@ pub enum ConnectionState {
     Connected,
 }
 
+/// Queue edits kept for undo
+const QUEUE_UNDO_LIMIT: usize = 10;
+
+/// The most items one `get_queue` returns; a loaded queue this long may hold more
+const QUEUE_PAGE: usize = 100;
+
+/// Where a queued track came from; `add_to_queue` needs both ids to add it again
+#[derive(Debug, Clone)]
+pub struct QueueSource {
+    pub sid: i64,
+    pub cid: String,
+}
+
+/// Tracks taken out of the queue by one edit, in queue order
+#[derive(Debug, Clone)]
+pub struct QueueUndo {
+    pub tracks: Vec<RemovedTrack>,
+}
+
+/// A track taken out of the queue, with what it takes to put it back
+#[derive(Debug, Clone)]
+pub struct RemovedTrack {
+    /// Position in the queue, from 0
+    pub position: usize,
+    pub mid: String,
+    /// None for tracks queued elsewhere, which can't be put back
+    pub source: Option<QueueSource>,
+}
+
+/// How long a toast stays in the status bar
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// A short-lived notice that takes over the status bar until it expires
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub until: Instant,
+}
+
+/// Startup discovery progress, shown in the main view while discovering
+#[derive(Debug, Clone)]
+pub struct DiscoveryStatus {
+    pub started: Instant,
+    pub round: u32,
+    /// Search targets an M-SEARCH has gone out for this round
+    pub targets_sent: Vec<&'static str>,
+    /// Addresses of devices that answered, across all rounds
+    pub responders: Vec<String>,
+    /// Stops the discovery/connect task when discovery is cancelled
+    pub task: Option<AbortHandle>,
+}
+
+impl Default for DiscoveryStatus {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            round: 0,
+            targets_sent: Vec::new(),
+            responders: Vec::new(),
+            task: None,
+        }
+    }
+}
+
 /// AVR-specific state
 #[derive(Debug, Clone, Default)]
 pub struct AvrState {
     pub connected: bool,
+    /// Connected through the web interface, with polled status
+    pub http_fallback: bool,
     pub power: bool,
-    pub master_volume: u8,
+    pub master_volume: AvrVolume,
+    /// The receiver's volume limit, once reported
+    pub max_volume: Option<AvrVolume>,
     pub muted: bool,
     pub surround_mode: String,
     pub input_source: String,
+    /// Inputs in the order the receiver listed them
+    pub inputs: Vec<AvrInput>,
+    /// Zone targeted by the AVR volume keys
+    pub zone: AvrZone,
+    /// Quick select memory the current settings came from
+    pub quick_select: Option<QuickSelect>,
+    /// Minutes the sleep timer had left and when the AVR reported it
+    pub sleep_timer: Option<(u16, Instant)>,
+    /// Zone 2 and 3 as reported; a receiver without the zone never answers for it
+    pub zones: HashMap<AvrZone, ZoneStatus>,
+    /// Tone and subwoofer levels in dB, once the AVR has reported them
+    pub bass: Option<f32>,
+    pub treble: Option<f32>,
+    pub subwoofer_level: Option<f32>,
+    /// Dialog Enhancer level, 0 when off
+    pub dialog_enhancer: Option<u8>,
+    /// Bass Sync level, once the AVR has reported it
+    pub bass_sync: Option<u8>,
+    pub loudness_management: Option<bool>,
+    pub cinema_eq: Option<bool>,
+    pub lfc: Option<bool>,
+    pub lfc_amount: Option<u8>,
+    /// LFE level in dB, 0 to -10
+    pub lfe_level: Option<i8>,
+    pub dynamic_compression: Option<DynamicCompression>,
+    pub headphones: bool,
+    /// `None` until the AVR reports it, i.e. on receivers without IMAX Enhanced
+    pub imax: Option<ImaxMode>,
+    /// `None` until the AVR reports it, i.e. on receivers without ECO mode
+    pub eco: Option<EcoMode>,
+    /// `None` until the AVR reports it, i.e. on receivers without a Restorer
+    pub restorer: Option<Restorer>,
+    /// `None` until the AVR reports it, i.e. on receivers with one HDMI output
+    pub hdmi_monitor: Option<HdmiMonitor>,
+    // Audyssey, queried when its popup opens
+    pub multeq: Option<MultEq>,
+    pub dynamic_eq: Option<bool>,
+    pub reference_level: Option<u8>,
+    pub dynamic_volume: Option<DynamicVolume>,
+    /// Audio and video signal details, reported while the AVR panel is expanded
+    pub signal_info: HashMap<SignalField, String>,
+    /// DSP parameters the current surround mode reported
+    pub surround_parameters: HashMap<SurroundParameter, String>,
+}
+
+/// An AVR input source as set up on the receiver
+#[derive(Debug, Clone, Default)]
+pub struct AvrInput {
+    /// Code sent after `SI`, e.g. "SAT/CBL"
+    pub code: String,
+    /// The user's name for it; the code until the receiver reports one
+    pub name: String,
+    pub hidden: bool,
+}
+
+/// Power and volume of an extra AVR zone
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZoneStatus {
+    pub power: bool,
+    pub volume: Option<AvrVolume>,
 }
 
 pub struct App {
     pub config: Config,
+    pub theme: Theme,
+    pub messages: Messages,
     pub connection_state: ConnectionState,
     pub current_view: View,
     pub previous_view: View,
     pub should_quit: bool,
     pub status_message: Option<String>,
+    pub toast: Option<Toast>,
+    /// Errors reported so far and the latest one, for event notifications
+    pub error_count: u64,
+    pub last_error: Option<String>,
+    /// Link shown in the status bar, opened if the open-link key is pressed again
+    pub pending_link: Option<String>,
+
+    // Devices found on the network and the host the HEOS connection uses
+    pub network_devices: Vec<DiscoveredDevice>,
+    pub connected_host: Option<String>,
+    /// Host the AVR control connection was made to, for reconnecting
+    pub avr_host: Option<String>,
+    /// HEOS account the device is signed in to
+    pub signed_in_user: Option<String>,
+    /// Username typed at the sign-in prompt, while the password is asked for
+    pub sign_in_user: Option<String>,
+    pub discovery: DiscoveryStatus,
+    /// Running volume ramp and the level it started from
+    pub volume_fade: Option<(AbortHandle, u8)>,
 
     // Player state (HEOS)
     pub players: Vec<Player>,
     pub current_player_idx: usize,
+    /// Player selected last time, kept in the state dir for the next start
+    pub last_pid: Option<i64>,
+    /// Last known state of every player by pid, so switching is instant and the
+    /// device list can show what each room is doing
+    pub player_states: HashMap<i64, PlayerState>,
+    /// Waiting for play states to switch to the playing player at startup
+    pub auto_select_pending: bool,
     pub player_state: PlayerState,
+    /// When the current track/station started playing, reset on media change
+    pub now_playing_since: Option<Instant>,
+    /// When the last progress event arrived, to advance the position between events
+    pub progress_at: Option<Instant>,
+
+    // Groups and their volumes, for the mixer and device list
+    pub groups: Vec<Group>,
+    pub group_volumes: HashMap<i64, u8>,
+    /// Group picked in the Groups view; volume keys then act on the whole group
+    pub group_target: Option<i64>,
+    pub group_selected: usize,
+    /// Focused gauge in the group mixer: 0 is the group, then each member
+    pub mixer_focus: usize,
+
+    // Clock, refreshed on every tick
+    pub clock: DateTime<Local>,
+    pub last_tick: Instant,
 
     // Queue
     pub queue: Vec<QueueItem>,
     pub queue_selected: usize,
+    /// Imported stream URLs or local playlist entries still to play, in order
+    pub stream_queue: VecDeque<PlaylistEntry>,
+    /// Removed or cleared queue items, most recent last
+    pub queue_undo: Vec<QueueUndo>,
+    /// The edit an undo is re-adding; it goes back on `queue_undo` if none of it made it
+    pub queue_undo_pending: Option<QueueUndo>,
+    /// Source of each track queued from Browse while running, by mid; queue items
+    /// don't say which source they came from
+    pub queue_sources: HashMap<String, QueueSource>,
 
     // Browse
     pub music_sources: Vec<MusicSource>,
     pub browse_items: Vec<BrowseItem>,
     pub browse_selected: usize,
-    pub browse_stack: Vec<(i64, String)>, // (sid, cid) history
+    pub browse_stack: Vec<BrowseLevel>,
+    pub browse_paging: Option<BrowsePaging>,
+    /// Levels browsed before, kept for `ui.browse_cache_ttl` seconds
+    pub browse_cache: HashMap<BrowseKey, CachedBrowse>,
+    /// When the shown browse list was fetched
+    pub browse_fetched: Instant,
+    pub local_playlists: Vec<LocalPlaylist>,
+
+    /// Player and media that failed to play, offered for a retry
+    pub failed_playback: Option<(i64, NowPlayingMedia)>,
+
+    // Details of a device, fetched when its info popup opens
+    pub player_info: Option<Player>,
+
+    // Quick selects of the current player
+    pub quickselects: Vec<QuickSelectSlot>,
+    pub quickselect_selected: usize,
+
+    // Options for the selected playable Browse item
+    pub browse_menu: Vec<BrowseAction>,
+    pub browse_menu_selected: usize,
+
+    // Service options sent with the open browse container
+    pub service_options: Vec<ServiceOption>,
+    pub service_option_selected: usize,
+
+    // HEOS Favorites, in preset order
+    pub favorites: Vec<BrowseItem>,
+    pub favorite_selected: usize,
+
+    // Search: the source being searched and how it can be searched
+    pub search_sid: Option<i64>,
+    pub search_criteria: Vec<SearchCriteria>,
+    pub search_criteria_selected: usize,
+
+    // Plugins: extra browse roots and actions
+    pub plugins: PluginHost,
+
+    // Listening statistics, fed by state changes
+    pub stats: Stats,
+    // Recently played media, newest shown first
+    pub history: History,
+    pub history_selected: usize,
+    pub plugin_action_selected: usize,
+    pub bookmark_selected: usize,
 
     // Inputs
     pub inputs: Vec<MusicSource>,
+    /// Physical inputs of every player, the current player's first
+    pub input_sources: Vec<InputSource>,
     pub input_selected: usize,
 
-    // Device selection
+    // Text input popup, takes all key presses while open
+    pub prompt: Option<Prompt>,
+
+    // Yes/no popup, takes all key presses while open
+    pub confirm: Option<Confirm>,
+
+    // Digits typed in a list, applied as a 1-based item number on Enter
+    pub jump_input: String,
+
+    // Multi-key sequence in progress
+    pub chord: KeyChord,
+
+    // Device selection, and players marked for grouping
     pub device_selected: usize,
+    pub device_marks: Vec<i64>,
 
     // Surround mode selection
     pub surround_selected: usize,
+    pub upmixer_selected: usize,
+    pub avr_preset_selected: usize,
 
     // Sound settings selection
     pub sound_setting_selected: usize,
+    pub compression_selected: usize,
+    pub sleep_timer_selected: usize,
+    pub audyssey_selected: usize,
+    pub dynamic_volume_selected: usize,
+    /// Bass or treble being adjusted and its pending level in dB
+    pub tone_slider: Option<(Tone, i8)>,
+    pub avr_quick_select_selected: usize,
+    pub avr_input_selected: usize,
+    pub hdmi_monitor_selected: usize,
 
     // HEOS client handle
     handle: Option<HeosHandle>,
@@ -79,34 +545,105 @@ pub struct App {
     // AVR control handle and state
     avr_handle: Option<AvrHandle>,
     pub avr_state: AvrState,
+    /// Show the signal info line in the AVR panel
+    pub avr_panel_expanded: bool,
 }
 
 impl App {
     pub fn new(config: Config) -> Self {
         Self {
+            theme: Theme::from_preset(config.ui.theme),
+            messages: Messages::for_locale(&config.ui.locale),
             config,
             connection_state: ConnectionState::Disconnected,
             current_view: View::Main,
             previous_view: View::Main,
             should_quit: false,
             status_message: None,
+            toast: None,
+            error_count: 0,
+            last_error: None,
+            pending_link: None,
+            network_devices: Vec::new(),
+            connected_host: None,
+            avr_host: None,
+            signed_in_user: None,
+            sign_in_user: None,
+            discovery: DiscoveryStatus::default(),
+            volume_fade: None,
             players: Vec::new(),
             current_player_idx: 0,
+            last_pid: load_last_pid(),
+            player_states: HashMap::new(),
+            auto_select_pending: false,
             player_state: PlayerState::default(),
+            now_playing_since: None,
+            progress_at: None,
+            groups: Vec::new(),
+            group_volumes: HashMap::new(),
+            group_target: None,
+            group_selected: 0,
+            mixer_focus: 0,
+            clock: Local::now(),
+            last_tick: Instant::now(),
             queue: Vec::new(),
             queue_selected: 0,
+            stream_queue: VecDeque::new(),
+            queue_undo: Vec::new(),
+            queue_undo_pending: None,
+            queue_sources: HashMap::new(),
             music_sources: Vec::new(),
             browse_items: Vec::new(),
             browse_selected: 0,
             browse_stack: Vec::new(),
+            browse_paging: None,
+            browse_cache: HashMap::new(),
+            browse_fetched: Instant::now(),
+            failed_playback: None,
+            player_info: None,
+            quickselects: Vec::new(),
+            quickselect_selected: 0,
+            browse_menu: Vec::new(),
+            browse_menu_selected: 0,
+            service_options: Vec::new(),
+            service_option_selected: 0,
+            favorites: Vec::new(),
+            favorite_selected: 0,
+            search_sid: None,
+            search_criteria: Vec::new(),
+            search_criteria_selected: 0,
+            local_playlists: Vec::new(),
+            plugins: PluginHost::default(),
+            stats: Stats::default(),
+            history: History::default(),
+            history_selected: 0,
+            plugin_action_selected: 0,
+            bookmark_selected: 0,
             inputs: Vec::new(),
+            input_sources: Vec::new(),
             input_selected: 0,
+            prompt: None,
+            confirm: None,
+            jump_input: String::new(),
+            chord: KeyChord::default(),
             device_selected: 0,
+            device_marks: Vec::new(),
             surround_selected: 0,
+            upmixer_selected: 0,
+            avr_preset_selected: 0,
             sound_setting_selected: 0,
+            compression_selected: 0,
+            sleep_timer_selected: 0,
+            audyssey_selected: 0,
+            dynamic_volume_selected: 0,
+            tone_slider: None,
+            avr_quick_select_selected: 0,
+            avr_input_selected: 0,
+            hdmi_monitor_selected: 0,
             handle: None,
             avr_handle: None,
             avr_state: AvrState::default(),
+            avr_panel_expanded: false,
         }
     }
 
@@ -136,10 +673,55 @@ impl App {
         self.status_message = Some(msg.into());
     }
 
+    /// Show an error in the status bar using the localized "Error: ..." template
+    pub fn set_error(&mut self, err: impl Display) {
+        let message = err.to_string();
+        self.set_status(self.messages.format(Msg::Error, &[&message]));
+        self.error_count += 1;
+        self.last_error = Some(message);
+    }
+
+    /// Stop a running startup discovery; returns false when none is running
+    pub fn cancel_discovery(&mut self) -> bool {
+        if self.connection_state != ConnectionState::Discovering {
+            return false;
+        }
+        if let Some(task) = self.discovery.task.take() {
+            task.abort();
+        }
+        self.connection_state = ConnectionState::Disconnected;
+        self.set_status(self.messages.get(Msg::DiscoveryCancelled));
+        true
+    }
+
     pub fn clear_status(&mut self) {
         self.status_message = None;
     }
 
+    pub fn show_toast(&mut self, text: impl Into<String>) {
+        self.toast = Some(Toast {
+            text: text.into(),
+            until: Instant::now() + TOAST_DURATION,
+        });
+    }
+
+    /// The toast to show, if one hasn't expired yet
+    pub fn active_toast(&self) -> Option<&str> {
+        self.toast.as_ref().map(|toast| toast.text.as_str())
+    }
+
+    pub fn tick(&mut self) {
+        self.clock = Local::now();
+        self.last_tick = Instant::now();
+        if self
+            .toast
+            .as_ref()
+            .is_some_and(|t| t.until <= self.last_tick)
+        {
+            self.toast = None;
+        }
+    }
+
     pub fn show_view(&mut self, view: View) {
         if self.current_view != view {
             self.previous_view = self.current_view;
@@ -149,251 +731,2091 @@ impl App {
 
     pub fn go_back(&mut self) {
         match self.current_view {
-            View::Help | View::Devices | View::Queue | View::Inputs
-            | View::SurroundModes | View::SoundSettings => {
+            View::Help
+            | View::Devices
+            | View::Queue
+            | View::Inputs
+            | View::AvrInputs
+            | View::HdmiMonitor
+            | View::SurroundModes
+            | View::Upmixers
+            | View::GroupMixer
+            | View::Groups
+            | View::AvrPresets
+            | View::AvrQuickSelect
+            | View::SoundSettings
+            | View::Bookmarks
+            | View::PluginActions
+            | View::NowPlaying
+            | View::Favorites
+            | View::QuickSelects
+            | View::Stats
+            | View::History => {
                 self.current_view = View::Main;
             }
+            View::DynamicCompression | View::SleepTimer | View::Audyssey | View::ToneSlider => {
+                self.current_view = View::SoundSettings;
+            }
+            View::DynamicVolume => {
+                self.current_view = View::Audyssey;
+            }
+            View::PlayerInfo => {
+                self.current_view = View::Devices;
+            }
+            View::SearchCriteria | View::BrowseMenu | View::ServiceOptions => {
+                self.current_view = View::Browse;
+            }
             View::Browse => {
-                if self.browse_stack.is_empty() {
-                    self.current_view = View::Main;
-                } else {
-                    self.browse_stack.pop();
-                }
+                // Levels below the sources go back with `browse_back`
+                self.current_view = View::Main;
             }
             View::Main => {}
         }
     }
 
+    /// Switch both volume readouts between raw levels and dB; returns true when now in dB
+    pub fn toggle_volume_units(&mut self) -> bool {
+        let ui = &mut self.config.ui;
+        let to_db = ui.heos_volume_unit == HeosVolumeUnit::Percent;
+        if to_db {
+            ui.heos_volume_unit = HeosVolumeUnit::Db;
+            ui.avr_volume_unit = AvrVolumeUnit::Db;
+        } else {
+            ui.heos_volume_unit = HeosVolumeUnit::Percent;
+            ui.avr_volume_unit = AvrVolumeUnit::Absolute;
+        }
+        to_db
+    }
+
+    pub fn heos_volume_text(&self) -> String {
+        ui::volume::heos(self.player_state.volume, self.config.ui.heos_volume_unit)
+    }
+
+    pub fn avr_volume_text(&self) -> String {
+        ui::volume::avr(self.avr_state.master_volume, self.config.ui.avr_volume_unit)
+    }
+
+    pub fn open_prompt(&mut self, kind: PromptKind, input: impl Into<String>) {
+        self.prompt = Some(Prompt {
+            kind,
+            input: input.into(),
+        });
+    }
+
+    /// Write the loaded queue to a playlist file; returns the path written
+    pub fn export_queue(&self, input: &str) -> Result<PathBuf> {
+        let path = playlist::expand_path(input.trim());
+        playlist::export_queue(&path, &self.queue)?;
+        Ok(path)
+    }
+
+    /// Start playing the stream URLs listed in a file; returns how many were found
+    pub async fn import_url_list(&mut self, input: &str) -> Result<usize> {
+        let path = playlist::expand_path(input.trim());
+        let urls = playlist::read_url_list(&path)?;
+        let count = urls.len();
+        self.stream_queue = urls.into_iter().map(PlaylistEntry::from_url).collect();
+        self.play_next_stream().await?;
+        Ok(count)
+    }
+
+    /// Play the next imported stream URL or playlist entry; returns false when none are left
+    pub async fn play_next_stream(&mut self) -> Result<bool> {
+        let Some(entry) = self.stream_queue.pop_front() else {
+            return Ok(false);
+        };
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            match (&entry.url, entry.sid, &entry.cid, &entry.mid) {
+                (Some(url), _, _, _) => handle.play_url(pid, url).await?,
+                (None, Some(sid), Some(cid), _) => handle.replace_queue(pid, sid, cid).await?,
+                (None, Some(sid), None, Some(mid)) => {
+                    handle
+                        .play_station(pid, sid, None, mid, &entry.name)
+                        .await?
+                }
+                _ => anyhow::bail!("Playlist entry '{}' has nothing to play", entry.name),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Replace the queue with a container (a HEOS playlist, an album) and play it from
+    /// track `start` on
+    pub async fn play_container(&self, sid: i64, cid: &str, start: usize) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.replace_queue(pid, sid, cid).await?;
+            if start > 0 {
+                // The new queue is numbered from 1 in container order
+                handle.play_queue_item(pid, start as i64 + 1).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn append_container(&self, sid: i64, cid: &str) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.append_to_queue(pid, sid, cid).await?;
+        }
+        Ok(())
+    }
+
+    /// The highlighted Browse item if it's a container HEOS can queue as a whole,
+    /// with the source it belongs to
+    pub fn playable_container(&self) -> Option<(i64, &BrowseItem)> {
+        let level = self.browse_stack.last()?;
+        let item = self.browse_items.get(self.browse_selected)?;
+        (is_heos_sid(level.sid) && item.container == "yes" && item.playable == "yes")
+            .then_some((level.sid, item))
+    }
+
+    /// Offer queue options for the highlighted Browse item; returns false for items
+    /// that aren't queueable, which keep their plain Enter behaviour
+    pub fn open_browse_menu(&mut self) -> bool {
+        let (Some(level), Some(item)) = (
+            self.browse_stack.last(),
+            self.browse_items.get(self.browse_selected),
+        ) else {
+            return false;
+        };
+        // Stations stream rather than queue
+        if !is_heos_sid(level.sid) || item.playable != "yes" || item.item_type == "station" {
+            return false;
+        }
+        let mut options = Vec::new();
+        if item.container == "yes" {
+            options.push(BrowseAction::Open);
+        } else if item.mid.is_empty() {
+            return false;
+        }
+        options.extend([
+            BrowseAction::PlayNow,
+            BrowseAction::PlayNext,
+            BrowseAction::AddToEnd,
+            BrowseAction::ReplaceAndPlay,
+        ]);
+        if level.sid == PLAYLISTS_SID && level.cid.is_some() && item.container != "yes" {
+            options.push(BrowseAction::PlayFromHere);
+        }
+        self.browse_menu = options;
+        self.browse_menu_selected = 0;
+        self.current_view = View::BrowseMenu;
+        true
+    }
+
+    /// Whether what's playing is a station that takes thumbs up/down
+    pub fn can_rate_track(&self) -> bool {
+        let media = &self.player_state.now_playing;
+        media.is_station() && THUMBS_SIDS.contains(&media.sid)
+    }
+
+    /// Thumbs up (or down) the playing station track; returns false when it can't be rated
+    pub async fn rate_track(&self, up: bool) -> Result<bool> {
+        if !self.can_rate_track() {
+            return Ok(false);
+        }
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.set_thumbs(pid, up).await?;
+        }
+        Ok(true)
+    }
+
+    /// Stream the highlighted Browse station or track; returns its name
+    pub async fn play_browse_item(&self) -> Result<Option<String>> {
+        let (Some(level), Some(item)) = (
+            self.browse_stack.last(),
+            self.browse_items.get(self.browse_selected),
+        ) else {
+            return Ok(None);
+        };
+        if !is_heos_sid(level.sid) || item.mid.is_empty() || item.container == "yes" {
+            return Ok(None);
+        }
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle
+                .play_station(pid, level.sid, level.cid.as_deref(), &item.mid, &item.name)
+                .await?;
+        }
+        Ok(Some(item.name.clone()))
+    }
+
+    /// Queue the highlighted Browse item as chosen in the menu; returns its name
+    pub async fn run_browse_action(&mut self, action: BrowseAction) -> Result<Option<String>> {
+        let (Some(level), Some(item)) = (
+            self.browse_stack.last(),
+            self.browse_items.get(self.browse_selected),
+        ) else {
+            return Ok(None);
+        };
+        let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) else {
+            return Ok(None);
+        };
+        match (action, action.aid()) {
+            (BrowseAction::PlayFromHere, _) => {
+                if let Some(cid) = &level.cid {
+                    self.play_container(level.sid, cid, self.browse_selected)
+                        .await?;
+                    for track in self.browse_items.iter().filter(|i| !i.mid.is_empty()) {
+                        let source = QueueSource {
+                            sid: level.sid,
+                            cid: cid.clone(),
+                        };
+                        self.queue_sources.insert(track.mid.clone(), source);
+                    }
+                }
+            }
+            (_, Some(aid)) if item.container == "yes" => {
+                handle
+                    .add_to_queue(pid, level.sid, Some(&item.cid), None, aid)
+                    .await?
+            }
+            (_, Some(aid)) => {
+                handle
+                    .add_to_queue(pid, level.sid, level.cid.as_deref(), Some(&item.mid), aid)
+                    .await?;
+                if let Some(cid) = &level.cid {
+                    let source = QueueSource {
+                        sid: level.sid,
+                        cid: cid.clone(),
+                    };
+                    self.queue_sources.insert(item.mid.clone(), source);
+                }
+            }
+            (_, None) => return Ok(None),
+        }
+        Ok(Some(item.name.clone()))
+    }
+
+    /// Re-read the playlist files so edits show up the next time Browse opens
+    pub fn reload_local_playlists(&mut self) -> Result<()> {
+        self.local_playlists = playlist::load_local_playlists()?;
+        Ok(())
+    }
+
+    /// Play a local playlist from entry `start` on; returns the number of entries queued
+    pub async fn play_local_playlist(&mut self, id: &str, start: usize) -> Result<usize> {
+        let Some(playlist) = self.local_playlists.iter().find(|p| p.id == id) else {
+            return Ok(0);
+        };
+        self.stream_queue = playlist.items.iter().skip(start).cloned().collect();
+        let count = self.stream_queue.len();
+        self.play_next_stream().await?;
+        Ok(count)
+    }
+
+    /// Run a plugin action with the current player and media as context
+    pub async fn run_plugin_action(&mut self, idx: usize) -> Result<Option<String>> {
+        let context = serde_json::json!({
+            "player": self.current_player().map(|p| p.name.clone()),
+            "now_playing": self.player_state.now_playing,
+        });
+        self.plugins.run_action(idx, context).await
+    }
+
+    /// Artwork/service link for the item under the cursor, or the now playing media
+    pub fn current_item_link(&self) -> Option<String> {
+        let url = match self.current_view {
+            View::Queue => self
+                .queue
+                .get(self.queue_selected)
+                .map(|i| i.image_url.as_str()),
+            View::Browse if self.browse_stack.is_empty() => self
+                .music_sources
+                .get(self.browse_selected)
+                .map(|s| s.image_url.as_str()),
+            View::Browse => self
+                .browse_items
+                .get(self.browse_selected)
+                .map(|i| i.image_url.as_str()),
+            _ => Some(self.player_state.now_playing.image_url.as_str()),
+        };
+        url.filter(|u| !u.is_empty()).map(str::to_string)
+    }
+
+    /// Open a URL in the system browser
+    pub fn open_link(&self, url: &str) -> Result<()> {
+        let opener = if cfg!(target_os = "macos") {
+            "open"
+        } else {
+            "xdg-open"
+        };
+        process::Command::new(opener)
+            .arg(url)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        Ok(())
+    }
+
     // ==================== HEOS Commands ====================
 
-    pub async fn refresh_players(&self) -> Result<()> {
+    /// Fetch the player list in the background; [`HeosEvent::Players`] reconciles it
+    pub fn refresh_players(&self) {
         if let Some(handle) = &self.handle {
-            handle.get_players().await?;
+            handle.load_players();
+        }
+    }
+
+    /// Take a fresh player list, keeping the selection and per-player state of the
+    /// players that are still there
+    fn set_players(&mut self, players: Vec<Player>) {
+        let ignored = &self.config.ui.ignored_players;
+        self.players = players
+            .into_iter()
+            .filter(|p| {
+                !ignored
+                    .iter()
+                    .any(|i| i.eq_ignore_ascii_case(&p.name) || *i == p.pid.to_string())
+            })
+            .collect();
+        // After a reconnect, stay on the player that was selected
+        let selected = self.player_state.player.as_ref().map(|p| p.pid);
+        match self.players.iter().position(|p| Some(p.pid) == selected) {
+            Some(idx) => self.current_player_idx = idx,
+            None if selected.is_some() && !self.players.is_empty() => {
+                // It went away: carry on with the one now in its place
+                let idx = self.current_player_idx.min(self.players.len() - 1);
+                self.show_player(idx);
+            }
+            None => {}
+        }
+        let pids: Vec<i64> = self.players.iter().map(|p| p.pid).collect();
+        self.player_states.retain(|pid, _| pids.contains(pid));
+        self.device_marks.retain(|pid| pids.contains(pid));
+        self.device_selected = self
+            .device_selected
+            .min(self.players.len().saturating_sub(1));
+        if !self.players.is_empty() && self.player_state.player.is_none() {
+            // Start on the configured player until play states show which is in use
+            self.current_player_idx = self.default_player_idx();
+            self.player_state.player = self.current_player().cloned();
+            self.apply_device_theme();
+            self.auto_select_pending = true;
+        }
+    }
+
+    pub async fn refresh_player_state(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.get_play_state(pid).await?;
+            handle.get_now_playing(pid).await?;
+            handle.get_volume(pid).await?;
+            handle.get_mute(pid).await?;
+            handle.get_play_mode(pid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn toggle_play_pause(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            match self.player_state.play_state {
+                PlayState::Play => handle.pause(pid).await?,
+                _ => handle.play(pid).await?,
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn stop(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.stop(pid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn next_track(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_next(pid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn prev_track(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_previous(pid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn volume_up(&self) -> Result<()> {
+        if let (Some(handle), Some(group)) = (&self.handle, self.target_group()) {
+            handle
+                .group_volume_up(group.gid, self.volume_step())
+                .await?;
+        } else if let Some(pid) = self.current_pid() {
+            self.step_volume(pid, self.player_state.volume, true)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn volume_down(&self) -> Result<()> {
+        if let (Some(handle), Some(group)) = (&self.handle, self.target_group()) {
+            handle
+                .group_volume_down(group.gid, self.volume_step())
+                .await?;
+        } else if let Some(pid) = self.current_pid() {
+            self.step_volume(pid, self.player_state.volume, false)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Step player `pid` up or down from `level` by its volume step, stopping at its
+    /// `max_volume`
+    async fn step_volume(&self, pid: i64, level: u8, up: bool) -> Result<()> {
+        let Some(handle) = &self.handle else {
+            return Ok(());
+        };
+        let device = self
+            .players
+            .iter()
+            .find(|p| p.pid == pid)
+            .and_then(|p| self.config.device.get(&p.name));
+        let step = device
+            .and_then(|d| d.volume_step)
+            .unwrap_or(self.config.ui.volume_step);
+        match device.and_then(|d| d.max_volume) {
+            Some(max) if up && level.saturating_add(step) > max => {
+                handle.set_volume(pid, max.max(level)).await
+            }
+            _ if up => handle.volume_up(pid, step).await,
+            _ => handle.volume_down(pid, step).await,
+        }
+    }
+
+    /// The music source the current media is playing from
+    pub fn now_playing_source(&self) -> Option<&MusicSource> {
+        let sid = self.player_state.now_playing.sid;
+        self.music_sources
+            .iter()
+            .chain(&self.inputs)
+            .find(|s| s.sid == sid)
+    }
+
+    /// Current position and length of the playing track, if it reports one
+    pub fn playback_progress(&self) -> Option<(Duration, Duration)> {
+        let state = &self.player_state;
+        if state.duration_ms == 0 {
+            return None;
+        }
+        let mut position = Duration::from_millis(state.position_ms);
+        // Progress events come every few seconds; fill the gaps while playing
+        if state.play_state == PlayState::Play {
+            if let Some(at) = self.progress_at {
+                position += self.last_tick.saturating_duration_since(at);
+            }
+        }
+        let duration = Duration::from_millis(state.duration_ms);
+        Some((position.min(duration), duration))
+    }
+
+    /// Length of the whole queue and of what's left to play, counting tracks of known length
+    pub fn queue_durations(&self) -> Option<(Duration, Duration)> {
+        let lengths: Vec<Duration> = self
+            .queue
+            .iter()
+            .map(|item| Duration::from_millis(item.duration))
+            .collect();
+        let total: Duration = lengths.iter().sum();
+        if total.is_zero() {
+            return None;
+        }
+        let qid = self.player_state.now_playing.qid;
+        let remaining = match self.queue.iter().position(|item| item.qid == qid) {
+            Some(idx) => {
+                let played = self
+                    .playback_progress()
+                    .map_or(Duration::ZERO, |(pos, _)| pos);
+                lengths[idx].saturating_sub(played) + lengths[idx + 1..].iter().sum::<Duration>()
+            }
+            None => total,
+        };
+        Some((total, remaining))
+    }
+
+    /// The group the current player belongs to
+    pub fn active_group(&self) -> Option<&Group> {
+        let pid = self.current_pid()?;
+        self.groups.iter().find(|g| g.contains(pid))
+    }
+
+    /// The group a player belongs to
+    pub fn group_of(&self, pid: i64) -> Option<&Group> {
+        self.groups.iter().find(|g| g.contains(pid))
+    }
+
+    /// Group role from `get_groups`. Stereo pairs don't show up there; their players
+    /// share a `gid` that is the primary's pid
+    pub fn player_role(&self, pid: i64) -> PlayerRole {
+        if let Some(group) = self.group_of(pid) {
+            return if group.leader() == Some(pid) {
+                PlayerRole::GroupLeader
+            } else {
+                PlayerRole::GroupMember
+            };
+        }
+        let Some(gid) = self
+            .players
+            .iter()
+            .find(|p| p.pid == pid)
+            .and_then(|p| p.gid)
+        else {
+            return PlayerRole::Alone;
+        };
+        let paired = self
+            .players
+            .iter()
+            .any(|p| p.pid != pid && p.gid == Some(gid));
+        match (paired, gid == pid) {
+            (false, _) => PlayerRole::Alone,
+            (true, true) => PlayerRole::PairPrimary,
+            (true, false) => PlayerRole::PairSecondary { primary: gid },
+        }
+    }
+
+    /// Mark or unmark the player at `idx` for grouping
+    pub fn toggle_device_mark(&mut self, idx: usize) {
+        if let Some(pid) = self.players.get(idx).map(|p| p.pid) {
+            match self.device_marks.iter().position(|&m| m == pid) {
+                Some(i) => {
+                    self.device_marks.remove(i);
+                }
+                None => self.device_marks.push(pid),
+            }
+        }
+    }
+
+    /// Group the marked players, led by the first marked; returns how many were grouped
+    pub async fn group_marked_players(&mut self) -> Result<usize> {
+        let Some(handle) = &self.handle else {
+            return Ok(0);
+        };
+        if self.device_marks.len() < 2 {
+            anyhow::bail!("{}", self.messages.get(Msg::GroupNeedsTwo));
+        }
+        handle.set_group(&self.device_marks).await?;
+        let count = self.device_marks.len();
+        self.device_marks.clear();
+        Ok(count)
+    }
+
+    /// Dissolve the group the player at `idx` is in; returns the group's name
+    pub async fn ungroup_player(&self, idx: usize) -> Result<Option<String>> {
+        let Some(handle) = &self.handle else {
+            return Ok(None);
+        };
+        let Some(group) = self.players.get(idx).and_then(|p| self.group_of(p.pid)) else {
+            return Ok(None);
+        };
+        let Some(leader) = group.leader() else {
+            return Ok(None);
+        };
+        handle.set_group(&[leader]).await?;
+        Ok(Some(group.name.clone()))
+    }
+
+    pub async fn refresh_group(&self, gid: i64) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            handle.get_group_info(gid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn refresh_groups(&self) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            handle.get_groups().await?;
+        }
+        Ok(())
+    }
+
+    /// Fetch every group's volume and each member's volume
+    pub async fn refresh_group_volumes(&self) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            for group in &self.groups {
+                handle.get_group_volume(group.gid).await?;
+                for member in &group.players {
+                    handle.get_volume(member.pid).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The group volume keys act on, while it still exists
+    pub fn target_group(&self) -> Option<&Group> {
+        let gid = self.group_target?;
+        self.groups.iter().find(|g| g.gid == gid)
+    }
+
+    /// Control a group: its leader becomes the current player and volume keys drive the group
+    pub async fn select_group(&mut self, idx: usize) -> Result<Option<String>> {
+        let Some(group) = self.groups.get(idx).cloned() else {
+            return Ok(None);
+        };
+        let leader = group.leader();
+        if let Some(pos) = self.players.iter().position(|p| Some(p.pid) == leader) {
+            if pos != self.current_player_idx {
+                self.switch_player(pos).await?;
+            }
+        }
+        self.group_target = Some(group.gid);
+        Ok(Some(group.name))
+    }
+
+    /// Raise the focused mixer gauge: the whole group or a single member
+    pub async fn mixer_volume_up(&self) -> Result<()> {
+        let (Some(handle), Some(group)) = (&self.handle, self.active_group()) else {
+            return Ok(());
+        };
+        match self.mixer_focus.checked_sub(1) {
+            None => {
+                handle
+                    .group_volume_up(group.gid, self.volume_step())
+                    .await?
+            }
+            Some(i) => {
+                if let Some(member) = group.players.get(i) {
+                    self.step_cached_volume(member.pid, true).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn mixer_volume_down(&self) -> Result<()> {
+        let (Some(handle), Some(group)) = (&self.handle, self.active_group()) else {
+            return Ok(());
+        };
+        match self.mixer_focus.checked_sub(1) {
+            None => {
+                handle
+                    .group_volume_down(group.gid, self.volume_step())
+                    .await?
+            }
+            Some(i) => {
+                if let Some(member) = group.players.get(i) {
+                    self.step_cached_volume(member.pid, false).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Step player `pid` from its last known level, capped like the current player
+    async fn step_cached_volume(&self, pid: i64, up: bool) -> Result<()> {
+        let level = self.player_states.get(&pid).map_or(0, |s| s.volume);
+        self.step_volume(pid, level, up).await
+    }
+
+    /// Move the mixer focus left or right, wrapping around
+    pub fn mixer_focus_move(&mut self, forward: bool) {
+        let count = self.active_group().map_or(0, |g| g.players.len()) + 1;
+        self.mixer_focus = if forward {
+            (self.mixer_focus + 1) % count
+        } else {
+            (self.mixer_focus + count - 1) % count
+        };
+    }
+
+    /// `[device."<name>"]` settings for the current player
+    pub fn device_config(&self) -> Option<&DeviceConfig> {
+        let name = &self.current_player()?.name;
+        self.config.device.get(name)
+    }
+
+    pub fn volume_step(&self) -> u8 {
+        self.device_config()
+            .and_then(|d| d.volume_step)
+            .unwrap_or(self.config.ui.volume_step)
+    }
+
+    /// Set the current player to an absolute level, capped at `max_volume`; returns the level sent
+    pub async fn set_volume(&self, level: u8) -> Result<u8> {
+        let level = self.limit_volume(level);
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.set_volume(pid, level).await?;
+        }
+        Ok(level)
+    }
+
+    /// Clamp a volume level to the current player's `max_volume`
+    pub fn limit_volume(&self, level: u8) -> u8 {
+        match self.device_config().and_then(|d| d.max_volume) {
+            Some(max) => level.min(max),
+            None => level,
+        }
+    }
+
+    /// Re-resolve the theme, using the current player's accent color if it has one
+    pub fn apply_device_theme(&mut self) {
+        let theme = Theme::from_preset(self.config.ui.theme);
+        let accent = self
+            .device_config()
+            .and_then(|d| d.accent.as_deref())
+            .and_then(|accent| accent.parse::<Color>().ok());
+        self.theme = match accent {
+            Some(color) => theme.with_accent(color),
+            None => theme,
+        };
+    }
+
+    pub async fn toggle_mute(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.toggle_mute(pid).await?;
+        }
+        Ok(())
+    }
+
+    /// Mute, unmute or stop with the volume ramped down or up in the background.
+    /// Muting and stopping put the volume back afterwards, unheard
+    pub fn fade(&mut self, fade: Fade) {
+        let (Some(handle), Some(pid)) = (self.handle.clone(), self.current_pid()) else {
+            return;
+        };
+        // A ramp cut short leaves the volume low, so carry on from where it started
+        let level = match self.volume_fade.take() {
+            Some((task, level)) if !task.is_finished() => {
+                task.abort();
+                level
+            }
+            _ => self.player_state.volume,
+        };
+        let task = tokio::spawn(async move {
+            // Failures also arrive as responses, which report them
+            let _: Result<()> = async {
+                match fade {
+                    Fade::Mute => {
+                        handle.fade_volume(pid, level, 0).await?;
+                        handle.set_mute(pid, true).await?;
+                        handle.set_volume(pid, level).await
+                    }
+                    Fade::Unmute => {
+                        handle.set_volume(pid, 0).await?;
+                        handle.set_mute(pid, false).await?;
+                        handle.fade_volume(pid, 0, level).await
+                    }
+                    Fade::Stop => {
+                        handle.fade_volume(pid, level, 0).await?;
+                        handle.stop(pid).await?;
+                        handle.set_volume(pid, level).await
+                    }
+                }
+            }
+            .await;
+        });
+        self.volume_fade = Some((task.abort_handle(), level));
+    }
+
+    /// Fetch the volume and mute state of every player for the device list
+    pub async fn refresh_player_volumes(&self) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            for player in &self.players {
+                handle.get_volume(player.pid).await?;
+                handle.get_mute(player.pid).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Step the volume of the player at `idx`, which needn't be the current one
+    pub async fn step_player_volume(&self, idx: usize, up: bool) -> Result<()> {
+        let Some(pid) = self.players.get(idx).map(|p| p.pid) else {
+            return Ok(());
+        };
+        self.step_cached_volume(pid, up).await
+    }
+
+    /// Toggle mute on the player at `idx`, based on its cached mute state
+    pub async fn toggle_player_mute(&mut self, idx: usize) -> Result<()> {
+        let (Some(handle), Some(player)) = (&self.handle, self.players.get(idx)) else {
+            return Ok(());
+        };
+        let muted = self
+            .player_states
+            .get(&player.pid)
+            .is_some_and(|s| s.mute == MuteState::On);
+        handle.set_mute(player.pid, !muted).await?;
+        let msg = if muted {
+            Msg::PlayerUnmuted
+        } else {
+            Msg::PlayerMuted
+        };
+        let text = self.messages.format(msg, &[&player.name]);
+        self.set_status(text);
+        Ok(())
+    }
+
+    pub async fn sign_in(&self, username: &str, password: &str) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            handle.sign_in(username, password).await?;
+        }
+        Ok(())
+    }
+
+    /// Execute a `:` / `--pipe` command against the current player
+    pub async fn run_command(&mut self, command: Command) -> Result<()> {
+        if let Command::Player(name) | Command::Move(name) = &command {
+            let idx = self
+                .players
+                .iter()
+                .position(|p| p.name.eq_ignore_ascii_case(name) || p.pid.to_string() == *name)
+                .ok_or_else(|| anyhow::anyhow!("No player named '{}'", name))?;
+            if matches!(command, Command::Move(_)) {
+                return self.transfer_playback(idx);
+            }
+            return self.select_player(idx).await;
+        }
+        if let Command::AvrVolume(level) = command {
+            return self.avr_set_volume(level).await;
+        }
+
+        let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) else {
+            anyhow::bail!("{}", self.messages.get(Msg::NoPlayerConnected));
+        };
+        match command {
+            Command::Play => handle.play(pid).await,
+            Command::Pause => handle.pause(pid).await,
+            Command::TogglePlay => self.toggle_play_pause().await,
+            Command::Stop => handle.stop(pid).await,
+            Command::Next => handle.play_next(pid).await,
+            Command::Prev => handle.play_previous(pid).await,
+            Command::Volume(level) => handle.set_volume(pid, self.limit_volume(level)).await,
+            Command::VolumeUp => self.volume_up().await,
+            Command::VolumeDown => self.volume_down().await,
+            Command::Mute(None) => handle.toggle_mute(pid).await,
+            Command::Mute(Some(on)) => handle.set_mute(pid, on).await,
+            Command::PlayUrl(url) => handle.play_url(pid, &url).await,
+            Command::Input(input) => handle.play_input(pid, &input).await,
+            Command::SignOut => handle.sign_out().await,
+            Command::Player(_) | Command::Move(_) | Command::AvrVolume(_) => Ok(()),
+        }
+    }
+
+    /// Carry on what the current player is playing on the player at `idx`, stop it
+    /// here and switch control over. Stations are tuned in again; a queue is rebuilt
+    /// track by track, less tracks not queued from Browse, and resumed at the same
+    /// track from the start of it. Runs in the background and ends with
+    /// [`HeosEvent::PlaybackTransferred`]
+    pub fn transfer_playback(&mut self, idx: usize) -> Result<()> {
+        let (Some(handle), Some(from)) = (self.handle.clone(), self.current_pid()) else {
+            anyhow::bail!("{}", self.messages.get(Msg::NoPlayerConnected));
+        };
+        let Some(to) = self.players.get(idx).map(|p| p.pid) else {
+            return Ok(());
+        };
+        if to == from {
+            return Ok(());
+        }
+        let sources = self.queue_sources.clone();
+        let messages = self.messages;
+        handle.report(move |handle| async move {
+            let skipped = Self::move_playback(&handle, from, to, &sources, messages).await?;
+            Ok(HeosEvent::PlaybackTransferred { to, skipped })
+        });
+        Ok(())
+    }
+
+    /// Returns how many queued tracks couldn't be carried over
+    async fn move_playback(
+        handle: &HeosHandle,
+        from: i64,
+        to: i64,
+        sources: &HashMap<String, QueueSource>,
+        messages: Messages,
+    ) -> Result<usize> {
+        let mut skipped = 0;
+        let media = handle.fetch_now_playing(from).await?;
+        if media.is_station() && !media.mid.is_empty() {
+            handle
+                .play_station(to, media.sid, None, &media.mid, &media.station)
+                .await?;
+        } else if media.qid > 0 {
+            let queue = handle.fetch_queue(from, TRANSFER_QUEUE_LIMIT).await?;
+            // Only tracks queued from Browse can be added again
+            let tracks: Vec<(&QueueItem, &QueueSource)> = queue
+                .iter()
+                .filter_map(|item| Some((item, sources.get(&item.mid)?)))
+                .collect();
+            if !tracks.iter().any(|(item, _)| item.qid == media.qid) {
+                anyhow::bail!("{}", messages.get(Msg::TrackNotMovable));
+            }
+            skipped = queue.len() - tracks.len();
+            handle.clear_queue(to).await?;
+            // Queue ids are positions, starting at 1; resume at the current track, or
+            // the first one after it that made it
+            let mut added = 0;
+            let mut resume_at = None;
+            for (item, source) in tracks {
+                if item.qid == media.qid {
+                    resume_at = Some(added + 1);
+                }
+                match handle
+                    .add_track_to_queue(to, source.sid, &source.cid, &item.mid)
+                    .await
+                {
+                    Ok(()) => added += 1,
+                    Err(_) => skipped += 1,
+                }
+            }
+            if added == 0 {
+                anyhow::bail!("{}", messages.get(Msg::TrackNotMovable));
+            }
+            let resume_at = resume_at.unwrap_or(1).min(added);
+            handle.play_queue_item(to, resume_at).await?;
+        } else {
+            anyhow::bail!("{}", messages.get(Msg::NothingToMove));
+        }
+        handle.stop(from).await?;
+        Ok(skipped)
+    }
+
+    /// Take control of the player playback was moved to
+    pub async fn finish_transfer(&mut self, to: i64) -> Result<()> {
+        let Some(idx) = self.players.iter().position(|p| p.pid == to) else {
+            return Ok(());
+        };
+        self.auto_select_pending = false;
+        self.group_target = None;
+        self.switch_player(idx).await
+    }
+
+    pub async fn cycle_repeat(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            let new_repeat = self.player_state.repeat.next();
+            handle
+                .set_play_mode(pid, new_repeat.as_str(), self.player_state.shuffle.as_str())
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn toggle_shuffle(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            let new_shuffle = self.player_state.shuffle.toggle();
+            handle
+                .set_play_mode(pid, self.player_state.repeat.as_str(), new_shuffle.as_str())
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn refresh_queue(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.get_queue(pid, 0, 100).await?;
+        }
+        Ok(())
+    }
+
+    /// Remove the queue item at `idx`, keeping it for undo; returns its title
+    pub async fn remove_queue_item(&mut self, idx: usize) -> Result<Option<String>> {
+        let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) else {
+            return Ok(None);
+        };
+        let Some(item) = self.queue.get(idx).cloned() else {
+            return Ok(None);
+        };
+        handle.remove_from_queue(pid, item.qid).await?;
+        handle.get_queue(pid, 0, 100).await?;
+        let song = item.song.clone();
+        self.push_queue_undo(vec![(idx, item)]);
+        Ok(Some(song))
+    }
+
+    /// Empty the queue, keeping its tracks for undo; returns how many were removed, or
+    /// None when only part of the queue was loaded and so nothing was kept
+    pub async fn clear_queue(&mut self) -> Result<Option<usize>> {
+        let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) else {
+            return Ok(Some(0));
+        };
+        handle.clear_queue(pid).await?;
+        handle.get_queue(pid, 0, 100).await?;
+        let items = std::mem::take(&mut self.queue);
+        let count = items.len();
+        self.queue_selected = 0;
+        if count >= QUEUE_PAGE {
+            return Ok(None);
+        }
+        if count > 0 {
+            self.push_queue_undo(items.into_iter().enumerate().collect());
+        }
+        Ok(Some(count))
+    }
+
+    fn push_queue_undo(&mut self, items: Vec<(usize, QueueItem)>) {
+        let tracks = items
+            .into_iter()
+            .map(|(position, item)| RemovedTrack {
+                position,
+                source: self.queue_sources.get(&item.mid).cloned(),
+                mid: item.mid,
+            })
+            .collect();
+        self.queue_undo.push(QueueUndo { tracks });
+        if self.queue_undo.len() > QUEUE_UNDO_LIMIT {
+            self.queue_undo.remove(0);
+        }
+    }
+
+    /// Put the most recently removed items back where they were, in the background;
+    /// [`HeosEvent::QueueRestored`] reports how many. False if there's nothing to undo
+    pub fn undo_queue_edit(&mut self) -> bool {
+        let (Some(handle), Some(pid)) = (self.handle.clone(), self.current_pid()) else {
+            return false;
+        };
+        if self.queue_undo_pending.is_some() {
+            return true;
+        }
+        let Some(undo) = self.queue_undo.pop() else {
+            return false;
+        };
+        self.queue_undo_pending = Some(undo.clone());
+        handle.report(move |handle| async move {
+            let restored = Self::restore_tracks(&handle, pid, &undo.tracks).await;
+            // Report the undo even if the reload can't be sent, so it isn't left pending
+            let _ = handle.get_queue(pid, 0, 100).await;
+            Ok(HeosEvent::QueueRestored {
+                restored,
+                failed: undo.tracks.len() - restored,
+            })
+        });
+        true
+    }
+
+    /// Re-add `tracks` at the end of the queue, then move them back into place;
+    /// returns how many made it back
+    async fn restore_tracks(handle: &HeosHandle, pid: i64, tracks: &[RemovedTrack]) -> usize {
+        let Ok(len) = handle.fetch_queue_len(pid).await else {
+            return 0;
+        };
+        let mut restored = Vec::new();
+        for track in tracks {
+            let Some(source) = &track.source else {
+                continue;
+            };
+            let added = handle
+                .add_track_to_queue(pid, source.sid, &source.cid, &track.mid)
+                .await;
+            if added.is_ok() {
+                restored.push(track);
+            }
+        }
+        // Queue ids are positions from 1. Moving the re-added tracks back in queue order
+        // leaves the ones still at the end where they were added, so a track that won't
+        // move just stays at the end
+        for (n, track) in restored.iter().enumerate() {
+            let qid = (len + n + 1) as i64;
+            let dqid = (track.position + 1) as i64;
+            if dqid < qid {
+                let _ = handle.move_queue_item(pid, qid, dqid).await;
+            }
+        }
+        restored.len()
+    }
+
+    /// Select the queue item that's playing; false if it isn't in the loaded queue
+    pub fn jump_to_now_playing(&mut self) -> bool {
+        let qid = self.player_state.now_playing.qid;
+        match self.queue.iter().position(|item| item.qid == qid) {
+            Some(idx) => {
+                self.queue_selected = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub async fn play_queue_item(&self, qid: i64) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_queue_item(pid, qid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn refresh_music_sources(&self) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            handle.get_music_sources().await?;
+        }
+        Ok(())
+    }
+
+    /// Browse into a level, from the cache if it was open recently; the level is
+    /// dropped again if fetching it fails
+    pub async fn enter_browse_level(&mut self, level: BrowseLevel) -> Result<()> {
+        self.cache_browse_level();
+        self.browse_stack.push(level);
+        if self.restore_browse_level() {
+            return Ok(());
+        }
+        if let Err(e) = self.fetch_browse_level().await {
+            self.browse_stack.pop();
+            self.restore_browse_level();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Go up one browse level, showing it as it was left if it's still cached
+    pub async fn browse_back(&mut self) -> Result<()> {
+        self.cache_browse_level();
+        let Some(left) = self.browse_stack.pop() else {
+            return Ok(());
+        };
+        self.browse_paging = None;
+        self.service_options.clear();
+        if self.browse_stack.is_empty() {
+            // Back at the sources, with the one just left selected
+            self.browse_items.clear();
+            self.browse_selected = self
+                .music_sources
+                .iter()
+                .position(|s| s.sid == left.sid)
+                .unwrap_or(0);
+            return Ok(());
+        }
+        if self.restore_browse_level() {
+            return Ok(());
+        }
+        // Don't leave the deeper level's items up while the list is refetched
+        self.browse_items.clear();
+        self.browse_selected = 0;
+        self.fetch_browse_level().await
+    }
+
+    /// Fetch the open browse level again, bypassing the cache
+    pub async fn refresh_browse(&mut self) -> Result<()> {
+        let Some(level) = self.browse_stack.last() else {
+            return self.refresh_music_sources().await;
+        };
+        self.browse_cache.remove(&level.key());
+        self.fetch_browse_level().await
+    }
+
+    /// Request the items of the innermost browse level
+    async fn fetch_browse_level(&mut self) -> Result<()> {
+        let Some(level) = self.browse_stack.last().cloned() else {
+            return Ok(());
+        };
+        match (&level.search, &level.cid) {
+            (Some((scid, query)), _) => {
+                self.browse_paging = None;
+                if let Some(handle) = &self.handle {
+                    handle.search(level.sid, query, *scid).await?;
+                }
+                Ok(())
+            }
+            (None, Some(cid)) => self.browse_container(level.sid, cid).await,
+            (None, None) => self.browse_source(level.sid).await,
+        }
+    }
+
+    /// Keep the shown list of the innermost level for going back to it
+    fn cache_browse_level(&mut self) {
+        // Local playlists change under the list and are cheap to rebuild
+        let Some(level) = self
+            .browse_stack
+            .last()
+            .filter(|level| level.sid != LOCAL_PLAYLISTS_SID)
+        else {
+            return;
+        };
+        if self.browse_items.is_empty() {
+            return;
+        }
+        self.browse_cache.insert(
+            level.key(),
+            CachedBrowse {
+                items: self.browse_items.clone(),
+                selected: self.browse_selected,
+                paging: self.browse_paging.clone(),
+                service_options: self.service_options.clone(),
+                fetched: self.browse_fetched,
+            },
+        );
+    }
+
+    /// Show the innermost level from the cache; false if it isn't cached or has expired
+    fn restore_browse_level(&mut self) -> bool {
+        let Some(key) = self.browse_stack.last().map(BrowseLevel::key) else {
+            return false;
+        };
+        let ttl = Duration::from_secs(self.config.ui.browse_cache_ttl);
+        let Some(cached) = self.browse_cache.remove(&key) else {
+            return false;
+        };
+        if cached.fetched.elapsed() >= ttl {
+            return false;
+        }
+        self.browse_items = cached.items;
+        self.browse_selected = cached.selected;
+        self.browse_paging = cached.paging.map(|paging| BrowsePaging {
+            loading: false,
+            ..paging
+        });
+        self.service_options = cached.service_options;
+        self.browse_fetched = cached.fetched;
+        true
+    }
+
+    pub async fn browse_source(&mut self, sid: i64) -> Result<()> {
+        self.browse_paging = None;
+        self.service_options.clear();
+        if sid == LOCAL_PLAYLISTS_SID {
+            self.browse_items = self
+                .local_playlists
+                .iter()
+                .map(LocalPlaylist::browse_item)
+                .collect();
+            self.browse_selected = 0;
+            self.browse_fetched = Instant::now();
+            return Ok(());
+        }
+        if PluginHost::is_plugin_sid(sid) {
+            self.browse_items = self.plugins.browse(sid, None).await?;
+            self.browse_selected = 0;
+            self.browse_fetched = Instant::now();
+            return Ok(());
+        }
+        if let Some(handle) = &self.handle {
+            handle.browse_source(sid).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn browse_container(&mut self, sid: i64, cid: &str) -> Result<()> {
+        self.browse_paging = None;
+        self.service_options.clear();
+        if sid == LOCAL_PLAYLISTS_SID {
+            self.browse_items = self
+                .local_playlists
+                .iter()
+                .find(|p| p.id == cid)
+                .map(LocalPlaylist::entry_items)
+                .unwrap_or_default();
+            self.browse_selected = 0;
+            self.browse_fetched = Instant::now();
+            return Ok(());
+        }
+        if PluginHost::is_plugin_sid(sid) {
+            self.browse_items = self.plugins.browse(sid, Some(cid)).await?;
+            self.browse_selected = 0;
+            self.browse_fetched = Instant::now();
+            return Ok(());
+        }
+        if let Some(handle) = &self.handle {
+            handle.browse_container(sid, cid).await?;
+        }
+        Ok(())
+    }
+
+    /// Fetch the next page of the open container once the selection gets near the
+    /// end of what's loaded
+    pub async fn fetch_more_browse_items(&mut self) -> Result<()> {
+        let loaded = self.browse_items.len();
+        let Some(paging) = self.browse_paging.as_mut() else {
+            return Ok(());
+        };
+        let near_end = self.browse_selected + BROWSE_PREFETCH_MARGIN >= loaded;
+        if paging.loading || loaded >= paging.total || !near_end {
+            return Ok(());
+        }
+        if let Some(handle) = &self.handle {
+            handle
+                .browse_page(paging.sid, paging.cid.as_deref(), loaded)
+                .await?;
+            paging.loading = true;
+        }
+        Ok(())
+    }
+
+    /// Start the media that last failed to play again; false if nothing failed
+    pub async fn retry_playback(&mut self) -> Result<bool> {
+        let (Some(handle), Some((pid, media))) = (&self.handle, self.failed_playback.take()) else {
+            return Ok(false);
+        };
+        if media.is_station() && !media.mid.is_empty() {
+            handle
+                .play_station(pid, media.sid, None, &media.mid, &media.station)
+                .await?;
+        } else if media.qid > 0 {
+            handle.play_queue_item(pid, media.qid).await?;
+        } else {
+            handle.play(pid).await?;
+        }
+        Ok(true)
+    }
+
+    /// Show the service options of the open browse container; false if there are none
+    pub fn open_service_options(&mut self) -> bool {
+        if self.service_options.is_empty() {
+            return false;
+        }
+        self.service_option_selected = 0;
+        self.show_view(View::ServiceOptions);
+        true
+    }
+
+    /// Run the highlighted service option on the selected browse item
+    pub async fn run_service_option(&self) -> Result<()> {
+        let (Some(handle), Some(level), Some(option)) = (
+            &self.handle,
+            self.browse_stack.last(),
+            self.service_options.get(self.service_option_selected),
+        ) else {
+            return Ok(());
+        };
+        let item = self.browse_items.get(self.browse_selected);
+        // Items without an id of their own fall back to the open container
+        let cid = item
+            .map(|item| item.cid.as_str())
+            .filter(|cid| !cid.is_empty())
+            .or(level.cid.as_deref());
+        let mid = item
+            .map(|item| item.mid.as_str())
+            .filter(|mid| !mid.is_empty());
+        let name = item.map_or(level.name.as_str(), |item| item.name.as_str());
+        let pid = self.current_pid().unwrap_or_default();
+        handle
+            .set_service_option(level.sid, option.id, pid, cid, mid, name)
+            .await
+    }
+
+    /// Fetch `player/get_player_info` for the highlighted device in the background;
+    /// [`HeosEvent::PlayerInfo`] opens it
+    pub fn load_player_info(&self) {
+        let Some(pid) = self.players.get(self.device_selected).map(|p| p.pid) else {
+            return;
+        };
+        if let Some(handle) = &self.handle {
+            handle.load_player_info(pid);
+        }
+    }
+
+    pub async fn refresh_quickselects(&self) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.get_quickselects(pid).await?;
+        }
+        Ok(())
+    }
+
+    /// Play the highlighted quick select; returns its name
+    pub async fn play_quickselect(&self) -> Result<Option<String>> {
+        let Some(slot) = self.quickselects.get(self.quickselect_selected) else {
+            return Ok(None);
+        };
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_quickselect(pid, slot.id).await?;
+        }
+        Ok(Some(slot.name.clone()))
+    }
+
+    /// Store what's playing in the highlighted quick select; returns its number
+    pub async fn save_quickselect(&self) -> Result<Option<i64>> {
+        let Some(slot) = self.quickselects.get(self.quickselect_selected) else {
+            return Ok(None);
+        };
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.set_quickselect(pid, slot.id).await?;
+            // Names follow the saved source
+            handle.get_quickselects(pid).await?;
+        }
+        Ok(Some(slot.id))
+    }
+
+    /// Ask for the favorites; the list arrives as an event
+    pub fn refresh_favorites(&self) {
+        if let Some(handle) = &self.handle {
+            handle.load_favorites();
+        }
+    }
+
+    /// Play the nth (1-based) favorite; returns its name when known
+    pub async fn play_favorite(&self, preset: usize) -> Result<Option<String>> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_preset(pid, preset).await?;
+        }
+        Ok(preset
+            .checked_sub(1)
+            .and_then(|idx| self.favorites.get(idx))
+            .map(|item| item.name.clone()))
+    }
+
+    /// Play a history entry again, counted from the newest; returns its title
+    pub async fn play_history_entry(&self, idx: usize) -> Result<Option<String>> {
+        let (Some(handle), Some(pid), Some(entry)) =
+            (&self.handle, self.current_pid(), self.history.recent(idx))
+        else {
+            return Ok(None);
+        };
+        // Tracks are added through their container, which isn't known for every track
+        let playable = entry.is_station() || !entry.cid.is_empty();
+        if entry.mid.is_empty() || !is_heos_sid(entry.sid) || !playable {
+            anyhow::bail!(
+                "{}",
+                self.messages
+                    .format(Msg::HistoryNotPlayable, &[&entry.title()])
+            );
+        }
+        if entry.is_station() {
+            handle
+                .play_station(pid, entry.sid, None, &entry.mid, &entry.station)
+                .await?;
+        } else {
+            let aid = BrowseAction::PlayNow.aid().unwrap_or(1);
+            handle
+                .add_to_queue(pid, entry.sid, Some(&entry.cid), Some(&entry.mid), aid)
+                .await?;
+        }
+        Ok(Some(entry.title()))
+    }
+
+    /// Ask the browsed (or, at the top, the highlighted) source how it can be searched;
+    /// returns false for sources that aren't HEOS music services
+    pub async fn start_search(&mut self) -> Result<bool> {
+        let sid = match self.browse_stack.first() {
+            Some(level) => Some(level.sid),
+            None => self.music_sources.get(self.browse_selected).map(|s| s.sid),
+        };
+        let Some(sid) = sid.filter(|&sid| is_heos_sid(sid)) else {
+            return Ok(false);
+        };
+        self.search_sid = Some(sid);
+        self.search_criteria.clear();
+        self.search_criteria_selected = 0;
+        if let Some(handle) = &self.handle {
+            handle.get_search_criteria(sid).await?;
+        }
+        Ok(true)
+    }
+
+    /// Search the source with the selected criterion, showing the results as a browse level
+    pub async fn search(&mut self, query: &str) -> Result<()> {
+        let (Some(sid), Some(criteria)) = (
+            self.search_sid,
+            self.search_criteria.get(self.search_criteria_selected),
+        ) else {
+            return Ok(());
+        };
+        let level = BrowseLevel {
+            sid,
+            cid: None,
+            name: format!("🔍 {}: {}", criteria.name, query),
+            search: Some((criteria.scid, query.to_string())),
+        };
+        self.current_view = View::Browse;
+        self.cache_browse_level();
+        // A search is always run again, rather than shown from the cache
+        self.browse_cache.remove(&level.key());
+        self.browse_stack.push(level);
+        self.browse_items.clear();
+        self.browse_selected = 0;
+        self.browse_paging = None;
+        self.fetch_browse_level().await
+    }
+
+    /// Bookmark the current browse location; returns false if it was already saved
+    pub fn add_bookmark(&mut self) -> Result<bool> {
+        let (Some(source), Some(level)) = (self.browse_stack.first(), self.browse_stack.last())
+        else {
+            return Ok(false);
+        };
+        let bookmark = Bookmark {
+            name: level.name.clone(),
+            sid: level.sid,
+            source_name: source.name.clone(),
+            cid: level.cid.clone(),
+        };
+        let exists = self
+            .config
+            .bookmarks
+            .iter()
+            .any(|b| b.sid == bookmark.sid && b.cid == bookmark.cid);
+        if exists {
+            return Ok(false);
+        }
+        self.config.bookmarks.push(bookmark);
+        self.config.save()?;
+        Ok(true)
+    }
+
+    pub fn remove_bookmark(&mut self, idx: usize) -> Result<Option<Bookmark>> {
+        if idx >= self.config.bookmarks.len() {
+            return Ok(None);
+        }
+        let removed = self.config.bookmarks.remove(idx);
+        self.bookmark_selected = self
+            .bookmark_selected
+            .min(self.config.bookmarks.len().saturating_sub(1));
+        self.config.save()?;
+        Ok(Some(removed))
+    }
+
+    /// Switch to the Browse view at a bookmarked location
+    pub async fn open_bookmark(&mut self, idx: usize) -> Result<()> {
+        let Some(bookmark) = self.config.bookmarks.get(idx).cloned() else {
+            return Ok(());
+        };
+        self.current_view = View::Browse;
+        self.cache_browse_level();
+        self.browse_selected = 0;
+        self.browse_stack = vec![BrowseLevel {
+            sid: bookmark.sid,
+            cid: None,
+            name: bookmark.source_name.clone(),
+            search: None,
+        }];
+        if bookmark.cid.is_some() {
+            self.browse_stack.push(BrowseLevel {
+                sid: bookmark.sid,
+                cid: bookmark.cid.clone(),
+                name: bookmark.name.clone(),
+                search: None,
+            });
+        }
+        if self.restore_browse_level() {
+            return Ok(());
+        }
+        self.fetch_browse_level().await
+    }
+
+    /// Select a player on request, remembering it for the next start
+    pub async fn select_player(&mut self, idx: usize) -> Result<()> {
+        if idx < self.players.len() {
+            self.auto_select_pending = false;
+            self.group_target = None;
+            self.switch_player(idx).await?;
+            if let Some(input) = self.device_config().and_then(|d| d.default_input.clone()) {
+                self.play_input(&input).await?;
+            }
+            let pid = self.players[idx].pid;
+            if self.last_pid != Some(pid) {
+                self.last_pid = Some(pid);
+                save_last_pid(pid)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Make the player at `idx` current and load its state
+    pub async fn switch_player(&mut self, idx: usize) -> Result<()> {
+        if idx < self.players.len() {
+            // Show the last known state right away; the refresh below brings it up to date
+            self.show_player(idx);
+            self.refresh_player_state().await?;
+        }
+        Ok(())
+    }
+
+    /// Make the player at `idx` current with its last known state
+    fn show_player(&mut self, idx: usize) {
+        self.current_player_idx = idx;
+        self.now_playing_since = None;
+        if let Some(player) = self.players.get(idx) {
+            self.player_state = self
+                .player_states
+                .get(&player.pid)
+                .cloned()
+                .unwrap_or_default();
+            self.player_state.player = Some(player.clone());
+        }
+        self.apply_device_theme();
+    }
+
+    /// Ask every player for its play state and media, to find the one in use
+    pub async fn refresh_play_states(&self) -> Result<()> {
+        if let Some(handle) = &self.handle {
+            for player in &self.players {
+                handle.get_play_state(player.pid).await?;
+                handle.get_now_playing(player.pid).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a change to the cached state of `pid`, and to the live state if it's current
+    fn update_player_state(&mut self, pid: i64, update: impl Fn(&mut PlayerState)) {
+        update(self.player_states.entry(pid).or_default());
+        if self.current_pid() == Some(pid) {
+            update(&mut self.player_state);
+        }
+    }
+
+    /// In follow-playback mode, the player to switch to when `pid` starts playing
+    pub fn follow_target(&self, pid: i64, state: PlayState) -> Option<usize> {
+        let was_playing = self
+            .player_states
+            .get(&pid)
+            .is_some_and(|s| s.play_state == PlayState::Play);
+        if !self.config.ui.follow_playback || state != PlayState::Play || was_playing {
+            return None;
+        }
+        self.players
+            .iter()
+            .position(|p| p.pid == pid)
+            .filter(|&idx| idx != self.current_player_idx)
+    }
+
+    /// Once play states are in, the player to switch to at startup, if not the current one
+    pub fn take_auto_select(&mut self) -> Option<usize> {
+        if !self.auto_select_pending {
+            return None;
+        }
+        let playing = self
+            .players
+            .iter()
+            .position(|p| self.play_state_of(p.pid) == PlayState::Play);
+        let all_reported = self
+            .players
+            .iter()
+            .all(|p| self.play_state_of(p.pid) != PlayState::Unknown);
+        if playing.is_some() || all_reported {
+            self.auto_select_pending = false;
+        }
+        playing.filter(|&idx| idx != self.current_player_idx)
+    }
+
+    /// Last known play state of any player
+    pub fn play_state_of(&self, pid: i64) -> PlayState {
+        self.player_states
+            .get(&pid)
+            .map_or(PlayState::Unknown, |s| s.play_state)
+    }
+
+    /// Index of the configured (or last used) player, else the first
+    fn default_player_idx(&self) -> usize {
+        self.config
+            .ui
+            .default_player
+            .as_ref()
+            .and_then(|name| {
+                self.players
+                    .iter()
+                    .position(|p| p.name.eq_ignore_ascii_case(name) || p.pid.to_string() == *name)
+            })
+            .or_else(|| {
+                self.players
+                    .iter()
+                    .position(|p| Some(p.pid) == self.last_pid)
+            })
+            .unwrap_or(0)
+    }
+
+    /// Play an internet radio or HTTP stream on the current player
+    pub async fn play_stream_url(&self, url: &str) -> Result<()> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            anyhow::bail!("{}", self.messages.format(Msg::InvalidStreamUrl, &[&url]));
+        }
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_url(pid, url).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn play_input(&self, input: &str) -> Result<()> {
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            handle.play_input(pid, input).await?;
+        }
+        Ok(())
+    }
+
+    /// Ask the device which inputs its players have; the list arrives as an event
+    pub fn refresh_input_sources(&self) {
+        if let Some(handle) = &self.handle {
+            handle.load_input_sources();
+        }
+    }
+
+    /// Play the highlighted input on the current player; returns its name
+    pub async fn play_input_source(&self) -> Result<Option<String>> {
+        let Some(input) = self.input_sources.get(self.input_selected) else {
+            return Ok(None);
+        };
+        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
+            if input.sid == pid {
+                handle.play_input(pid, &input.input).await?;
+            } else {
+                handle
+                    .play_input_source(pid, input.sid, &input.input)
+                    .await?;
+            }
+        }
+        Ok(Some(input.name.clone()))
+    }
+
+    // ==================== AVR Commands ====================
+
+    pub async fn avr_query_status(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.query_status().await?;
+        }
+        Ok(())
+    }
+
+    pub async fn avr_set_surround_mode(&self, mode: SurroundMode) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.set_surround_mode(mode).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn avr_query_signal_info(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.query_signal_info().await?;
+        }
+        Ok(())
+    }
+
+    /// Set the configured headphone volume, if any
+    pub async fn avr_apply_headphone_volume(&self) -> Result<()> {
+        if let (Some(avr), Some(level)) = (&self.avr_handle, self.config.avr.headphone_volume) {
+            let level = AvrVolume::from_level(level).min(self.avr_volume_limit());
+            avr.set_volume(level).await?;
+        }
+        Ok(())
+    }
+
+    /// Send the `idx`th configured AVR preset; returns its name
+    pub fn avr_apply_preset(&self, idx: usize) -> Option<&str> {
+        let preset = self.config.avr_presets.get(idx)?;
+        if let Some(avr) = &self.avr_handle {
+            avr.send_batch(preset.commands());
+        }
+        Some(&preset.name)
+    }
+
+    pub async fn avr_set_upmixer(&self, upmixer: Upmixer) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.set_upmixer(upmixer).await?;
+        }
+        Ok(())
+    }
+
+    /// The user's name for an input code, or the code itself
+    pub fn avr_input_name<'a>(&'a self, code: &'a str) -> &'a str {
+        self.avr_state
+            .inputs
+            .iter()
+            .find(|input| input.code == code)
+            .map_or(code, |input| input.name.as_str())
+    }
+
+    /// Inputs shown in the AVR input selector, leaving out deleted ones
+    pub fn avr_visible_inputs(&self) -> Vec<&AvrInput> {
+        self.avr_state
+            .inputs
+            .iter()
+            .filter(|input| !input.hidden)
+            .collect()
+    }
+
+    /// The `code` entry of the input list, added if the receiver hadn't reported it
+    fn avr_input_entry(&mut self, code: String) -> &mut AvrInput {
+        let inputs = &mut self.avr_state.inputs;
+        let idx = match inputs.iter().position(|input| input.code == code) {
+            Some(idx) => idx,
+            None => {
+                inputs.push(AvrInput {
+                    name: code.clone(),
+                    code,
+                    hidden: false,
+                });
+                inputs.len() - 1
+            }
+        };
+        &mut inputs[idx]
+    }
+
+    pub async fn avr_set_input(&self, input: &str) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.set_input(input).await?;
         }
         Ok(())
     }
 
-    pub async fn refresh_player_state(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.get_play_state(pid).await?;
-            handle.get_now_playing(pid).await?;
-            handle.get_volume(pid).await?;
-            handle.get_mute(pid).await?;
-            handle.get_play_mode(pid).await?;
+    pub async fn avr_volume_up(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.volume_up().await?;
         }
         Ok(())
     }
 
-    pub async fn toggle_play_pause(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            match self.player_state.play_state {
-                PlayState::Play => handle.pause(pid).await?,
-                _ => handle.play(pid).await?,
-            }
+    pub async fn avr_volume_down(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.volume_down().await?;
         }
         Ok(())
     }
 
-    pub async fn stop(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.stop(pid).await?;
+    pub async fn avr_set_power(&self, on: bool) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            if on {
+                avr.power_on().await?;
+            } else {
+                avr.power_off().await?;
+            }
         }
         Ok(())
     }
 
-    pub async fn next_track(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.play_next(pid).await?;
+    /// Set the AVR master volume, kept under the receiver's limit
+    pub async fn avr_set_volume(&self, level: AvrVolume) -> Result<()> {
+        let Some(avr) = &self.avr_handle else {
+            anyhow::bail!("No AVR connected");
+        };
+        avr.set_volume(level.min(self.avr_volume_limit())).await
+    }
+
+    /// Highest master volume the receiver accepts
+    pub fn avr_volume_limit(&self) -> AvrVolume {
+        self.avr_state
+            .max_volume
+            .map_or(AvrVolume::MAX, |max| max.min(AvrVolume::MAX))
+    }
+
+    /// Raise the targeted zone's volume; the main zone moves by 0.5 like the remote
+    pub async fn avr_volume_half_up(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            match self.avr_state.zone {
+                AvrZone::Main => {
+                    let target = self.avr_state.master_volume.step_up();
+                    avr.set_volume(target.min(self.avr_volume_limit())).await?;
+                }
+                zone => avr.zone_volume_up(zone).await?,
+            }
         }
         Ok(())
     }
 
-    pub async fn prev_track(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.play_previous(pid).await?;
+    /// Lower the targeted zone's volume; the main zone moves by 0.5 like the remote
+    pub async fn avr_volume_half_down(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            match self.avr_state.zone {
+                AvrZone::Main => {
+                    let target = self.avr_state.master_volume.step_down();
+                    avr.set_volume(target).await?;
+                }
+                zone => avr.zone_volume_down(zone).await?,
+            }
         }
         Ok(())
     }
 
-    pub async fn volume_up(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.volume_up(pid, self.config.ui.volume_step).await?;
+    /// Move to the next zone the receiver has; Main is always there
+    pub fn cycle_avr_zone(&mut self) -> AvrZone {
+        let mut zone = self.avr_state.zone.next();
+        while zone != AvrZone::Main && !self.avr_state.zones.contains_key(&zone) {
+            zone = zone.next();
         }
-        Ok(())
+        self.avr_state.zone = zone;
+        zone
     }
 
-    pub async fn volume_down(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.volume_down(pid, self.config.ui.volume_step).await?;
+    pub async fn avr_mute_toggle(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            if self.avr_state.muted {
+                avr.mute_off().await?;
+            } else {
+                avr.mute_on().await?;
+            }
         }
         Ok(())
     }
 
-    pub async fn toggle_mute(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.toggle_mute(pid).await?;
+    /// Open the slider for bass or treble at the level the AVR reported
+    pub fn open_tone_slider(&mut self, tone: Tone) {
+        let level = match tone {
+            Tone::Bass => self.avr_state.bass,
+            Tone::Treble => self.avr_state.treble,
+        };
+        self.tone_slider = Some((tone, level.map_or(0, |db| db.round() as i8)));
+        self.show_view(View::ToneSlider);
+    }
+
+    pub fn tone_slider_move(&mut self, up: bool) {
+        if let Some((_, db)) = &mut self.tone_slider {
+            let step = if up { 1 } else { -1 };
+            *db = (*db + step).clamp(-Tone::LIMIT, Tone::LIMIT);
         }
-        Ok(())
     }
 
-    pub async fn cycle_repeat(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            let new_repeat = self.player_state.repeat.next();
-            handle
-                .set_play_mode(pid, new_repeat.as_str(), self.player_state.shuffle.as_str())
-                .await?;
+    /// Send the slider's level and close it
+    pub fn apply_tone_slider(&mut self) -> Option<(Tone, i8)> {
+        let (tone, db) = self.tone_slider.take()?;
+        if let Some(avr) = &self.avr_handle {
+            avr.set_tone(tone, db);
         }
-        Ok(())
+        self.current_view = View::SoundSettings;
+        Some((tone, db))
     }
 
-    pub async fn toggle_shuffle(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            let new_shuffle = self.player_state.shuffle.toggle();
-            handle
-                .set_play_mode(pid, self.player_state.repeat.as_str(), new_shuffle.as_str())
-                .await?;
+    pub async fn avr_query_audyssey(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.query_audyssey().await?;
         }
         Ok(())
     }
 
-    pub async fn refresh_queue(&self) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.get_queue(pid, 0, 100).await?;
+    /// Step an Audyssey setting to the next or previous value, wrapping around;
+    /// one the AVR hasn't reported yet starts from the first value
+    pub async fn avr_audyssey_step(&self, setting: AudysseySetting, forward: bool) -> Result<()> {
+        let Some(avr) = &self.avr_handle else {
+            return Ok(());
+        };
+        let state = &self.avr_state;
+        match setting {
+            AudysseySetting::MultEq => {
+                let mode = step(MultEq::all(), state.multeq, forward);
+                avr.multeq(mode).await?;
+            }
+            AudysseySetting::DynamicEq => {
+                avr.dynamic_eq(!state.dynamic_eq.unwrap_or(false)).await?;
+            }
+            AudysseySetting::ReferenceLevel => {
+                let offset = step(&REFERENCE_LEVEL_OFFSETS, state.reference_level, forward);
+                avr.reference_level(offset).await?;
+            }
+            AudysseySetting::DynamicVolume => {
+                let mode = step(DynamicVolume::all(), state.dynamic_volume, forward);
+                avr.dynamic_volume(mode).await?;
+            }
         }
         Ok(())
     }
 
-    pub async fn play_queue_item(&self, qid: i64) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.play_queue_item(pid, qid).await?;
+    pub async fn avr_bass_sync_up(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.bass_sync_up().await?;
         }
         Ok(())
     }
 
-    pub async fn refresh_music_sources(&self) -> Result<()> {
-        if let Some(handle) = &self.handle {
-            handle.get_music_sources().await?;
+    pub async fn avr_bass_sync_down(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.bass_sync_down().await?;
         }
         Ok(())
     }
 
-    pub async fn browse_source(&self, sid: i64) -> Result<()> {
-        if let Some(handle) = &self.handle {
-            handle.browse_source(sid).await?;
+    /// Flip Loudness Management from its last reported state (off if unknown)
+    pub async fn avr_loudness_management_toggle(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            let on = self.avr_state.loudness_management.unwrap_or(false);
+            avr.loudness_management(!on).await?;
         }
         Ok(())
     }
 
-    pub async fn browse_container(&self, sid: i64, cid: &str) -> Result<()> {
-        if let Some(handle) = &self.handle {
-            handle.browse_container(sid, cid).await?;
+    /// Flip Cinema EQ from its last reported state (off if unknown)
+    pub async fn avr_cinema_eq_toggle(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            let on = self.avr_state.cinema_eq.unwrap_or(false);
+            avr.cinema_eq(!on).await?;
         }
         Ok(())
     }
 
-    pub async fn select_player(&mut self, idx: usize) -> Result<()> {
-        if idx < self.players.len() {
-            self.current_player_idx = idx;
-            self.player_state = PlayerState::default();
-            if let Some(player) = self.players.get(idx) {
-                self.player_state.player = Some(player.clone());
-            }
-            self.refresh_player_state().await?;
+    /// Flip Audyssey LFC from its last reported state (off if unknown)
+    pub async fn avr_lfc_toggle(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            let on = self.avr_state.lfc.unwrap_or(false);
+            avr.lfc(!on).await?;
         }
         Ok(())
     }
 
-    pub async fn play_input(&self, input: &str) -> Result<()> {
-        if let (Some(handle), Some(pid)) = (&self.handle, self.current_pid()) {
-            handle.play_input(pid, input).await?;
+    pub async fn avr_lfc_amount_up(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.lfc_amount_up().await?;
         }
         Ok(())
     }
 
-    // ==================== AVR Commands ====================
-
-    pub async fn avr_query_status(&self) -> Result<()> {
+    pub async fn avr_lfc_amount_down(&self) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            avr.query_status().await?;
+            avr.lfc_amount_down().await?;
         }
         Ok(())
     }
 
-    pub async fn avr_set_surround_mode(&self, mode: SurroundMode) -> Result<()> {
+    pub async fn avr_query_surround_parameters(&self) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            avr.set_surround_mode(mode).await?;
+            avr.query_surround_parameters().await?;
         }
         Ok(())
     }
 
-    pub async fn avr_set_input(&self, input: &str) -> Result<()> {
-        if let Some(avr) = &self.avr_handle {
-            avr.set_input(input).await?;
+    /// Step IMAX Enhanced to its next mode
+    pub async fn avr_imax_cycle(&self) -> Result<()> {
+        if let (Some(avr), Some(mode)) = (&self.avr_handle, self.avr_state.imax) {
+            avr.imax(mode.next()).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_volume_up(&self) -> Result<()> {
-        if let Some(avr) = &self.avr_handle {
-            avr.volume_up().await?;
+    /// Step the Restorer to its next level
+    pub async fn avr_restorer_cycle(&self) -> Result<()> {
+        if let (Some(avr), Some(mode)) = (&self.avr_handle, self.avr_state.restorer) {
+            avr.restorer(mode.next()).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_volume_down(&self) -> Result<()> {
-        if let Some(avr) = &self.avr_handle {
-            avr.volume_down().await?;
+    /// Step ECO mode to its next setting
+    pub async fn avr_eco_cycle(&self) -> Result<()> {
+        if let (Some(avr), Some(mode)) = (&self.avr_handle, self.avr_state.eco) {
+            avr.eco(mode.next()).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_mute_toggle(&self) -> Result<()> {
+    pub async fn avr_set_hdmi_monitor(&self, output: HdmiMonitor) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            if self.avr_state.muted {
-                avr.mute_off().await?;
-            } else {
-                avr.mute_on().await?;
-            }
+            avr.hdmi_monitor(output).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_bass_up(&self) -> Result<()> {
+    pub async fn avr_set_dynamic_volume(&self, mode: DynamicVolume) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            avr.bass_up().await?;
+            avr.dynamic_volume(mode).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_bass_down(&self) -> Result<()> {
+    pub async fn avr_set_dynamic_compression(&self, drc: DynamicCompression) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            avr.bass_down().await?;
+            avr.dynamic_compression(drc).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_treble_up(&self) -> Result<()> {
+    pub async fn avr_recall_quick_select(&self, quick: QuickSelect) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            avr.treble_up().await?;
+            avr.quick_select(quick).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_treble_down(&self) -> Result<()> {
+    /// Store the current input, surround mode and volume in a quick select memory
+    pub async fn avr_save_quick_select(&self, quick: QuickSelect) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            avr.treble_down().await?;
+            avr.save_quick_select(quick).await?;
         }
         Ok(())
     }
 
-    pub async fn avr_dynamic_eq_toggle(&self) -> Result<()> {
+    pub async fn avr_set_sleep_timer(&self, minutes: Option<u16>) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
-            // Toggle - we'd need to track state properly
-            avr.dynamic_eq_on().await?;
+            avr.sleep_timer(minutes).await?;
         }
         Ok(())
     }
 
+    /// Time until the AVR goes to standby, counted down from its last report
+    pub fn sleep_timer_remaining(&self) -> Option<Duration> {
+        let (minutes, reported) = self.avr_state.sleep_timer?;
+        Duration::from_secs(u64::from(minutes) * 60).checked_sub(reported.elapsed())
+    }
+
     pub async fn avr_subwoofer_up(&self) -> Result<()> {
         if let Some(avr) = &self.avr_handle {
             avr.subwoofer_up().await?;
@@ -408,28 +2830,64 @@ impl App {
         Ok(())
     }
 
+    pub async fn avr_lfe_up(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.lfe_up().await?;
+        }
+        Ok(())
+    }
+
+    pub async fn avr_lfe_down(&self) -> Result<()> {
+        if let Some(avr) = &self.avr_handle {
+            avr.lfe_down().await?;
+        }
+        Ok(())
+    }
+
     // ==================== Event Handlers ====================
 
     pub fn handle_heos_event(&mut self, event: HeosEvent) {
         match event {
             HeosEvent::Connected => {
                 self.connection_state = ConnectionState::Connected;
-                self.set_status("Connected to HEOS device");
+                self.set_status(self.messages.get(Msg::Connected));
             }
             HeosEvent::Disconnected => {
                 self.connection_state = ConnectionState::Disconnected;
-                self.set_status("Disconnected from HEOS device");
+                self.set_status(self.messages.get(Msg::Disconnected));
                 self.handle = None;
+                self.signed_in_user = None;
             }
-            HeosEvent::PlayersChanged(players) => {
-                if !players.is_empty() {
-                    self.players = players;
-                }
+            HeosEvent::Reconnecting {
+                attempt,
+                delay_secs,
+            } => {
+                self.set_status(
+                    self.messages
+                        .format(Msg::Reconnecting, &[&delay_secs, &attempt]),
+                );
             }
-            HeosEvent::PlayerStateChanged { pid, state } => {
-                if self.current_pid() == Some(pid) {
-                    self.player_state.play_state = state;
+            HeosEvent::DevicesDiscovered(devices) => {
+                self.network_devices = devices;
+            }
+            HeosEvent::Discovery(progress) => match progress {
+                DiscoveryProgress::Round(round) => {
+                    self.discovery.round = round;
+                    self.discovery.targets_sent.clear();
+                }
+                DiscoveryProgress::SearchSent(target) => {
+                    if !self.discovery.targets_sent.contains(&target) {
+                        self.discovery.targets_sent.push(target);
+                    }
                 }
+                DiscoveryProgress::Responder(ip) => {
+                    if !self.discovery.responders.contains(&ip) {
+                        self.discovery.responders.push(ip);
+                    }
+                }
+            },
+            HeosEvent::PlayerStateChanged { pid, state } => {
+                self.update_player_state(pid, |s| s.play_state = state);
             }
             HeosEvent::NowPlayingChanged { pid } => {
                 if self.current_pid() == Some(pid) {
@@ -437,22 +2895,149 @@ impl App {
                 }
             }
             HeosEvent::VolumeChanged { pid, level, mute } => {
-                if self.current_pid() == Some(pid) {
-                    self.player_state.volume = level;
-                    self.player_state.mute = mute;
+                let changed = self.player_state.volume != level;
+                self.update_player_state(pid, |s| {
+                    s.volume = level;
+                    s.mute = mute;
+                });
+                if changed && self.current_pid() == Some(pid) {
+                    let text = self.heos_volume_text();
+                    self.set_status(self.messages.format(Msg::VolumeStatus, &[&text]));
                 }
             }
-            HeosEvent::PlayModeChanged { pid, repeat, shuffle } => {
+            HeosEvent::GroupVolumeChanged { gid, level } => {
+                self.group_volumes.insert(gid, level);
+            }
+            HeosEvent::PlayersChanged => {
+                // Refetched by the caller; `Players` reconciles the list
+            }
+            HeosEvent::Players(players) => self.set_players(players),
+            HeosEvent::GroupsChanged | HeosEvent::SourcesChanged => {
+                // Refetched by the caller
+            }
+            HeosEvent::PlayerInfo(player) => {
+                self.player_info = Some(player);
+                // Unless the list was left while it loaded
+                if self.current_view == View::Devices {
+                    self.show_view(View::PlayerInfo);
+                }
+            }
+            HeosEvent::PlaybackTransferred { to, skipped } => {
+                let name = self
+                    .players
+                    .iter()
+                    .find(|p| p.pid == to)
+                    .map(|p| p.name.clone());
+                match name {
+                    Some(name) if skipped > 0 => {
+                        let text = self
+                            .messages
+                            .format(Msg::PlaybackPartlyMoved, &[&name, &skipped]);
+                        self.set_status(text);
+                    }
+                    Some(name) => {
+                        self.set_status(self.messages.format(Msg::PlaybackMoved, &[&name]))
+                    }
+                    None => {}
+                }
+                if self.current_view == View::Devices {
+                    self.current_view = View::Main;
+                }
+            }
+            HeosEvent::QueueRestored { restored, failed } => {
+                let undo = self.queue_undo_pending.take();
+                if restored == 0 && failed > 0 {
+                    // Nothing made it back: keep the edit to try again
+                    self.queue_undo.extend(undo);
+                    self.set_status(self.messages.get(Msg::QueueRestoreFailed));
+                } else {
+                    let text = if failed > 0 {
+                        self.messages
+                            .format(Msg::QueuePartlyRestored, &[&restored, &failed])
+                    } else {
+                        self.messages.format(Msg::QueueRestored, &[&restored])
+                    };
+                    self.set_status(text);
+                }
+            }
+            HeosEvent::Favorites(favorites) => {
+                self.favorites = favorites;
+                self.favorite_selected = self
+                    .favorite_selected
+                    .min(self.favorites.len().saturating_sub(1));
+            }
+            HeosEvent::InputSources(mut inputs) => {
+                // The current player's own inputs first
+                let pid = self.current_pid();
+                inputs.sort_by_key(|input| Some(input.sid) != pid);
+                self.input_sources = inputs;
+                self.input_selected = self
+                    .input_selected
+                    .min(self.input_sources.len().saturating_sub(1));
+            }
+            HeosEvent::UserChanged(user) => {
+                if user != self.signed_in_user {
+                    match &user {
+                        Some(name) => self.set_status(self.messages.format(Msg::SignedIn, &[name])),
+                        None => self.set_status(self.messages.get(Msg::SignedOut)),
+                    }
+                    self.signed_in_user = user;
+                    self.forget_account_browse();
+                }
+            }
+            HeosEvent::ProgressChanged {
+                pid,
+                position_ms,
+                duration_ms,
+            } => {
+                self.update_player_state(pid, |s| {
+                    s.position_ms = position_ms;
+                    s.duration_ms = duration_ms;
+                });
                 if self.current_pid() == Some(pid) {
-                    self.player_state.repeat = repeat;
-                    self.player_state.shuffle = shuffle;
+                    self.progress_at = Some(Instant::now());
+                    // Fill in lengths the queue listing didn't report
+                    let qid = self.player_state.now_playing.qid;
+                    if let Some(item) = self.queue.iter_mut().find(|item| item.qid == qid) {
+                        if item.duration == 0 {
+                            item.duration = duration_ms;
+                        }
+                    }
                 }
             }
+            HeosEvent::PlayModeChanged { pid, repeat, shuffle } => {
+                self.update_player_state(pid, |s| {
+                    s.repeat = repeat;
+                    s.shuffle = shuffle;
+                });
+            }
             HeosEvent::QueueChanged { pid: _ } => {
                 // Trigger queue refresh if viewing queue
             }
+            HeosEvent::PlaybackError { pid, error } => {
+                let player = self
+                    .players
+                    .iter()
+                    .find(|p| p.pid == pid)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_else(|| pid.to_string());
+                // Only the current player's media can be retried with Ctrl+r
+                let msg = if self.current_pid() == Some(pid) {
+                    let media = self.player_state.now_playing.clone();
+                    self.failed_playback = Some((pid, media));
+                    Msg::PlaybackFailedRetry
+                } else {
+                    Msg::PlaybackFailed
+                };
+                self.show_toast(self.messages.format(msg, &[&player, &error]));
+                self.set_error(&error);
+            }
             HeosEvent::Error(msg) => {
-                self.set_status(format!("Error: {}", msg));
+                // Discovery or the initial connect gave up
+                if self.connection_state == ConnectionState::Discovering {
+                    self.connection_state = ConnectionState::Disconnected;
+                }
+                self.set_error(msg);
             }
             HeosEvent::Response(response) => {
                 self.handle_response(response);
@@ -464,29 +3049,141 @@ impl App {
         match event {
             AvrEvent::Connected => {
                 self.avr_state.connected = true;
-                self.set_status("AVR control connected");
+                self.avr_state.http_fallback = false;
+                self.set_status(self.messages.get(Msg::AvrConnected));
+            }
+            AvrEvent::Reconnecting {
+                attempt,
+                delay_secs,
+            } => {
+                self.set_status(
+                    self.messages
+                        .format(Msg::AvrReconnecting, &[&delay_secs, &attempt]),
+                );
+            }
+            AvrEvent::HttpFallback => {
+                self.avr_state.http_fallback = true;
+                self.set_status(self.messages.get(Msg::AvrHttpFallback));
             }
             AvrEvent::Disconnected => {
                 self.avr_state.connected = false;
                 self.avr_handle = None;
             }
             AvrEvent::MasterVolume(vol) => {
+                let changed = self.avr_state.master_volume != vol;
                 self.avr_state.master_volume = vol;
+                if changed {
+                    let text = self.avr_volume_text();
+                    self.set_status(self.messages.format(Msg::AvrVolumeStatus, &[&text]));
+                }
+            }
+            AvrEvent::MaxVolume(max) => {
+                self.avr_state.max_volume = Some(max);
             }
             AvrEvent::Mute(muted) => {
                 self.avr_state.muted = muted;
             }
             AvrEvent::Power(on) => {
                 self.avr_state.power = on;
+                // Standby ends the sleep timer, whether it ran out or not
+                if !on {
+                    self.avr_state.sleep_timer = None;
+                }
             }
             AvrEvent::SurroundMode(mode) => {
+                // Parameters of the previous mode may not apply; they're queried again
+                self.avr_state.surround_parameters.clear();
                 self.avr_state.surround_mode = mode;
             }
             AvrEvent::InputSource(input) => {
                 self.avr_state.input_source = input;
             }
+            AvrEvent::InputName(code, name) => {
+                self.avr_input_entry(code).name = name;
+            }
+            AvrEvent::InputHidden(code, hidden) => {
+                self.avr_input_entry(code).hidden = hidden;
+            }
+            AvrEvent::Bass(level) => {
+                self.avr_state.bass = Some(level);
+            }
+            AvrEvent::Treble(level) => {
+                self.avr_state.treble = Some(level);
+            }
+            AvrEvent::SubwooferLevel(level) => {
+                self.avr_state.subwoofer_level = Some(level);
+            }
+            AvrEvent::DialogEnhancer(level) => {
+                self.avr_state.dialog_enhancer = Some(level);
+            }
+            AvrEvent::BassSync(level) => {
+                self.avr_state.bass_sync = Some(level);
+            }
+            AvrEvent::LoudnessManagement(on) => {
+                self.avr_state.loudness_management = Some(on);
+            }
+            AvrEvent::CinemaEq(on) => {
+                self.avr_state.cinema_eq = Some(on);
+            }
+            AvrEvent::Lfc(on) => {
+                self.avr_state.lfc = Some(on);
+            }
+            AvrEvent::LfcAmount(amount) => {
+                self.avr_state.lfc_amount = Some(amount);
+            }
+            AvrEvent::LfeLevel(level) => {
+                self.avr_state.lfe_level = Some(level);
+            }
+            AvrEvent::DynamicCompression(drc) => {
+                self.avr_state.dynamic_compression = Some(drc);
+            }
+            AvrEvent::Headphones(plugged) => {
+                self.avr_state.headphones = plugged;
+            }
+            AvrEvent::Imax(mode) => {
+                self.avr_state.imax = Some(mode);
+            }
+            AvrEvent::Restorer(mode) => {
+                self.avr_state.restorer = Some(mode);
+            }
+            AvrEvent::HdmiMonitor(output) => {
+                self.avr_state.hdmi_monitor = Some(output);
+            }
+            AvrEvent::Eco(mode) => {
+                self.avr_state.eco = Some(mode);
+            }
+            AvrEvent::MultEq(mode) => {
+                self.avr_state.multeq = Some(mode);
+            }
+            AvrEvent::DynamicEq(on) => {
+                self.avr_state.dynamic_eq = Some(on);
+            }
+            AvrEvent::ReferenceLevel(offset) => {
+                self.avr_state.reference_level = Some(offset);
+            }
+            AvrEvent::DynamicVolume(mode) => {
+                self.avr_state.dynamic_volume = Some(mode);
+            }
+            AvrEvent::SignalInfo(field, value) => {
+                self.avr_state.signal_info.insert(field, value);
+            }
+            AvrEvent::SurroundParameter(param, value) => {
+                self.avr_state.surround_parameters.insert(param, value);
+            }
+            AvrEvent::QuickSelect(quick) => {
+                self.avr_state.quick_select = quick;
+            }
+            AvrEvent::SleepTimer(minutes) => {
+                self.avr_state.sleep_timer = minutes.map(|m| (m, Instant::now()));
+            }
+            AvrEvent::ZonePower(zone, on) => {
+                self.avr_state.zones.entry(zone).or_default().power = on;
+            }
+            AvrEvent::ZoneVolume(zone, vol) => {
+                self.avr_state.zones.entry(zone).or_default().volume = Some(vol);
+            }
             AvrEvent::Error(msg) => {
-                self.set_status(format!("AVR Error: {}", msg));
+                self.set_status(self.messages.format(Msg::AvrError, &[&msg]));
             }
             AvrEvent::Response(_) => {
                 // Generic response, ignore
@@ -494,53 +3191,257 @@ impl App {
         }
     }
 
+    /// Show a `browse` response: the first page replaces the list, later pages of the
+    /// open container are appended
+    fn apply_browse_page(
+        &mut self,
+        cmd: &str,
+        params: &HashMap<String, String>,
+        items: Vec<BrowseItem>,
+        options: Vec<ServiceOption>,
+    ) {
+        let start = params
+            .get("range")
+            .and_then(|range| range.split(',').next())
+            .and_then(|start| start.parse::<usize>().ok())
+            .unwrap_or(0);
+        let sid = params.get("sid").and_then(|sid| sid.parse::<i64>().ok());
+        let cid = params.get("cid").cloned();
+        let total = params
+            .get("count")
+            .and_then(|count| count.parse::<usize>().ok());
+
+        if start == 0 {
+            // Results for a level that was left before they arrived
+            let search = cmd.contains("search");
+            let for_open_level = self.browse_stack.last().is_some_and(|level| {
+                Some(level.sid) == sid
+                    && level.search.is_some() == search
+                    && (search || level.cid == cid)
+            });
+            if !for_open_level {
+                return;
+            }
+            self.browse_items = items;
+            self.browse_selected = 0;
+            self.browse_fetched = Instant::now();
+            self.service_options = options;
+            // Search results come in one go
+            self.browse_paging = match (sid, total) {
+                (Some(sid), Some(total)) if !cmd.contains("search") => Some(BrowsePaging {
+                    sid,
+                    cid,
+                    total,
+                    loading: false,
+                }),
+                _ => None,
+            };
+            return;
+        }
+
+        let loaded = self.browse_items.len();
+        if let Some(paging) = self.browse_paging.as_mut() {
+            // Drop pages of a container that's no longer open, or already received
+            if Some(paging.sid) == sid && paging.cid == cid && start == loaded {
+                self.browse_items.extend(items);
+                paging.total = total.unwrap_or(paging.total);
+            }
+            paging.loading = false;
+        }
+    }
+
+    /// Drop browse results that belong to the previous HEOS account
+    fn forget_account_browse(&mut self) {
+        self.favorites.clear();
+        self.favorite_selected = 0;
+        self.search_sid = None;
+        self.search_criteria.clear();
+        if self
+            .browse_stack
+            .first()
+            .is_some_and(|level| is_heos_sid(level.sid))
+        {
+            self.browse_stack.clear();
+            self.browse_items.clear();
+            self.browse_paging = None;
+            self.browse_selected = 0;
+            if matches!(self.current_view, View::BrowseMenu | View::SearchCriteria) {
+                self.current_view = View::Browse;
+            }
+        }
+        self.browse_cache.retain(|key, _| !is_heos_sid(key.0));
+    }
+
     fn handle_response(&mut self, response: crate::heos::protocol::HeosResponse) {
-        if !response.is_success() {
+        // Account state: "signed_in&un=<user>" or "signed_out"
+        let cmd = &response.heos.command;
+        if cmd.contains("check_account") || cmd.contains("sign_in") || cmd.contains("sign_out") {
             let params = response.parse_message();
-            if let Some(text) = params.get("text") {
-                self.set_status(format!("Error: {}", text));
+            if params.contains_key("signed_in") {
+                self.signed_in_user = params.get("un").cloned();
+                if cmd.contains("sign_in") {
+                    let user = self.signed_in_user.clone().unwrap_or_default();
+                    self.set_status(self.messages.format(Msg::SignedIn, &[&user]));
+                }
+            } else if params.contains_key("signed_out") {
+                self.signed_in_user = None;
+                if cmd.contains("sign_out") {
+                    self.set_status(self.messages.get(Msg::SignedOut));
+                }
+            }
+        }
+
+        if let Some(error) = HeosError::from_response(&response) {
+            if error == HeosError::NotSignedIn {
+                // Signed out elsewhere, e.g. in the HEOS app
+                self.signed_in_user = None;
             }
+            self.set_error(error);
+            return;
+        }
+        if !response.is_success() {
             return;
         }
 
         let cmd = &response.heos.command;
 
-        if cmd.contains("get_players") {
-            if let Some(players) = response.get_payload_array::<Player>() {
-                self.players = players;
-                if !self.players.is_empty() && self.player_state.player.is_none() {
-                    self.player_state.player = Some(self.players[0].clone());
+        // The queue and quick selects are kept for the current player only; an answer
+        // for another player, e.g. one just switched away from, would overwrite them
+        if cmd.contains("get_queue") || cmd.contains("get_quickselects") {
+            let pid = response
+                .parse_message()
+                .get("pid")
+                .and_then(|s| s.parse().ok());
+            if pid.is_some() && pid != self.current_pid() {
+                return;
+            }
+        }
+
+        if cmd.contains("set_service_option") {
+            let params = response.parse_message();
+            match params.get("option").map(String::as_str) {
+                Some("11") => self.set_status(self.messages.get(Msg::ThumbsUpSent)),
+                Some("12") => self.set_status(self.messages.get(Msg::ThumbsDownSent)),
+                Some(id) if response.is_success() => {
+                    let option = self.service_options.iter().find(|o| o.id.to_string() == id);
+                    if let Some(option) = option {
+                        let text = self
+                            .messages
+                            .format(Msg::ServiceOptionDone, &[&option.name]);
+                        self.set_status(text);
+                    }
                 }
+                _ => {}
             }
         } else if cmd.contains("get_play_state") {
             let params = response.parse_message();
             if let Some(state) = params.get("state") {
-                self.player_state.play_state = PlayState::from_str(state);
+                let state = PlayState::from_str(state);
+                let pid = params.get("pid").and_then(|s| s.parse().ok());
+                match pid {
+                    Some(pid) => self.update_player_state(pid, |s| s.play_state = state),
+                    None => self.player_state.play_state = state,
+                }
             }
         } else if cmd.contains("get_now_playing_media") {
-            if let Some(media) = response.get_payload_object::<NowPlayingMedia>() {
+            let pid = response
+                .parse_message()
+                .get("pid")
+                .and_then(|s| s.parse().ok())
+                .or(self.current_pid());
+            let media = response.get_payload_object::<NowPlayingMedia>();
+            if let (Some(pid), Some(media)) = (pid, &media) {
+                self.player_states.entry(pid).or_default().now_playing = media.clone();
+            }
+            // Toasts and timing only follow the current player
+            if let Some(media) = media.filter(|_| pid == self.current_pid()) {
+                let previous = &self.player_state.now_playing;
+                let changed = media.mid != previous.mid
+                    || media.song != previous.song
+                    || media.station != previous.station;
+                // Ticker for track changes the main view isn't showing
+                let is_new_track = changed && self.now_playing_since.is_some();
+                if is_new_track && self.current_view != View::Main && !media.song.is_empty() {
+                    let title = if media.artist.is_empty() {
+                        media.song.clone()
+                    } else {
+                        format!("{} – {}", media.song, media.artist)
+                    };
+                    self.show_toast(self.messages.format(Msg::NowPlayingToast, &[&title]));
+                }
+                if changed || self.now_playing_since.is_none() {
+                    self.now_playing_since = Some(Instant::now());
+                }
                 self.player_state.now_playing = media;
             }
+        } else if cmd.contains("get_groups") {
+            if let Some(groups) = response.get_payload_array::<Group>() {
+                self.groups = groups;
+                self.group_selected = self.group_selected.min(self.groups.len().saturating_sub(1));
+            }
+        } else if cmd.contains("get_group_info") {
+            if let Some(group) = response.get_payload_object::<Group>() {
+                match self.groups.iter_mut().find(|g| g.gid == group.gid) {
+                    Some(existing) => *existing = group,
+                    None => self.groups.push(group),
+                }
+            }
+        } else if cmd.starts_with("group/") {
+            let params = response.parse_message();
+            let gid = params.get("gid").and_then(|s| s.parse().ok());
+            let level = params.get("level").and_then(|s| s.parse().ok());
+            if let (Some(gid), Some(level)) = (gid, level) {
+                self.group_volumes.insert(gid, level);
+            }
         } else if cmd.contains("get_volume") || cmd.contains("volume_up") || cmd.contains("volume_down") {
             let params = response.parse_message();
             if let Some(level) = params.get("level").and_then(|s| s.parse().ok()) {
-                self.player_state.volume = level;
+                let pid = params.get("pid").and_then(|s| s.parse().ok());
+                match pid {
+                    Some(pid) => self.update_player_state(pid, |s| s.volume = level),
+                    None => self.player_state.volume = level,
+                }
             }
         } else if cmd.contains("get_mute") || cmd.contains("set_mute") || cmd.contains("toggle_mute") {
             let params = response.parse_message();
             if let Some(state) = params.get("state") {
-                self.player_state.mute = MuteState::from_str(state);
+                let mute = MuteState::from_str(state);
+                let pid = params.get("pid").and_then(|s| s.parse().ok());
+                match pid {
+                    Some(pid) => self.update_player_state(pid, |s| s.mute = mute),
+                    None => self.player_state.mute = mute,
+                }
             }
         } else if cmd.contains("get_play_mode") || cmd.contains("set_play_mode") {
             let params = response.parse_message();
-            if let Some(repeat) = params.get("repeat") {
-                self.player_state.repeat = RepeatMode::from_str(repeat);
+            let pid = params
+                .get("pid")
+                .and_then(|s| s.parse().ok())
+                .or(self.current_pid());
+            if let (Some(pid), Some(repeat), Some(shuffle)) =
+                (pid, params.get("repeat"), params.get("shuffle"))
+            {
+                let repeat = RepeatMode::from_str(repeat);
+                let shuffle = ShuffleMode::from_str(shuffle);
+                self.update_player_state(pid, |s| {
+                    s.repeat = repeat;
+                    s.shuffle = shuffle;
+                });
             }
-            if let Some(shuffle) = params.get("shuffle") {
-                self.player_state.shuffle = ShuffleMode::from_str(shuffle);
+        } else if cmd.contains("get_quickselects") {
+            if let Some(slots) = response.get_payload_array::<QuickSelectSlot>() {
+                self.quickselects = slots;
+                self.quickselect_selected = self
+                    .quickselect_selected
+                    .min(self.quickselects.len().saturating_sub(1));
             }
         } else if cmd.contains("get_queue") {
-            if let Some(queue) = response.get_payload_array::<QueueItem>() {
+            if let Some(mut queue) = response.get_payload_array::<QueueItem>() {
+                // Keep lengths learned from progress events while the tracks stay the same
+                for item in queue.iter_mut().filter(|item| item.duration == 0) {
+                    let known = self.queue.iter().find(|old| old.mid == item.mid);
+                    item.duration = known.map_or(0, |old| old.duration);
+                }
                 self.queue = queue;
             }
         } else if cmd.contains("get_music_sources") {
@@ -550,16 +3451,74 @@ impl App {
                     .filter(|s| s.source_type != "heos_server")
                     .cloned()
                     .collect();
+                if !self.local_playlists.is_empty() {
+                    let name = self.messages.get(Msg::LocalPlaylists);
+                    self.music_sources.push(playlist::local_source(name));
+                }
+                self.music_sources.extend(self.plugins.sources());
                 self.inputs = sources
                     .into_iter()
                     .filter(|s| s.source_type == "heos_server" || s.name.contains("Input"))
                     .collect();
             }
+        } else if cmd.contains("get_search_criteria") {
+            if let Some(criteria) = response.get_payload_array::<SearchCriteria>() {
+                self.search_criteria = criteria;
+                self.search_criteria_selected = 0;
+                if self.search_criteria.is_empty() {
+                    self.set_status(self.messages.get(Msg::SearchUnavailable));
+                } else if self.current_view == View::Browse {
+                    self.current_view = View::SearchCriteria;
+                }
+            }
         } else if cmd.contains("browse") {
             if let Some(items) = response.get_payload_array::<BrowseItem>() {
-                self.browse_items = items;
-                self.browse_selected = 0;
+                if cmd.contains("search") && items.is_empty() {
+                    self.set_status(self.messages.get(Msg::SearchNoResults));
+                }
+                // Only Browse sends browse commands. The favorites and input lists request
+                // theirs, and a request's numbered response goes back to it, not here
+                let params = response.parse_message();
+                self.apply_browse_page(cmd, &params, items, response.get_options());
             }
         }
     }
 }
+
+/// Whether a browse source is served by the HEOS device, rather than locally or by a plugin
+fn is_heos_sid(sid: i64) -> bool {
+    sid != LOCAL_PLAYLISTS_SID && !PluginHost::is_plugin_sid(sid)
+}
+
+/// The value after (or before) `current` in `values`, wrapping around
+fn step<T: Copy + PartialEq>(values: &[T], current: Option<T>, forward: bool) -> T {
+    let len = values.len();
+    let next = match current.and_then(|c| values.iter().position(|v| *v == c)) {
+        Some(i) if forward => (i + 1) % len,
+        Some(i) => (i + len - 1) % len,
+        None => 0,
+    };
+    values[next]
+}
+
+/// Where the last selected player is kept, next to the history
+fn last_pid_path() -> PathBuf {
+    History::path().with_file_name("last_player")
+}
+
+fn load_last_pid() -> Option<i64> {
+    std::fs::read_to_string(last_pid_path())
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn save_last_pid(pid: i64) -> Result<()> {
+    let path = last_pid_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, pid.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::heos::AvrVolume;
+use anyhow::{bail, Context, Result};
+
+/// A text command, as typed at the `:` prompt or piped in on stdin
+///
+/// Grammar, one command per line (`#` starts a comment):
+///
+/// ```text
+/// play | pause | toggle | stop | next | prev
+/// volume <0-100> | volume up | volume down
+/// avr-volume <0-98, in steps of 0.5>     (TUI only; --pipe doesn't connect the AVR)
+/// mute [on|off]
+/// player <name or pid>
+/// url <stream url>
+/// input <input name, e.g. inputs/aux_in_1>
+/// signout
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Play,
+    Pause,
+    TogglePlay,
+    Stop,
+    Next,
+    Prev,
+    Volume(u8),
+    VolumeUp,
+    VolumeDown,
+    /// AVR master volume, which takes half steps unlike HEOS volume
+    AvrVolume(AvrVolume),
+    /// `None` toggles
+    Mute(Option<bool>),
+    Player(String),
+    /// Move what's playing to another player and control that one
+    Move(String),
+    PlayUrl(String),
+    Input(String),
+    SignOut,
+}
+
+impl Command {
+    /// Parse one line; blank lines and comments give `None`
+    pub fn parse(line: &str) -> Result<Option<Self>> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let (name, arg) = match line.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, arg.trim()),
+            None => (line, ""),
+        };
+
+        let command = match (name.to_lowercase().as_str(), arg) {
+            ("play", "") => Command::Play,
+            ("pause", "") => Command::Pause,
+            ("toggle", "") => Command::TogglePlay,
+            ("stop", "") => Command::Stop,
+            ("next", "") => Command::Next,
+            ("prev" | "previous", "") => Command::Prev,
+            ("volume" | "vol", "up" | "+") => Command::VolumeUp,
+            ("volume" | "vol", "down" | "-") => Command::VolumeDown,
+            ("volume" | "vol", level) => {
+                let level: u8 = level
+                    .parse()
+                    .with_context(|| format!("Invalid volume '{}'", level))?;
+                if level > 100 {
+                    bail!("Volume must be 0-100");
+                }
+                Command::Volume(level)
+            }
+            ("avr-volume" | "avol", level) => {
+                let level: f32 = level
+                    .parse()
+                    .with_context(|| format!("Invalid AVR volume '{}'", level))?;
+                if !(0.0..=98.0).contains(&level) || (level * 2.0).fract() != 0.0 {
+                    bail!("AVR volume must be 0-98 in steps of 0.5");
+                }
+                Command::AvrVolume(AvrVolume::from_level(level))
+            }
+            ("mute", "") => Command::Mute(None),
+            ("mute", "on") => Command::Mute(Some(true)),
+            ("mute", "off") => Command::Mute(Some(false)),
+            ("unmute", "") => Command::Mute(Some(false)),
+            ("player", name) if !name.is_empty() => Command::Player(name.to_string()),
+            ("move", name) if !name.is_empty() => Command::Move(name.to_string()),
+            ("url", url) if !url.is_empty() => Command::PlayUrl(url.to_string()),
+            ("input", input) if !input.is_empty() => Command::Input(input.to_string()),
+            ("signout" | "sign-out", "") => Command::SignOut,
+            _ => bail!("Unknown command '{}'", line),
+        };
+        Ok(Some(command))
+    }
+
+    /// Whether the command sends a request to the HEOS device (and gets a response)
+    pub fn is_remote(&self) -> bool {
+        !matches!(self, Command::Player(_) | Command::AvrVolume(_))
+    }
+}
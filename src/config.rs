@@ -1,5 +1,7 @@
+use crate::heos::{AvrVolume, DiscoveredDevice};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -8,6 +10,112 @@ pub struct Config {
     pub connection: ConnectionConfig,
     #[serde(default)]
     pub ui: UiConfig,
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+    /// State publishing to an MQTT broker; disabled when the section is absent
+    #[serde(default)]
+    pub mqtt: Option<MqttConfig>,
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+    #[serde(default)]
+    pub hooks: Vec<HookConfig>,
+    /// Per-player overrides, keyed by player name: `[device."Living Room"]`
+    #[serde(default)]
+    pub device: HashMap<String, DeviceConfig>,
+    #[serde(default)]
+    pub avr: AvrConfig,
+    #[serde(default)]
+    pub avr_presets: Vec<AvrPreset>,
+    #[serde(default)]
+    pub account: AccountConfig,
+}
+
+/// HEOS account signed in to on connect; streaming services need one
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountConfig {
+    pub username: Option<String>,
+    /// Stored in plain text, so the config file is written readable by its owner only (0600);
+    /// leave it out to sign in from the prompt (K) instead
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AvrConfig {
+    /// Master volume set when headphones are plugged in, 0-98 in 0.5 steps
+    pub headphone_volume: Option<f32>,
+}
+
+/// A named AVR scene applied as one batch from the Presets popup; unset fields are left alone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvrPreset {
+    pub name: String,
+    /// Input code as sent after `SI`, e.g. "BD", "GAME", "TV"
+    pub input: Option<String>,
+    /// Surround mode as sent after `MS`, e.g. "MOVIE", "STEREO"
+    pub surround: Option<String>,
+    /// Absolute master volume, 0-98 in 0.5 steps
+    pub volume: Option<f32>,
+    /// Tone adjustments in dB, -6 to +6; turns tone control on
+    pub bass: Option<i8>,
+    pub treble: Option<i8>,
+    pub dynamic_eq: Option<bool>,
+}
+
+impl AvrPreset {
+    /// AVR commands for the preset, in the order they should be sent
+    pub fn commands(&self) -> Vec<String> {
+        let mut commands = Vec::new();
+        // Input first: switching inputs can reset the surround mode
+        if let Some(input) = &self.input {
+            commands.push(format!("SI{}", input.to_uppercase()));
+        }
+        if let Some(surround) = &self.surround {
+            commands.push(format!("MS{}", surround.to_uppercase()));
+        }
+        if let Some(volume) = self.volume {
+            let volume = AvrVolume::from_level(volume);
+            commands.push(format!("MV{}", volume.command_value()));
+        }
+        if self.bass.is_some() || self.treble.is_some() {
+            commands.push("PSTONE CTRL ON".to_string());
+        }
+        // Tone levels are offset so 50 is 0 dB
+        if let Some(bass) = self.bass {
+            commands.push(format!("PSBAS {}", 50 + bass.clamp(-6, 6)));
+        }
+        if let Some(treble) = self.treble {
+            commands.push(format!("PSTRE {}", 50 + treble.clamp(-6, 6)));
+        }
+        if let Some(on) = self.dynamic_eq {
+            commands.push(format!("PSDYNEQ {}", if on { "ON" } else { "OFF" }));
+        }
+        commands
+    }
+}
+
+/// Settings applied while a specific player is selected
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceConfig {
+    /// Overrides `ui.volume_step`
+    pub volume_step: Option<u8>,
+    /// Volume up and absolute volume commands never go above this
+    pub max_volume: Option<u8>,
+    /// Input played when the player is picked in the device selector
+    pub default_input: Option<String>,
+    /// Accent color name or `#rrggbb`, replacing the theme's accent
+    pub accent: Option<String>,
+}
+
+/// A saved browse location: a music source, optionally a container inside it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    pub sid: i64,
+    /// Name of the music source, shown in the breadcrumb when opened
+    #[serde(default)]
+    pub source_name: String,
+    #[serde(default)]
+    pub cid: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +125,21 @@ pub struct ConnectionConfig {
     pub discovery_timeout: u64,
     #[serde(default = "default_reconnect_delay")]
     pub reconnect_delay: u64,
+    /// Seconds between background discovery rounds after startup; 0 disables them
+    #[serde(default = "default_rediscover_interval")]
+    pub rediscover_interval: u64,
+    /// Seconds between player list refreshes, on top of `players_changed` events; 0 disables them
+    #[serde(default = "default_players_refresh_interval")]
+    pub players_refresh_interval: u64,
+    /// Devices that are always offered, even when SSDP discovery finds nothing
+    #[serde(default)]
+    pub known_devices: Vec<KnownDevice>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownDevice {
+    pub name: String,
+    pub host: String,
 }
 
 impl Default for ConnectionConfig {
@@ -25,6 +148,9 @@ impl Default for ConnectionConfig {
             host: None,
             discovery_timeout: default_discovery_timeout(),
             reconnect_delay: default_reconnect_delay(),
+            rediscover_interval: default_rediscover_interval(),
+            players_refresh_interval: default_players_refresh_interval(),
+            known_devices: Vec::new(),
         }
     }
 }
@@ -37,12 +163,166 @@ fn default_reconnect_delay() -> u64 {
     3
 }
 
+fn default_players_refresh_interval() -> u64 {
+    60
+}
+
+fn default_rediscover_interval() -> u64 {
+    60
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttConfig {
+    pub host: String,
+    #[serde(default = "default_mqtt_port")]
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default = "default_mqtt_client_id")]
+    pub client_id: String,
+    #[serde(default = "default_now_playing_topic")]
+    pub now_playing_topic: String,
+    #[serde(default = "default_volume_topic")]
+    pub volume_topic: String,
+    #[serde(default = "default_power_topic")]
+    pub power_topic: String,
+    /// Payloads use the `:` command grammar, e.g. `volume 20` or `pause`
+    #[serde(default = "default_command_topic")]
+    pub command_topic: String,
+}
+
+fn default_mqtt_port() -> u16 {
+    1883
+}
+
+fn default_mqtt_client_id() -> String {
+    "heos-tui".to_string()
+}
+
+fn default_now_playing_topic() -> String {
+    "heos-tui/now_playing".to_string()
+}
+
+fn default_volume_topic() -> String {
+    "heos-tui/volume".to_string()
+}
+
+fn default_power_topic() -> String {
+    "heos-tui/power".to_string()
+}
+
+fn default_command_topic() -> String {
+    "heos-tui/command".to_string()
+}
+
+/// A URL that receives a JSON POST on playback events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Events to send: "track", "play_state", "error", "connected", "disconnected",
+    /// "volume"; "track", "play_state" and "error" when empty
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Payload with `{{field}}` placeholders, filled with JSON-escaped values
+    pub template: Option<String>,
+    /// Extra attempts after a failed request
+    #[serde(default = "default_webhook_retries")]
+    pub retries: u32,
+}
+
+fn default_webhook_retries() -> u32 {
+    2
+}
+
+/// Events sent to a webhook that doesn't list any; volume steps would POST once per step
+const DEFAULT_WEBHOOK_EVENTS: [&str; 3] = ["track", "play_state", "error"];
+
+impl WebhookConfig {
+    /// Whether the webhook is subscribed to the named event
+    pub fn wants(&self, event: &str) -> bool {
+        if self.events.is_empty() {
+            DEFAULT_WEBHOOK_EVENTS.contains(&event)
+        } else {
+            self.events.iter().any(|e| e == event)
+        }
+    }
+}
+
+/// A shell command run on an event, with details in `HEOS_*` environment variables
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookConfig {
+    /// Same event names as webhooks
+    pub event: String,
+    pub command: String,
+    /// For "volume": only run when the volume rises to or above this level
+    pub threshold: Option<u8>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiConfig {
     #[serde(default = "default_volume_step")]
     pub volume_step: u8,
     #[serde(default = "default_refresh_rate")]
     pub refresh_rate: u64,
+    #[serde(default)]
+    pub theme: ThemePreset,
+    #[serde(default = "default_locale")]
+    pub locale: String,
+    #[serde(default)]
+    pub heos_volume_unit: HeosVolumeUnit,
+    #[serde(default)]
+    pub avr_volume_unit: AvrVolumeUnit,
+    /// Show the current time in the title bar
+    #[serde(default)]
+    pub show_clock: bool,
+    /// Show how long the current track/station has been playing in the title bar
+    #[serde(default)]
+    pub show_elapsed: bool,
+    /// Player names or pids left out of the Devices list
+    #[serde(default)]
+    pub ignored_players: Vec<String>,
+    /// Player selected at startup when none is playing; else the one selected last time
+    #[serde(default)]
+    pub default_player: Option<String>,
+    /// Switch to whichever player most recently started playing
+    #[serde(default)]
+    pub follow_playback: bool,
+    /// Fade the volume out and in over about a second when muting, unmuting or stopping
+    #[serde(default)]
+    pub soft_volume: bool,
+    /// Seconds a browsed list is kept, so going back to it doesn't refetch it
+    #[serde(default = "default_browse_cache_ttl")]
+    pub browse_cache_ttl: u64,
+}
+
+/// Built-in color schemes, selected with `theme = "..."` under `[ui]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemePreset {
+    #[default]
+    Default,
+    HighContrast,
+    Mono,
+}
+
+/// How HEOS player volume is displayed: the raw 0-100 level or a pseudo-dB scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeosVolumeUnit {
+    #[default]
+    Percent,
+    Db,
+}
+
+/// How AVR master volume is displayed: the absolute 0-98 value, dB relative to reference,
+/// or both as "50.5 (-29.5dB)"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum AvrVolumeUnit {
+    Absolute,
+    #[default]
+    Db,
+    Both,
 }
 
 impl Default for UiConfig {
@@ -50,6 +330,17 @@ impl Default for UiConfig {
         Self {
             volume_step: default_volume_step(),
             refresh_rate: default_refresh_rate(),
+            theme: ThemePreset::default(),
+            locale: default_locale(),
+            heos_volume_unit: HeosVolumeUnit::default(),
+            avr_volume_unit: AvrVolumeUnit::default(),
+            show_clock: false,
+            show_elapsed: false,
+            ignored_players: Vec::new(),
+            default_player: None,
+            follow_playback: false,
+            soft_volume: false,
+            browse_cache_ttl: default_browse_cache_ttl(),
         }
     }
 }
@@ -58,10 +349,18 @@ fn default_volume_step() -> u8 {
     5
 }
 
+fn default_browse_cache_ttl() -> u64 {
+    300
+}
+
 fn default_refresh_rate() -> u64 {
     250
 }
 
+fn default_locale() -> String {
+    "en".to_string()
+}
+
 impl Config {
     pub fn load() -> Result<Self> {
         let path = Self::config_path();
@@ -80,10 +379,56 @@ impl Config {
             std::fs::create_dir_all(parent)?;
         }
         let contents = toml::to_string_pretty(self)?;
-        std::fs::write(&path, contents)?;
+        Self::write_private(&path, &contents)?;
         Ok(())
     }
 
+    /// Write the file readable by its owner only; it can hold account and broker passwords
+    #[cfg(unix)]
+    fn write_private(path: &std::path::Path, contents: &str) -> Result<()> {
+        use std::io::Write;
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        // mode() only applies to new files; tighten one written by an older version
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        file.write_all(contents.as_bytes())?;
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn write_private(path: &std::path::Path, contents: &str) -> Result<()> {
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Configured known devices in the shape discovery returns
+    pub fn known_devices(&self) -> Vec<DiscoveredDevice> {
+        self.connection
+            .known_devices
+            .iter()
+            .map(|d| DiscoveredDevice {
+                ip: d.host.clone(),
+                location: String::new(),
+                friendly_name: Some(d.name.clone()),
+            })
+            .collect()
+    }
+
+    /// Directory holding local playlist files
+    pub fn playlists_dir() -> PathBuf {
+        Self::config_path().with_file_name("playlists")
+    }
+
+    /// Directory scanned for plugin executables
+    pub fn plugins_dir() -> PathBuf {
+        Self::config_path().with_file_name("plugins")
+    }
+
     pub fn config_path() -> PathBuf {
         dirs::config_dir()
             .unwrap_or_else(|| PathBuf::from("."))
@@ -1,5 +1,5 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
-use std::time::Duration;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers, MediaKeyCode};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 #[derive(Debug, Clone)]
@@ -64,15 +64,58 @@ pub enum Action {
     PrevTrack,
     VolumeUp,
     VolumeDown,
+    SetVolume,
+    AvrVolumeUp,
+    AvrVolumeDown,
+    CycleZone,
+    ToggleAvrPower,
     ToggleMute,
     CycleRepeat,
     ToggleShuffle,
+    ToggleVolumeUnit,
+    ToggleFollowPlayback,
+    ThumbsUp,
+    ThumbsDown,
+    /// Start the media that last failed to play again
+    RetryPlayback,
+    ToggleAvrPanel,
     ShowDevices,
     ShowQueue,
     ShowBrowse,
     ShowInputs,
     ShowSurroundModes,
     ShowSoundSettings,
+    ShowUpmixers,
+    ShowGroupMixer,
+    ShowGroups,
+    GroupPlayers,
+    UngroupPlayer,
+    /// Move what's playing to the highlighted player
+    TransferPlayback,
+    ShowAvrPresets,
+    ShowAvrInputs,
+    ShowHdmiMonitor,
+    ShowAvrQuickSelect,
+    ShowBookmarks,
+    ShowFavorites,
+    ShowQuickSelects,
+    ShowNowPlaying,
+    ShowStats,
+    ShowHistory,
+    ShowPluginActions,
+    AddBookmark,
+    DeleteItem,
+    ClearQueue,
+    OpenLink,
+    CommandMode,
+    SignIn,
+    Search,
+    ShowServiceOptions,
+    ExportQueue,
+    ImportUrls,
+    PlayUrl,
+    PlayPlaylist,
+    QueuePlaylist,
     ShowHelp,
     Back,
     Select,
@@ -81,6 +124,13 @@ pub enum Action {
     MoveLeft,
     MoveRight,
     Refresh,
+    Digit(u8),
+    JumpTop,
+    JumpBottom,
+    /// Select the playing track in the queue
+    JumpNowPlaying,
+    /// Open the nth (1-based) bookmark
+    OpenBookmark(u8),
 }
 
 impl Action {
@@ -89,6 +139,7 @@ impl Action {
             (KeyCode::Char('q'), _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
                 Some(Action::Quit)
             }
+            (KeyCode::Char('p'), KeyModifiers::CONTROL) => Some(Action::ToggleAvrPower),
             (KeyCode::Char(' '), _) | (KeyCode::Char('p'), _) => Some(Action::PlayPause),
             (KeyCode::Char('s'), _) => Some(Action::Stop),
             (KeyCode::Char('n'), _) | (KeyCode::Right, KeyModifiers::CONTROL) => {
@@ -97,17 +148,68 @@ impl Action {
             (KeyCode::Char('b'), _) | (KeyCode::Left, KeyModifiers::CONTROL) => {
                 Some(Action::PrevTrack)
             }
+            // Dedicated media keys, only reported by terminals with keyboard enhancement
+            (
+                KeyCode::Media(MediaKeyCode::PlayPause | MediaKeyCode::Play | MediaKeyCode::Pause),
+                _,
+            ) => Some(Action::PlayPause),
+            (KeyCode::Media(MediaKeyCode::Stop), _) => Some(Action::Stop),
+            (KeyCode::Media(MediaKeyCode::TrackNext), _) => Some(Action::NextTrack),
+            (KeyCode::Media(MediaKeyCode::TrackPrevious), _) => Some(Action::PrevTrack),
+            (KeyCode::Media(MediaKeyCode::RaiseVolume), _) => Some(Action::VolumeUp),
+            (KeyCode::Media(MediaKeyCode::LowerVolume), _) => Some(Action::VolumeDown),
+            (KeyCode::Media(MediaKeyCode::MuteVolume), _) => Some(Action::ToggleMute),
             (KeyCode::Char('+'), _) | (KeyCode::Char('='), _) => Some(Action::VolumeUp),
             (KeyCode::Char('-'), _) => Some(Action::VolumeDown),
+            (KeyCode::Char('v'), _) => Some(Action::SetVolume),
+            (KeyCode::Char(']'), _) => Some(Action::AvrVolumeUp),
+            (KeyCode::Char('['), _) => Some(Action::AvrVolumeDown),
+            (KeyCode::Char('Z'), _) => Some(Action::CycleZone),
             (KeyCode::Char('m'), _) => Some(Action::ToggleMute),
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => Some(Action::RetryPlayback),
             (KeyCode::Char('r'), _) => Some(Action::CycleRepeat),
             (KeyCode::Char('z'), _) => Some(Action::ToggleShuffle),
+            (KeyCode::Char('U'), _) => Some(Action::ToggleVolumeUnit),
+            (KeyCode::Char('F'), _) => Some(Action::ToggleFollowPlayback),
+            (KeyCode::Char('t'), _) => Some(Action::ThumbsUp),
+            (KeyCode::Char('D'), _) => Some(Action::ThumbsDown),
+            (KeyCode::Char('V'), _) => Some(Action::ToggleAvrPanel),
             (KeyCode::Char('d'), _) => Some(Action::ShowDevices),
+            (KeyCode::Char('G'), _) => Some(Action::ShowGroupMixer),
+            (KeyCode::Char('R'), _) => Some(Action::ShowGroups),
+            (KeyCode::Char('J'), _) => Some(Action::GroupPlayers),
+            (KeyCode::Char('X'), _) => Some(Action::UngroupPlayer),
+            (KeyCode::Char('H'), _) => Some(Action::TransferPlayback),
             (KeyCode::Char('u'), _) => Some(Action::ShowQueue),
             (KeyCode::Char('o'), _) => Some(Action::ShowBrowse),
             (KeyCode::Char('i'), _) => Some(Action::ShowInputs),
+            (KeyCode::Char('C'), _) => Some(Action::ShowAvrInputs),
+            (KeyCode::Char('N'), _) => Some(Action::ShowHdmiMonitor),
             (KeyCode::Char('a'), _) => Some(Action::ShowSurroundModes),
             (KeyCode::Char('w'), _) => Some(Action::ShowSoundSettings),
+            (KeyCode::Char('M'), _) => Some(Action::ShowUpmixers),
+            (KeyCode::Char('S'), _) => Some(Action::ShowAvrPresets),
+            (KeyCode::Char('Y'), _) => Some(Action::ShowAvrQuickSelect),
+            (KeyCode::Char('O'), _) => Some(Action::ShowBookmarks),
+            (KeyCode::Char('f'), _) => Some(Action::ShowFavorites),
+            (KeyCode::Char('y'), _) => Some(Action::ShowQuickSelects),
+            (KeyCode::Char('B'), _) => Some(Action::AddBookmark),
+            (KeyCode::Delete, _) => Some(Action::DeleteItem),
+            (KeyCode::Char('c'), _) => Some(Action::ClearQueue),
+            (KeyCode::Char('L'), _) => Some(Action::OpenLink),
+            (KeyCode::Char('I'), _) => Some(Action::ShowNowPlaying),
+            (KeyCode::Char('T'), _) => Some(Action::ShowStats),
+            (KeyCode::Char('P'), _) => Some(Action::ShowHistory),
+            (KeyCode::Char('x'), _) => Some(Action::ShowPluginActions),
+            (KeyCode::Char(':'), _) => Some(Action::CommandMode),
+            (KeyCode::Char('K'), _) => Some(Action::SignIn),
+            (KeyCode::Char('/'), _) => Some(Action::Search),
+            (KeyCode::Char('.'), _) => Some(Action::ShowServiceOptions),
+            (KeyCode::Char('e'), _) => Some(Action::ExportQueue),
+            (KeyCode::Char('E'), _) => Some(Action::ImportUrls),
+            (KeyCode::Char('W'), _) => Some(Action::PlayUrl),
+            (KeyCode::Char('A'), _) => Some(Action::PlayPlaylist),
+            (KeyCode::Char('Q'), _) => Some(Action::QueuePlaylist),
             (KeyCode::Char('?'), _) | (KeyCode::F(1), _) => Some(Action::ShowHelp),
             (KeyCode::Esc, _) => Some(Action::Back),
             (KeyCode::Enter, _) => Some(Action::Select),
@@ -116,7 +218,79 @@ impl Action {
             (KeyCode::Left, _) | (KeyCode::Char('h'), _) => Some(Action::MoveLeft),
             (KeyCode::Right, _) | (KeyCode::Char('l'), _) => Some(Action::MoveRight),
             (KeyCode::F(5), _) => Some(Action::Refresh),
+            (KeyCode::Char(c @ '0'..='9'), _) => Some(Action::Digit(c as u8 - b'0')),
             _ => None,
         }
     }
 }
+
+/// Key that starts leader-key sequences
+pub const LEADER_KEY: char = '\\';
+
+/// How long a started key sequence waits for its next key
+const CHORD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Outcome of feeding a key press into [`KeyChord`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChordStep {
+    /// Waiting for the next key of a sequence
+    Pending,
+    /// A sequence completed
+    Action(Action),
+    /// A started sequence didn't match; the key is dropped
+    Cancelled,
+    /// Not part of a sequence, handle as a single key
+    Single,
+}
+
+/// Multi-key sequences like `g g` or `<leader> 1`
+#[derive(Debug, Default)]
+pub struct KeyChord {
+    pending: Option<(char, Instant)>,
+}
+
+impl KeyChord {
+    pub fn feed(&mut self, key: KeyEvent) -> ChordStep {
+        let KeyCode::Char(c) = key.code else {
+            return match self.pending.take() {
+                Some(_) => ChordStep::Cancelled,
+                None => ChordStep::Single,
+            };
+        };
+        match self.pending.take() {
+            None if c == 'g' || c == LEADER_KEY => {
+                self.pending = Some((c, Instant::now()));
+                ChordStep::Pending
+            }
+            None => ChordStep::Single,
+            Some((prefix, _)) => match (prefix, c) {
+                ('g', 'g') => ChordStep::Action(Action::JumpTop),
+                ('g', 'e') => ChordStep::Action(Action::JumpBottom),
+                ('g', 'n') => ChordStep::Action(Action::JumpNowPlaying),
+                (LEADER_KEY, d @ '1'..='9') => {
+                    ChordStep::Action(Action::OpenBookmark(d as u8 - b'0'))
+                }
+                _ => ChordStep::Cancelled,
+            },
+        }
+    }
+
+    /// Drop a sequence that waited too long; returns true if one was dropped
+    pub fn expire(&mut self) -> bool {
+        match self.pending {
+            Some((_, since)) if since.elapsed() >= CHORD_TIMEOUT => {
+                self.pending = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Keys typed so far, for the status bar
+    pub fn pending(&self) -> Option<String> {
+        self.pending.map(|(c, _)| match c {
+            LEADER_KEY => "<leader>".to_string(),
+            c => c.to_string(),
+        })
+    }
+}
@@ -1,25 +1,159 @@
 use anyhow::{Context, Result};
+use std::fmt;
 use std::sync::Arc;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
 use tokio::sync::{mpsc, Mutex};
 
+use super::discovery::host_port;
+
 pub const AVR_PORT: u16 = 23;
+/// Web interface, used when another controller holds the telnet connection
+pub const AVR_HTTP_PORT: u16 = 8080;
+
+const HTTP_STATUS_PATH: &str = "/goform/formMainZone_MainZoneXml.xml";
+const HTTP_COMMAND_PATH: &str = "/goform/formiPhoneAppDirect.xml";
+const HTTP_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+const HTTP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Backoff between reconnect attempts, doubling from the min to the max
+const RECONNECT_MIN_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+/// A `PW?` probe is sent this often; the receiver always answers it, so a connection
+/// silent for `LIVENESS_TIMEOUT` is taken as dead
+const LIVENESS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(20);
+const LIVENESS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(45);
+
+/// Sleep timer lengths offered, in minutes; the receiver takes 1-120
+pub const SLEEP_TIMER_MINUTES: [u16; 5] = [15, 30, 60, 90, 120];
+
+/// Pause between commands sent as a batch
+const BATCH_COMMAND_GAP: std::time::Duration = std::time::Duration::from_millis(150);
 
 /// Events from the AVR control protocol
 #[derive(Debug, Clone)]
 pub enum AvrEvent {
     Connected,
     Disconnected,
-    MasterVolume(u8),       // 0-98
+    /// Connected over the web interface because the telnet port was busy
+    HttpFallback,
+    /// Waiting `delay_secs` before reconnect attempt number `attempt`
+    Reconnecting {
+        attempt: u32,
+        delay_secs: u64,
+    },
+    MasterVolume(AvrVolume),
+    /// Volume limit set on the receiver, reported along with the master volume
+    MaxVolume(AvrVolume),
     Mute(bool),
     Power(bool),
     SurroundMode(String),
     InputSource(String),
+    /// Name the user gave an input, by its code (`SSFUN`)
+    InputName(String, String),
+    /// Whether an input is hidden from the source list (`SSSOD`)
+    InputHidden(String, bool),
+    /// Tone and subwoofer levels in dB relative to 0
+    Bass(f32),
+    Treble(f32),
+    SubwooferLevel(f32),
+    /// Dialog Enhancer level 1-6, 0 when off
+    DialogEnhancer(u8),
+    /// Bass Sync level, 0-16
+    BassSync(u8),
+    DynamicCompression(DynamicCompression),
+    /// Whether headphones are plugged into the AVR
+    Headphones(bool),
+    /// Only sent by receivers with IMAX Enhanced
+    Imax(ImaxMode),
+    /// Only sent by receivers with an ECO mode
+    Eco(EcoMode),
+    /// Only sent by receivers with a Restorer
+    Restorer(Restorer),
+    /// Only sent by receivers with two HDMI outputs
+    HdmiMonitor(HdmiMonitor),
+    MultEq(MultEq),
+    DynamicEq(bool),
+    /// Reference Level Offset in dB: 0, 5, 10 or 15
+    ReferenceLevel(u8),
+    DynamicVolume(DynamicVolume),
+    SurroundParameter(SurroundParameter, String),
+    SignalInfo(SignalField, String),
+    LoudnessManagement(bool),
+    CinemaEq(bool),
+    Lfc(bool),
+    /// LFC containment amount, 1-7
+    LfcAmount(u8),
+    /// LFE channel level in dB, 0 down to -10
+    LfeLevel(i8),
+    /// Power of Zone 2 or 3; only receivers that have the zone answer
+    ZonePower(AvrZone, bool),
+    ZoneVolume(AvrZone, AvrVolume),
+    /// Quick select last recalled; `None` once settings changed since (`MSQUICK0`)
+    QuickSelect(Option<QuickSelect>),
+    /// Minutes left on the sleep timer, `None` when it's off
+    SleepTimer(Option<u16>),
     Error(String),
     Response(String),
 }
 
+/// AVR master volume as a fixed-point value in 0.5 steps (0-98, e.g. 101 = 50.5)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct AvrVolume(pub u16);
+
+impl AvrVolume {
+    pub const MAX: AvrVolume = AvrVolume(98 * 2);
+
+    /// Parse the value part of an MV response: "50" = 50, "505" = 50.5
+    pub fn from_response(s: &str) -> Option<Self> {
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        match s.len() {
+            2 => s.parse::<u16>().ok().map(|v| AvrVolume(v * 2)),
+            3 => {
+                let whole = s[..2].parse::<u16>().ok()?;
+                let half = u16::from(&s[2..] == "5");
+                Some(AvrVolume(whole * 2 + half))
+            }
+            _ => None,
+        }
+    }
+
+    /// From a level like 45 or 45.5, rounded to the nearest 0.5 step
+    pub fn from_level(level: f32) -> Self {
+        AvrVolume(((level * 2.0).round() as u16).min(Self::MAX.0))
+    }
+
+    /// Value for an MV command: "50" or "505"
+    pub fn command_value(&self) -> String {
+        let whole = self.0 / 2;
+        if self.0 % 2 == 1 {
+            format!("{:02}5", whole)
+        } else {
+            format!("{:02}", whole)
+        }
+    }
+
+    pub fn step_up(&self) -> Self {
+        AvrVolume((self.0 + 1).min(Self::MAX.0))
+    }
+
+    pub fn step_down(&self) -> Self {
+        AvrVolume(self.0.saturating_sub(1))
+    }
+}
+
+impl fmt::Display for AvrVolume {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 % 2 == 1 {
+            write!(f, "{}.5", self.0 / 2)
+        } else {
+            write!(f, "{}", self.0 / 2)
+        }
+    }
+}
+
 /// Surround modes available on Denon/Marantz AVRs
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SurroundMode {
@@ -129,7 +263,287 @@ impl SurroundMode {
     }
 }
 
-/// Quick select modes
+/// Surround DSP parameters; which ones a mode uses varies, so each is queried
+/// and only those the AVR answers are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SurroundParameter {
+    Mode,
+    CenterWidth,
+    CenterImage,
+    Panorama,
+    Dimension,
+}
+
+impl SurroundParameter {
+    /// Response prefix, followed by the value: `PSCEN 03`
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            SurroundParameter::Mode => "PSMODE:",
+            SurroundParameter::CenterWidth => "PSCEN ",
+            SurroundParameter::CenterImage => "PSCEI ",
+            SurroundParameter::Panorama => "PSPAN ",
+            SurroundParameter::Dimension => "PSDIM ",
+        }
+    }
+
+    pub fn query(&self) -> String {
+        format!("{}?", self.prefix().trim_end())
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SurroundParameter::Mode => "Mode",
+            SurroundParameter::CenterWidth => "Center Width",
+            SurroundParameter::CenterImage => "Center Image",
+            SurroundParameter::Panorama => "Panorama",
+            SurroundParameter::Dimension => "Dimension",
+        }
+    }
+
+    pub fn all() -> &'static [SurroundParameter] {
+        &[
+            SurroundParameter::Mode,
+            SurroundParameter::CenterWidth,
+            SurroundParameter::CenterImage,
+            SurroundParameter::Panorama,
+            SurroundParameter::Dimension,
+        ]
+    }
+
+    /// Split a `PS` response into a parameter and its value
+    pub fn from_response(response: &str) -> Option<(Self, String)> {
+        Self::all().iter().find_map(|param| {
+            response
+                .strip_prefix(param.prefix())
+                .map(|value| (*param, value.trim().to_string()))
+        })
+    }
+}
+
+/// Upmixers that expand stereo and multichannel sources to every speaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upmixer {
+    DolbySurround,
+    /// Dolby Surround with the center channel spread to the front speakers
+    DolbySurroundCenterSpread,
+    NeuralX,
+    Auro3D,
+    Auro2DSurround,
+}
+
+impl Upmixer {
+    /// Commands sent in order: the surround mode, then any parameters it needs
+    pub fn commands(&self) -> &'static [&'static str] {
+        match self {
+            Upmixer::DolbySurround => &["MSDOLBY SURROUND", "PSCES OFF"],
+            Upmixer::DolbySurroundCenterSpread => &["MSDOLBY SURROUND", "PSCES ON"],
+            Upmixer::NeuralX => &["MSNEURAL:X"],
+            Upmixer::Auro3D => &["MSAURO3D"],
+            Upmixer::Auro2DSurround => &["MSAURO2DSURR"],
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Upmixer::DolbySurround => "Dolby Surround",
+            Upmixer::DolbySurroundCenterSpread => "Dolby Surround (Center Spread)",
+            Upmixer::NeuralX => "DTS Neural:X",
+            Upmixer::Auro3D => "Auro-3D",
+            Upmixer::Auro2DSurround => "Auro-2D Surround",
+        }
+    }
+
+    pub fn all() -> &'static [Upmixer] {
+        &[
+            Upmixer::DolbySurround,
+            Upmixer::DolbySurroundCenterSpread,
+            Upmixer::NeuralX,
+            Upmixer::Auro3D,
+            Upmixer::Auro2DSurround,
+        ]
+    }
+
+    /// Whether a reported surround mode (`MS` value) is this upmixer's mode
+    pub fn is_active(&self, surround_mode: &str) -> bool {
+        let mode = self.commands()[0].trim_start_matches("MS");
+        surround_mode.trim().eq_ignore_ascii_case(mode)
+    }
+}
+
+/// Fields of the AVR's signal information (`SSINF` status lines)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalField {
+    AudioSignal,
+    SampleRate,
+    VideoInput,
+    VideoOutput,
+    Hdr,
+}
+
+impl SignalField {
+    /// Response prefix, followed by the value: `SSINFSIGRESI 1080p`
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            SignalField::AudioSignal => "SSINFAISSIG ",
+            SignalField::SampleRate => "SSINFAISFSV ",
+            SignalField::VideoInput => "SSINFSIGRESI ",
+            SignalField::VideoOutput => "SSINFSIGRESO ",
+            SignalField::Hdr => "SSINFHDR ",
+        }
+    }
+
+    pub fn all() -> &'static [SignalField] {
+        &[
+            SignalField::AudioSignal,
+            SignalField::SampleRate,
+            SignalField::VideoInput,
+            SignalField::VideoOutput,
+            SignalField::Hdr,
+        ]
+    }
+
+    pub fn from_response(response: &str) -> Option<(Self, String)> {
+        Self::all().iter().find_map(|field| {
+            response
+                .strip_prefix(field.prefix())
+                .map(|value| (*field, value.trim().to_string()))
+        })
+    }
+}
+
+/// Bass and treble, set as absolute levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    Bass,
+    Treble,
+}
+
+impl Tone {
+    /// Largest boost or cut in dB
+    pub const LIMIT: i8 = 6;
+
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Tone::Bass => "PSBAS",
+            Tone::Treble => "PSTRE",
+        }
+    }
+}
+
+/// GET a page from the receiver's web interface
+async fn http_get(url: String) -> Result<String> {
+    tokio::task::spawn_blocking(move || {
+        let body = ureq::get(&url)
+            .timeout(HTTP_TIMEOUT)
+            .call()?
+            .into_string()?;
+        Ok(body)
+    })
+    .await?
+}
+
+/// Percent-encode a command for the query string of a web interface request
+fn encode_command(cmd: &str) -> String {
+    cmd.bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                char::from(b).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect()
+}
+
+/// The trimmed `<value>` of the first `<tag>` element in the status XML
+fn xml_value<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let start = xml.find(&format!("<{}>", tag))?;
+    let value = xml[start..]
+        .split_once("<value>")?
+        .1
+        .split_once("</value>")?
+        .0
+        .trim();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Main zone status XML as the telnet responses it corresponds to
+fn status_lines(xml: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(power) = xml_value(xml, "Power") {
+        lines.push(format!("PW{}", power.to_uppercase()));
+    }
+    // In dB relative to reference, which is 80 on the absolute scale; "--" at the minimum
+    if let Some(db) = xml_value(xml, "MasterVolume") {
+        let level = db.parse::<f32>().map_or(0.0, |db| (db + 80.0).max(0.0));
+        let volume = AvrVolume::from_level(level);
+        lines.push(format!("MV{}", volume.command_value()));
+    }
+    if let Some(mute) = xml_value(xml, "Mute") {
+        lines.push(format!("MU{}", mute.to_uppercase()));
+    }
+    if let Some(input) = xml_value(xml, "InputFuncSelect") {
+        lines.push(format!("SI{}", input));
+    }
+    if let Some(mode) = xml_value(xml, "selectSurround") {
+        lines.push(format!("MS{}", mode));
+    }
+    lines
+}
+
+/// Parse a level where 50 is 0 dB and a third digit of 5 adds half a dB: "52" = +2, "495" = -0.5
+fn parse_offset_level(s: &str) -> Option<f32> {
+    AvrVolume::from_response(s.trim()).map(|level| f32::from(level.0) / 2.0 - 50.0)
+}
+
+/// AVR zones; volume commands use a different prefix per zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AvrZone {
+    #[default]
+    Main,
+    Zone2,
+    Zone3,
+}
+
+impl AvrZone {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AvrZone::Main => "Main",
+            AvrZone::Zone2 => "Zone 2",
+            AvrZone::Zone3 => "Zone 3",
+        }
+    }
+
+    /// Prefix for volume commands: MVUP, Z2UP, Z3UP
+    pub fn volume_prefix(&self) -> &'static str {
+        match self {
+            AvrZone::Main => "MV",
+            AvrZone::Zone2 => "Z2",
+            AvrZone::Zone3 => "Z3",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            AvrZone::Main => AvrZone::Zone2,
+            AvrZone::Zone2 => AvrZone::Zone3,
+            AvrZone::Zone3 => AvrZone::Main,
+        }
+    }
+
+    /// Split a Z2/Z3 response into its zone and the rest, e.g. `Z2ON`
+    pub fn from_response(response: &str) -> Option<(Self, &str)> {
+        if let Some(rest) = response.strip_prefix("Z2") {
+            Some((AvrZone::Zone2, rest))
+        } else {
+            response
+                .strip_prefix("Z3")
+                .map(|rest| (AvrZone::Zone3, rest))
+        }
+    }
+}
+
+/// Quick select memories, each storing input, surround mode and volume
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QuickSelect {
     Quick1,
@@ -149,6 +563,358 @@ impl QuickSelect {
             QuickSelect::Quick5 => "MSQUICK5",
         }
     }
+
+    /// Store the receiver's current settings in this memory
+    pub fn memory_command(&self) -> String {
+        format!("{} MEMORY", self.command())
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            QuickSelect::Quick1 => 1,
+            QuickSelect::Quick2 => 2,
+            QuickSelect::Quick3 => 3,
+            QuickSelect::Quick4 => 4,
+            QuickSelect::Quick5 => 5,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        Self::all()
+            .get(usize::from(number).checked_sub(1)?)
+            .copied()
+    }
+
+    pub fn all() -> &'static [QuickSelect] {
+        &[
+            QuickSelect::Quick1,
+            QuickSelect::Quick2,
+            QuickSelect::Quick3,
+            QuickSelect::Quick4,
+            QuickSelect::Quick5,
+        ]
+    }
+}
+
+/// Dynamic Range Compression settings (`PSDRC`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicCompression {
+    Auto,
+    Low,
+    Mid,
+    High,
+    Off,
+}
+
+impl DynamicCompression {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            DynamicCompression::Auto => "AUTO",
+            DynamicCompression::Low => "LOW",
+            DynamicCompression::Mid => "MID",
+            DynamicCompression::High => "HI",
+            DynamicCompression::Off => "OFF",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DynamicCompression::Auto => "Auto",
+            DynamicCompression::Low => "Low",
+            DynamicCompression::Mid => "Mid",
+            DynamicCompression::High => "High",
+            DynamicCompression::Off => "Off",
+        }
+    }
+
+    pub fn all() -> &'static [DynamicCompression] {
+        &[
+            DynamicCompression::Auto,
+            DynamicCompression::Low,
+            DynamicCompression::Mid,
+            DynamicCompression::High,
+            DynamicCompression::Off,
+        ]
+    }
+
+    pub fn from_response(s: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|drc| drc.command_value() == s.trim())
+    }
+}
+
+/// IMAX Enhanced processing (`PSIMAX`), on receivers that support it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImaxMode {
+    Auto,
+    On,
+    Off,
+}
+
+impl ImaxMode {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            ImaxMode::Auto => "AUTO",
+            ImaxMode::On => "ON",
+            ImaxMode::Off => "OFF",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ImaxMode::Auto => "Auto",
+            ImaxMode::On => "On",
+            ImaxMode::Off => "Off",
+        }
+    }
+
+    /// The setting after this one when cycling: Auto, On, Off
+    pub fn next(&self) -> Self {
+        match self {
+            ImaxMode::Auto => ImaxMode::On,
+            ImaxMode::On => ImaxMode::Off,
+            ImaxMode::Off => ImaxMode::Auto,
+        }
+    }
+
+    pub fn from_response(s: &str) -> Option<Self> {
+        match s.trim() {
+            "AUTO" => Some(ImaxMode::Auto),
+            "ON" => Some(ImaxMode::On),
+            "OFF" => Some(ImaxMode::Off),
+            _ => None,
+        }
+    }
+}
+
+/// Audyssey MultEQ room correction curve (`PSMULTEQ:`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultEq {
+    Reference,
+    BypassLr,
+    Flat,
+    Manual,
+    Off,
+}
+
+impl MultEq {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            MultEq::Reference => "AUDYSSEY",
+            MultEq::BypassLr => "BYP.LR",
+            MultEq::Flat => "FLAT",
+            MultEq::Manual => "MANUAL",
+            MultEq::Off => "OFF",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MultEq::Reference => "Reference",
+            MultEq::BypassLr => "Bypass L/R",
+            MultEq::Flat => "Flat",
+            MultEq::Manual => "Manual",
+            MultEq::Off => "Off",
+        }
+    }
+
+    pub fn all() -> &'static [MultEq] {
+        &[
+            MultEq::Reference,
+            MultEq::BypassLr,
+            MultEq::Flat,
+            MultEq::Manual,
+            MultEq::Off,
+        ]
+    }
+
+    pub fn from_response(s: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|mode| mode.command_value() == s.trim())
+            .copied()
+    }
+}
+
+/// Audyssey Dynamic Volume (`PSDYNVOL`), evening out loud and quiet passages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicVolume {
+    Off,
+    Light,
+    Medium,
+    Heavy,
+}
+
+impl DynamicVolume {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            DynamicVolume::Off => "OFF",
+            DynamicVolume::Light => "LIT",
+            DynamicVolume::Medium => "MED",
+            DynamicVolume::Heavy => "HEV",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DynamicVolume::Off => "Off",
+            DynamicVolume::Light => "Light",
+            DynamicVolume::Medium => "Medium",
+            DynamicVolume::Heavy => "Heavy",
+        }
+    }
+
+    pub fn all() -> &'static [DynamicVolume] {
+        &[
+            DynamicVolume::Off,
+            DynamicVolume::Light,
+            DynamicVolume::Medium,
+            DynamicVolume::Heavy,
+        ]
+    }
+
+    pub fn from_response(s: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|mode| mode.command_value() == s.trim())
+            .copied()
+    }
+}
+
+/// Reference Level Offset steps in dB (`PSREFLEV`); only used with Dynamic EQ on
+pub const REFERENCE_LEVEL_OFFSETS: [u8; 4] = [0, 5, 10, 15];
+
+/// ECO power saving mode (`ECO`); Auto lowers amplifier power with the volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcoMode {
+    On,
+    Auto,
+    Off,
+}
+
+impl EcoMode {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            EcoMode::On => "ON",
+            EcoMode::Auto => "AUTO",
+            EcoMode::Off => "OFF",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EcoMode::On => "On",
+            EcoMode::Auto => "Auto",
+            EcoMode::Off => "Off",
+        }
+    }
+
+    /// The setting after this one when cycling: On, Auto, Off
+    pub fn next(&self) -> Self {
+        match self {
+            EcoMode::On => EcoMode::Auto,
+            EcoMode::Auto => EcoMode::Off,
+            EcoMode::Off => EcoMode::On,
+        }
+    }
+
+    pub fn from_response(s: &str) -> Option<Self> {
+        match s.trim() {
+            "ON" => Some(EcoMode::On),
+            "AUTO" => Some(EcoMode::Auto),
+            "OFF" => Some(EcoMode::Off),
+            _ => None,
+        }
+    }
+}
+
+/// Restorer for compressed audio like streams (`PSRSTR`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Restorer {
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+impl Restorer {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            Restorer::Off => "OFF",
+            Restorer::Low => "LOW",
+            Restorer::Medium => "MED",
+            Restorer::High => "HI",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Restorer::Off => "Off",
+            Restorer::Low => "Low",
+            Restorer::Medium => "Medium",
+            Restorer::High => "High",
+        }
+    }
+
+    /// The setting after this one when cycling: Off, Low, Medium, High
+    pub fn next(&self) -> Self {
+        match self {
+            Restorer::Off => Restorer::Low,
+            Restorer::Low => Restorer::Medium,
+            Restorer::Medium => Restorer::High,
+            Restorer::High => Restorer::Off,
+        }
+    }
+
+    /// Older receivers answer MODE1-3 for the same levels
+    pub fn from_response(s: &str) -> Option<Self> {
+        match s.trim() {
+            "OFF" => Some(Restorer::Off),
+            "LOW" | "MODE3" => Some(Restorer::Low),
+            "MED" | "MODE2" => Some(Restorer::Medium),
+            "HI" | "MODE1" => Some(Restorer::High),
+            _ => None,
+        }
+    }
+}
+
+/// HDMI monitor output (`VSMONI`) on receivers with two HDMI outputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HdmiMonitor {
+    Auto,
+    Out1,
+    Out2,
+}
+
+impl HdmiMonitor {
+    pub fn command_value(&self) -> &'static str {
+        match self {
+            HdmiMonitor::Auto => "AUTO",
+            HdmiMonitor::Out1 => "1",
+            HdmiMonitor::Out2 => "2",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            HdmiMonitor::Auto => "Auto (both)",
+            HdmiMonitor::Out1 => "HDMI Out 1",
+            HdmiMonitor::Out2 => "HDMI Out 2",
+        }
+    }
+
+    pub fn all() -> &'static [HdmiMonitor] {
+        &[HdmiMonitor::Auto, HdmiMonitor::Out1, HdmiMonitor::Out2]
+    }
+
+    pub fn from_response(s: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|output| output.command_value() == s.trim())
+            .copied()
+    }
 }
 
 /// Handle for sending commands to the AVR
@@ -165,6 +931,19 @@ impl AvrHandle {
             .map_err(|_| anyhow::anyhow!("AVR disconnected"))
     }
 
+    /// Send several commands spaced out, since the AVR drops commands that arrive too quickly
+    pub fn send_batch(&self, commands: Vec<String>) {
+        let handle = self.clone();
+        tokio::spawn(async move {
+            for cmd in commands {
+                if handle.send_raw(&cmd).await.is_err() {
+                    break;
+                }
+                tokio::time::sleep(BATCH_COMMAND_GAP).await;
+            }
+        });
+    }
+
     // Power control
     pub async fn power_on(&self) -> Result<()> {
         self.send_raw("PWON").await
@@ -187,15 +966,25 @@ impl AvrHandle {
         self.send_raw("MVDOWN").await
     }
 
-    pub async fn set_volume(&self, level: u8) -> Result<()> {
-        let level = level.min(98);
-        self.send_raw(&format!("MV{:02}", level)).await
+    pub async fn set_volume(&self, level: AvrVolume) -> Result<()> {
+        let level = level.min(AvrVolume::MAX);
+        self.send_raw(&format!("MV{}", level.command_value())).await
     }
 
     pub async fn get_volume(&self) -> Result<()> {
         self.send_raw("MV?").await
     }
 
+    // Zone volume (Z2/Z3 step in whole dB)
+    pub async fn zone_volume_up(&self, zone: AvrZone) -> Result<()> {
+        self.send_raw(&format!("{}UP", zone.volume_prefix())).await
+    }
+
+    pub async fn zone_volume_down(&self, zone: AvrZone) -> Result<()> {
+        self.send_raw(&format!("{}DOWN", zone.volume_prefix()))
+            .await
+    }
+
     // Mute
     pub async fn mute_on(&self) -> Result<()> {
         self.send_raw("MUON").await
@@ -262,30 +1051,36 @@ impl AvrHandle {
         self.set_input(&format!("HDMI{}", num.min(7))).await
     }
 
-    // Tone control
-    pub async fn bass_up(&self) -> Result<()> {
-        self.send_raw("PSBAS UP").await
+    /// Set bass or treble in dB, offset so 50 is 0 dB; tone control is switched
+    /// on first since the levels are ignored while it's off
+    pub fn set_tone(&self, tone: Tone, db: i8) {
+        let level = 50 + db.clamp(-Tone::LIMIT, Tone::LIMIT);
+        self.send_batch(vec![
+            "PSTONE CTRL ON".to_string(),
+            format!("{} {}", tone.prefix(), level),
+        ]);
     }
 
-    pub async fn bass_down(&self) -> Result<()> {
-        self.send_raw("PSBAS DOWN").await
-    }
-
-    pub async fn treble_up(&self) -> Result<()> {
-        self.send_raw("PSTRE UP").await
+    // Audyssey
+    pub async fn multeq(&self, mode: MultEq) -> Result<()> {
+        self.send_raw(&format!("PSMULTEQ:{}", mode.command_value()))
+            .await
     }
 
-    pub async fn treble_down(&self) -> Result<()> {
-        self.send_raw("PSTRE DOWN").await
+    pub async fn dynamic_eq(&self, on: bool) -> Result<()> {
+        self.send_raw(if on { "PSDYNEQ ON" } else { "PSDYNEQ OFF" })
+            .await
     }
 
-    // Dynamic EQ
-    pub async fn dynamic_eq_on(&self) -> Result<()> {
-        self.send_raw("PSDYNEQ ON").await
+    pub async fn reference_level(&self, offset: u8) -> Result<()> {
+        self.send_raw(&format!("PSREFLEV {}", offset)).await
     }
 
-    pub async fn dynamic_eq_off(&self) -> Result<()> {
-        self.send_raw("PSDYNEQ OFF").await
+    pub async fn query_audyssey(&self) -> Result<()> {
+        self.send_raw("PSMULTEQ: ?").await?;
+        self.send_raw("PSDYNEQ ?").await?;
+        self.send_raw("PSREFLEV ?").await?;
+        self.send_raw("PSDYNVOL ?").await
     }
 
     // Dialog Enhancer
@@ -307,6 +1102,97 @@ impl AvrHandle {
         self.send_raw("PSSWL DOWN").await
     }
 
+    // Bass Sync (front speaker bass matched to the subwoofer), 0-16
+    pub async fn bass_sync_up(&self) -> Result<()> {
+        self.send_raw("PSBSC UP").await
+    }
+
+    pub async fn bass_sync_down(&self) -> Result<()> {
+        self.send_raw("PSBSC DOWN").await
+    }
+
+    pub async fn set_bass_sync(&self, level: u8) -> Result<()> {
+        self.send_raw(&format!("PSBSC {:02}", level.min(16))).await
+    }
+
+    // Loudness Management
+    pub async fn loudness_management(&self, on: bool) -> Result<()> {
+        self.send_raw(if on { "PSLOM ON" } else { "PSLOM OFF" })
+            .await
+    }
+
+    // Audyssey Low Frequency Containment and its containment amount, 1-7
+    pub async fn lfc(&self, on: bool) -> Result<()> {
+        self.send_raw(if on { "PSLFC ON" } else { "PSLFC OFF" })
+            .await
+    }
+
+    pub async fn lfc_amount_up(&self) -> Result<()> {
+        self.send_raw("PSCNTAMT UP").await
+    }
+
+    pub async fn lfc_amount_down(&self) -> Result<()> {
+        self.send_raw("PSCNTAMT DOWN").await
+    }
+
+    pub async fn set_lfc_amount(&self, amount: u8) -> Result<()> {
+        self.send_raw(&format!("PSCNTAMT {:02}", amount.clamp(1, 7)))
+            .await
+    }
+
+    /// Ask for every surround DSP parameter; ones the mode doesn't use go unanswered
+    pub async fn query_surround_parameters(&self) -> Result<()> {
+        for param in SurroundParameter::all() {
+            self.send_raw(&param.query()).await?;
+        }
+        Ok(())
+    }
+
+    // IMAX Enhanced
+    pub async fn imax(&self, mode: ImaxMode) -> Result<()> {
+        self.send_raw(&format!("PSIMAX {}", mode.command_value()))
+            .await
+    }
+
+    // Restorer
+    pub async fn restorer(&self, mode: Restorer) -> Result<()> {
+        self.send_raw(&format!("PSRSTR {}", mode.command_value()))
+            .await
+    }
+
+    // HDMI monitor output
+    pub async fn hdmi_monitor(&self, output: HdmiMonitor) -> Result<()> {
+        self.send_raw(&format!("VSMONI{}", output.command_value()))
+            .await
+    }
+
+    // ECO mode
+    pub async fn eco(&self, mode: EcoMode) -> Result<()> {
+        self.send_raw(&format!("ECO{}", mode.command_value())).await
+    }
+
+    pub async fn set_upmixer(&self, upmixer: Upmixer) -> Result<()> {
+        for cmd in upmixer.commands() {
+            self.send_raw(cmd).await?;
+        }
+        Ok(())
+    }
+
+    /// Ask for the current audio and video signal information
+    pub async fn query_signal_info(&self) -> Result<()> {
+        self.send_raw("SSINFAISSIG ?").await?;
+        self.send_raw("SSINFAISFSV ?").await?;
+        // Answered with separate input (I) and output (O) resolution lines
+        self.send_raw("SSINFSIGRES ?").await?;
+        self.send_raw("SSINFHDR ?").await
+    }
+
+    // Dynamic Range Compression
+    pub async fn dynamic_compression(&self, drc: DynamicCompression) -> Result<()> {
+        self.send_raw(&format!("PSDRC {}", drc.command_value()))
+            .await
+    }
+
     // LFE level
     pub async fn lfe_up(&self) -> Result<()> {
         self.send_raw("PSLFE UP").await
@@ -317,18 +1203,39 @@ impl AvrHandle {
     }
 
     // Cinema EQ
-    pub async fn cinema_eq_on(&self) -> Result<()> {
-        self.send_raw("PSCINEMA EQ.ON").await
+    pub async fn cinema_eq(&self, on: bool) -> Result<()> {
+        self.send_raw(if on {
+            "PSCINEMA EQ.ON"
+        } else {
+            "PSCINEMA EQ.OFF"
+        })
+        .await
+    }
+
+    // Quick select
+    pub async fn quick_select(&self, quick: QuickSelect) -> Result<()> {
+        self.send_raw(quick.command()).await
+    }
+
+    pub async fn save_quick_select(&self, quick: QuickSelect) -> Result<()> {
+        self.send_raw(&quick.memory_command()).await
     }
 
-    pub async fn cinema_eq_off(&self) -> Result<()> {
-        self.send_raw("PSCINEMA EQ.OFF").await
+    // Sleep timer in minutes, or off
+    pub async fn sleep_timer(&self, minutes: Option<u16>) -> Result<()> {
+        match minutes {
+            Some(minutes) => {
+                self.send_raw(&format!("SLP{:03}", minutes.clamp(1, 120)))
+                    .await
+            }
+            None => self.send_raw("SLPOFF").await,
+        }
     }
 
     // Night mode / Dynamic Volume
-    pub async fn dynamic_volume(&self, mode: &str) -> Result<()> {
-        // OFF, LIT (Light), MED (Medium), HEV (Heavy)
-        self.send_raw(&format!("PSDYNVOL {}", mode)).await
+    pub async fn dynamic_volume(&self, mode: DynamicVolume) -> Result<()> {
+        self.send_raw(&format!("PSDYNVOL {}", mode.command_value()))
+            .await
     }
 
     // Query all status
@@ -338,6 +1245,32 @@ impl AvrHandle {
         self.send_raw("MU?").await?;
         self.send_raw("SI?").await?;
         self.send_raw("MS?").await?;
+        self.send_raw("MSQUICK ?").await?;
+        self.send_raw("PSBAS ?").await?;
+        self.send_raw("PSTRE ?").await?;
+        self.send_raw("PSSWL ?").await?;
+        self.send_raw("PSDYNEQ ?").await?;
+        self.send_raw("PSDIL ?").await?;
+        self.send_raw("PSBSC ?").await?;
+        self.send_raw("PSLOM ?").await?;
+        self.send_raw("PSCINEMA EQ. ?").await?;
+        self.send_raw("PSLFC ?").await?;
+        self.send_raw("PSCNTAMT ?").await?;
+        self.send_raw("PSLFE ?").await?;
+        self.send_raw("PSDRC ?").await?;
+        // Unsupported receivers don't answer, which keeps the IMAX entry hidden
+        self.send_raw("PSIMAX ?").await?;
+        self.send_raw("PSRSTR ?").await?;
+        self.send_raw("ECO?").await?;
+        self.send_raw("SSHPD ?").await?;
+        self.send_raw("VSMONI ?").await?;
+        self.send_raw("SLP?").await?;
+        // Renamed and deleted inputs, one line per input
+        self.send_raw("SSFUN ?").await?;
+        self.send_raw("SSSOD ?").await?;
+        // Answered with power, source and volume, but only for zones the receiver has
+        self.send_raw("Z2?").await?;
+        self.send_raw("Z3?").await?;
         Ok(())
     }
 }
@@ -345,8 +1278,32 @@ impl AvrHandle {
 pub struct AvrClient;
 
 impl AvrClient {
+    /// Connect again after the connection dropped, retrying with exponential backoff
+    /// until the receiver answers over telnet or, failing that, its web interface
+    pub async fn reconnect(host: &str, event_tx: mpsc::Sender<AvrEvent>) -> AvrHandle {
+        let mut delay = RECONNECT_MIN_DELAY;
+        let mut attempt = 1;
+        loop {
+            let _ = event_tx
+                .send(AvrEvent::Reconnecting {
+                    attempt,
+                    delay_secs: delay.as_secs(),
+                })
+                .await;
+            tokio::time::sleep(delay).await;
+            if let Ok(handle) = Self::connect(host, event_tx.clone()).await {
+                return handle;
+            }
+            if let Ok(handle) = Self::connect_http(host, event_tx.clone()).await {
+                return handle;
+            }
+            delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+            attempt += 1;
+        }
+    }
+
     pub async fn connect(host: &str, event_tx: mpsc::Sender<AvrEvent>) -> Result<AvrHandle> {
-        let addr = format!("{}:{}", host, AVR_PORT);
+        let addr = host_port(host, AVR_PORT);
         let stream = TcpStream::connect(&addr)
             .await
             .context("Failed to connect to AVR control port")?;
@@ -359,31 +1316,48 @@ impl AvrClient {
 
         // Spawn reader task
         let event_tx_clone = event_tx.clone();
+        let write_half_clone = write_half.clone();
         tokio::spawn(async move {
             let mut reader = BufReader::new(read_half);
             let mut line = String::new();
 
             loop {
                 line.clear();
-                match reader.read_line(&mut line).await {
-                    Ok(0) => {
+                let read = tokio::time::timeout(LIVENESS_TIMEOUT, reader.read_line(&mut line));
+                match read.await {
+                    Ok(Ok(0)) | Err(_) => {
                         let _ = event_tx_clone.send(AvrEvent::Disconnected).await;
                         break;
                     }
-                    Ok(_) => {
+                    Ok(Ok(_)) => {
                         let response = line.trim();
                         if !response.is_empty() {
                             Self::handle_response(response, &event_tx_clone).await;
                         }
                     }
-                    Err(e) => {
+                    Ok(Err(e)) => {
                         let _ = event_tx_clone
                             .send(AvrEvent::Error(format!("Read error: {}", e)))
                             .await;
+                        let _ = event_tx_clone.send(AvrEvent::Disconnected).await;
                         break;
                     }
                 }
             }
+
+            // Stops the writer, which ends the probe task
+            *write_half_clone.lock().await = None;
+        });
+
+        // Spawn liveness probe task
+        let probe_tx = cmd_tx.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(LIVENESS_INTERVAL).await;
+                if probe_tx.send("PW?\r".to_string()).await.is_err() {
+                    break;
+                }
+            }
         });
 
         // Spawn writer task
@@ -409,22 +1383,67 @@ impl AvrClient {
         Ok(AvrHandle { cmd_tx })
     }
 
-    async fn handle_response(response: &str, tx: &mpsc::Sender<AvrEvent>) {
-        let event = if response.starts_with("MV") && !response.starts_with("MVMAX") {
-            // Master volume response: MV50 or MV505 (50.5)
-            let vol_str = &response[2..];
-            if let Ok(vol) = vol_str.parse::<u8>() {
-                Some(AvrEvent::MasterVolume(vol))
-            } else if vol_str.len() == 3 {
-                // Handle half-dB values like "505" = 50.5
-                if let Ok(vol) = vol_str[..2].parse::<u8>() {
-                    Some(AvrEvent::MasterVolume(vol))
-                } else {
-                    None
+    /// Control the receiver through its web interface instead of telnet. Status is
+    /// polled and covers only power, volume, mute, input and surround mode; queries
+    /// are dropped since only telnet answers them.
+    pub async fn connect_http(host: &str, event_tx: mpsc::Sender<AvrEvent>) -> Result<AvrHandle> {
+        let base = format!("http://{}", host_port(host, AVR_HTTP_PORT));
+        let status_url = format!("{}{}", base, HTTP_STATUS_PATH);
+        let mut status = http_get(status_url.clone())
+            .await
+            .context("Failed to reach the AVR web interface")?;
+
+        let (cmd_tx, mut cmd_rx) = mpsc::channel::<String>(100);
+
+        // Spawn poller task, passing on only the lines that changed since the last poll
+        let event_tx_clone = event_tx.clone();
+        tokio::spawn(async move {
+            let mut last = Vec::new();
+            loop {
+                let lines = status_lines(&status);
+                for line in lines.iter().filter(|line| !last.contains(*line)) {
+                    Self::handle_response(line, &event_tx_clone).await;
                 }
-            } else {
-                None
+                last = lines;
+
+                tokio::time::sleep(HTTP_POLL_INTERVAL).await;
+                if event_tx_clone.is_closed() {
+                    break;
+                }
+                match http_get(status_url.clone()).await {
+                    Ok(xml) => status = xml,
+                    Err(_) => {
+                        let _ = event_tx_clone.send(AvrEvent::Disconnected).await;
+                        break;
+                    }
+                }
+            }
+        });
+
+        // Spawn command task
+        tokio::spawn(async move {
+            while let Some(cmd) = cmd_rx.recv().await {
+                let cmd = cmd.trim_end();
+                if cmd.ends_with('?') {
+                    continue;
+                }
+                let url = format!("{}{}?{}", base, HTTP_COMMAND_PATH, encode_command(cmd));
+                let _ = http_get(url).await;
             }
+        });
+
+        event_tx.send(AvrEvent::Connected).await?;
+        event_tx.send(AvrEvent::HttpFallback).await?;
+
+        Ok(AvrHandle { cmd_tx })
+    }
+
+    async fn handle_response(response: &str, tx: &mpsc::Sender<AvrEvent>) {
+        let event = if let Some(max) = response.strip_prefix("MVMAX") {
+            AvrVolume::from_response(max.trim()).map(AvrEvent::MaxVolume)
+        } else if let Some(volume) = response.strip_prefix("MV") {
+            // Master volume response: MV50 or MV505 (50.5)
+            AvrVolume::from_response(volume).map(AvrEvent::MasterVolume)
         } else if response.starts_with("MU") {
             match &response[2..] {
                 "ON" => Some(AvrEvent::Mute(true)),
@@ -439,8 +1458,118 @@ impl AvrClient {
             }
         } else if response.starts_with("SI") {
             Some(AvrEvent::InputSource(response[2..].to_string()))
+        } else if let Some(number) = response.strip_prefix("MSQUICK") {
+            number
+                .parse()
+                .ok()
+                .map(|n| AvrEvent::QuickSelect(QuickSelect::from_number(n)))
         } else if response.starts_with("MS") {
             Some(AvrEvent::SurroundMode(response[2..].to_string()))
+        } else if let Some(level) = response.strip_prefix("PSBAS ") {
+            parse_offset_level(level).map(AvrEvent::Bass)
+        } else if let Some(level) = response.strip_prefix("PSTRE ") {
+            parse_offset_level(level).map(AvrEvent::Treble)
+        } else if let Some(level) = response.strip_prefix("PSSWL ") {
+            // Also answered with ON/OFF for the subwoofer itself
+            match parse_offset_level(level) {
+                Some(level) => Some(AvrEvent::SubwooferLevel(level)),
+                None => Some(AvrEvent::Response(response.to_string())),
+            }
+        } else if let Some(level) = response.strip_prefix("PSDIL ") {
+            match level {
+                "OFF" => Some(AvrEvent::DialogEnhancer(0)),
+                _ => level.parse().ok().map(AvrEvent::DialogEnhancer),
+            }
+        } else if let Some(level) = response.strip_prefix("PSBSC ") {
+            match level.parse() {
+                Ok(level) => Some(AvrEvent::BassSync(level)),
+                Err(_) => Some(AvrEvent::Response(response.to_string())),
+            }
+        } else if let Some(state) = response.strip_prefix("PSLOM ") {
+            match state {
+                "ON" => Some(AvrEvent::LoudnessManagement(true)),
+                "OFF" => Some(AvrEvent::LoudnessManagement(false)),
+                _ => None,
+            }
+        } else if let Some(state) = response.strip_prefix("PSCINEMA EQ.") {
+            match state {
+                "ON" => Some(AvrEvent::CinemaEq(true)),
+                "OFF" => Some(AvrEvent::CinemaEq(false)),
+                _ => None,
+            }
+        } else if let Some(state) = response.strip_prefix("PSLFC ") {
+            match state {
+                "ON" => Some(AvrEvent::Lfc(true)),
+                "OFF" => Some(AvrEvent::Lfc(false)),
+                _ => None,
+            }
+        } else if let Some(amount) = response.strip_prefix("PSCNTAMT ") {
+            amount.parse().ok().map(AvrEvent::LfcAmount)
+        } else if let Some(level) = response.strip_prefix("PSLFE ") {
+            // Attenuation as a positive number: "PSLFE 05" is -5 dB
+            level
+                .parse::<i8>()
+                .ok()
+                .map(|level| AvrEvent::LfeLevel(-level))
+        } else if let Some(drc) = response.strip_prefix("PSDRC ") {
+            DynamicCompression::from_response(drc).map(AvrEvent::DynamicCompression)
+        } else if let Some(entry) = response.strip_prefix("SSFUN") {
+            // "SSFUNSAT/CBL Cable Box", ended by "SSFUN END"
+            entry
+                .split_once(' ')
+                .filter(|(code, _)| !code.is_empty())
+                .map(|(code, name)| AvrEvent::InputName(code.to_string(), name.trim().to_string()))
+        } else if let Some(entry) = response.strip_prefix("SSSOD") {
+            // "SSSODDVD DEL" for a deleted input, "USE" otherwise
+            entry
+                .split_once(' ')
+                .filter(|(code, _)| !code.is_empty())
+                .map(|(code, usage)| AvrEvent::InputHidden(code.to_string(), usage == "DEL"))
+        } else if let Some(state) = response.strip_prefix("SSHPD ") {
+            match state {
+                "ON" => Some(AvrEvent::Headphones(true)),
+                "OFF" => Some(AvrEvent::Headphones(false)),
+                _ => None,
+            }
+        } else if let Some(minutes) = response.strip_prefix("SLP") {
+            match minutes {
+                "OFF" => Some(AvrEvent::SleepTimer(None)),
+                _ => minutes.parse().ok().map(|m| AvrEvent::SleepTimer(Some(m))),
+            }
+        } else if let Some(mode) = response.strip_prefix("PSIMAX ") {
+            ImaxMode::from_response(mode).map(AvrEvent::Imax)
+        } else if let Some(output) = response.strip_prefix("VSMONI") {
+            HdmiMonitor::from_response(output).map(AvrEvent::HdmiMonitor)
+        } else if let Some(mode) = response.strip_prefix("PSRSTR ") {
+            Restorer::from_response(mode).map(AvrEvent::Restorer)
+        } else if let Some(mode) = response.strip_prefix("ECO") {
+            EcoMode::from_response(mode).map(AvrEvent::Eco)
+        } else if let Some(mode) = response.strip_prefix("PSMULTEQ:") {
+            MultEq::from_response(mode).map(AvrEvent::MultEq)
+        } else if let Some(state) = response.strip_prefix("PSDYNEQ ") {
+            match state {
+                "ON" => Some(AvrEvent::DynamicEq(true)),
+                "OFF" => Some(AvrEvent::DynamicEq(false)),
+                _ => None,
+            }
+        } else if let Some(offset) = response.strip_prefix("PSREFLEV ") {
+            offset.parse().ok().map(AvrEvent::ReferenceLevel)
+        } else if let Some(mode) = response.strip_prefix("PSDYNVOL ") {
+            DynamicVolume::from_response(mode).map(AvrEvent::DynamicVolume)
+        } else if let Some((zone, rest)) = AvrZone::from_response(response) {
+            match rest {
+                "ON" => Some(AvrEvent::ZonePower(zone, true)),
+                "OFF" => Some(AvrEvent::ZonePower(zone, false)),
+                // Source and mute lines (Z2TUNER, Z2MUON) aren't tracked
+                _ => match AvrVolume::from_response(rest) {
+                    Some(vol) => Some(AvrEvent::ZoneVolume(zone, vol)),
+                    None => Some(AvrEvent::Response(response.to_string())),
+                },
+            }
+        } else if let Some((field, value)) = SignalField::from_response(response) {
+            Some(AvrEvent::SignalInfo(field, value))
+        } else if let Some((param, value)) = SurroundParameter::from_response(response) {
+            Some(AvrEvent::SurroundParameter(param, value))
         } else {
             Some(AvrEvent::Response(response.to_string()))
         };
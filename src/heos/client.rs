@@ -1,35 +1,95 @@
 use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{mpsc, oneshot, Mutex};
 
-use super::protocol::{self, HeosCommand, HeosResponse};
+use super::discovery::{host_port, DiscoveredDevice, DiscoveryProgress};
+use super::error::HeosError;
+use super::protocol::{self, HeosCommand, HeosResponse, MessageFramer};
 use super::types::*;
 
 pub const HEOS_PORT: u16 = 1255;
 
+/// Wait before the first reconnect attempt, doubled after each failure up to the max
+const RECONNECT_MIN_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 pub enum HeosEvent {
     Connected,
     Disconnected,
-    PlayersChanged(Vec<Player>),
+    /// Waiting `delay_secs` before reconnect attempt number `attempt`
+    Reconnecting {
+        attempt: u32,
+        delay_secs: u64,
+    },
+    /// Devices found by discovery, including configured known devices
+    DevicesDiscovered(Vec<DiscoveredDevice>),
+    /// Progress of the startup discovery
+    Discovery(DiscoveryProgress),
+    /// Players were added or removed; the event carries no list, so it's fetched again
+    PlayersChanged,
+    /// Every player on the network, from [`HeosHandle::load_players`]
+    Players(Vec<Player>),
+    /// One player's details, from [`HeosHandle::load_player_info`]
+    PlayerInfo(Player),
+    /// What the current player was playing now plays on `to`, less `skipped` queued
+    /// tracks that couldn't be carried over
+    PlaybackTransferred { to: i64, skipped: usize },
+    /// An undo put `restored` tracks back in the current player's queue; `failed`
+    /// couldn't be added again
+    QueueRestored { restored: usize, failed: usize },
     PlayerStateChanged { pid: i64, state: PlayState },
     NowPlayingChanged { pid: i64 },
+    ProgressChanged { pid: i64, position_ms: u64, duration_ms: u64 },
     VolumeChanged { pid: i64, level: u8, mute: MuteState },
+    GroupVolumeChanged { gid: i64, level: u8 },
+    GroupsChanged,
+    /// Music sources were added, removed or changed availability
+    SourcesChanged,
+    /// The HEOS account was signed in (with its username) or out, possibly from another app
+    UserChanged(Option<String>),
     PlayModeChanged { pid: i64, repeat: RepeatMode, shuffle: ShuffleMode },
     QueueChanged { pid: i64 },
+    /// The device couldn't play the current media
+    PlaybackError {
+        pid: i64,
+        error: String,
+    },
+    /// Inputs of every player, from [`HeosHandle::load_input_sources`]
+    InputSources(Vec<InputSource>),
+    /// The Favorites source, from [`HeosHandle::load_favorites`]
+    Favorites(Vec<BrowseItem>),
     Error(String),
     Response(HeosResponse),
 }
 
+/// How long [`HeosHandle::request`] waits for the response
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Length of a soft volume ramp, and the `set_volume` calls it takes
+const FADE_DURATION: Duration = Duration::from_secs(1);
+const FADE_STEPS: u32 = 10;
+
+/// Requests waiting for a response, by correlation key. Every request carries its
+/// own `SEQUENCE` number, so a plain [`HeosHandle::send`] never takes its response.
+type PendingRequests = Arc<Mutex<HashMap<String, oneshot::Sender<HeosResponse>>>>;
+
 /// Handle for sending commands to the HEOS client
 #[derive(Clone)]
 pub struct HeosHandle {
     cmd_tx: mpsc::Sender<HeosCommand>,
+    pending: PendingRequests,
+    sequence: Arc<AtomicU64>,
+    event_tx: mpsc::Sender<HeosEvent>,
 }
 
 impl HeosHandle {
+    /// Send without waiting; the response arrives as [`HeosEvent::Response`]
     pub async fn send(&self, cmd: HeosCommand) -> Result<()> {
         self.cmd_tx
             .send(cmd)
@@ -37,12 +97,61 @@ impl HeosHandle {
             .map_err(|_| anyhow::anyhow!("Client disconnected"))
     }
 
+    /// Send and wait for this command's own response, even with others of the same
+    /// kind in flight. The response goes only to the caller, not to [`HeosEvent::Response`].
+    pub async fn request(&self, cmd: HeosCommand) -> Result<HeosResponse> {
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) + 1;
+        let cmd = cmd.param("SEQUENCE", sequence.to_string());
+        let key = cmd.correlation_key();
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().await.insert(key.clone(), tx);
+        if let Err(e) = self.send(cmd).await {
+            self.pending.lock().await.remove(&key);
+            return Err(e);
+        }
+
+        let response = match tokio::time::timeout(REQUEST_TIMEOUT, rx).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => anyhow::bail!("Client disconnected"),
+            Err(_) => {
+                self.pending.lock().await.remove(&key);
+                anyhow::bail!("No response to {}", key)
+            }
+        };
+        if let Some(error) = HeosError::from_response(&response) {
+            return Err(error.into());
+        }
+        Ok(response)
+    }
+
     pub async fn register_for_events(&self) -> Result<()> {
         self.send(protocol::register_for_change_events(true)).await
     }
 
-    pub async fn get_players(&self) -> Result<()> {
-        self.send(protocol::get_players()).await
+    pub async fn check_account(&self) -> Result<()> {
+        self.send(protocol::check_account()).await
+    }
+
+    pub async fn sign_in(&self, username: &str, password: &str) -> Result<()> {
+        self.send(protocol::sign_in(username, password)).await
+    }
+
+    pub async fn sign_out(&self) -> Result<()> {
+        self.send(protocol::sign_out()).await
+    }
+
+    pub async fn get_players(&self) -> Result<Vec<Player>> {
+        let response = self.request(protocol::get_players()).await?;
+        response
+            .get_payload_array()
+            .context("Malformed player list")
+    }
+
+    pub async fn get_player_info(&self, pid: i64) -> Result<Player> {
+        let response = self.request(protocol::get_player_info(pid)).await?;
+        response
+            .get_payload_object()
+            .context("Malformed player info")
     }
 
     pub async fn get_play_state(&self, pid: i64) -> Result<()> {
@@ -77,6 +186,14 @@ impl HeosHandle {
         self.send(protocol::get_now_playing_media(pid)).await
     }
 
+    /// What a player is playing, waiting for the answer
+    pub async fn fetch_now_playing(&self, pid: i64) -> Result<NowPlayingMedia> {
+        let response = self.request(protocol::get_now_playing_media(pid)).await?;
+        response
+            .get_payload_object()
+            .context("Malformed now playing media")
+    }
+
     pub async fn get_volume(&self, pid: i64) -> Result<()> {
         self.send(protocol::get_volume(pid)).await
     }
@@ -89,6 +206,50 @@ impl HeosHandle {
         self.send(protocol::volume_down(pid, step)).await
     }
 
+    pub async fn set_volume(&self, pid: i64, level: u8) -> Result<()> {
+        self.send(protocol::set_volume(pid, level)).await
+    }
+
+    pub async fn get_groups(&self) -> Result<()> {
+        self.send(protocol::get_groups()).await
+    }
+
+    pub async fn get_group_info(&self, gid: i64) -> Result<()> {
+        self.send(protocol::get_group_info(gid)).await
+    }
+
+    pub async fn set_group(&self, pids: &[i64]) -> Result<()> {
+        self.send(protocol::set_group(pids)).await
+    }
+
+    pub async fn get_group_volume(&self, gid: i64) -> Result<()> {
+        self.send(protocol::get_group_volume(gid)).await
+    }
+
+    pub async fn group_volume_up(&self, gid: i64, step: u8) -> Result<()> {
+        self.send(protocol::group_volume_up(gid, step)).await
+    }
+
+    pub async fn group_volume_down(&self, gid: i64, step: u8) -> Result<()> {
+        self.send(protocol::group_volume_down(gid, step)).await
+    }
+
+    pub async fn set_mute(&self, pid: i64, on: bool) -> Result<()> {
+        self.send(protocol::set_mute(pid, if on { "on" } else { "off" }))
+            .await
+    }
+
+    /// Ramp the volume from `from` to `to` over about a second
+    pub async fn fade_volume(&self, pid: i64, from: u8, to: u8) -> Result<()> {
+        let (from, to) = (i32::from(from), i32::from(to));
+        for step in 1..=FADE_STEPS as i32 {
+            let level = from + (to - from) * step / FADE_STEPS as i32;
+            self.set_volume(pid, level as u8).await?;
+            tokio::time::sleep(FADE_DURATION / FADE_STEPS).await;
+        }
+        Ok(())
+    }
+
     pub async fn toggle_mute(&self, pid: i64) -> Result<()> {
         self.send(protocol::toggle_mute(pid)).await
     }
@@ -110,25 +271,252 @@ impl HeosHandle {
         self.send(protocol::get_queue(pid, start, end)).await
     }
 
+    /// The first `count` tracks of a player's queue, waiting for the answer
+    pub async fn fetch_queue(&self, pid: i64, count: u32) -> Result<Vec<QueueItem>> {
+        let response = self
+            .request(protocol::get_queue(pid, 0, count.saturating_sub(1)))
+            .await?;
+        response.get_payload_array().context("Malformed queue")
+    }
+
+    /// How many tracks a player's queue holds, read a page at a time
+    pub async fn fetch_queue_len(&self, pid: i64) -> Result<usize> {
+        // The most get_queue returns at once
+        const PAGE: u32 = 100;
+        let mut len = 0;
+        loop {
+            let response = self
+                .request(protocol::get_queue(pid, len, len + PAGE - 1))
+                .await?;
+            let page: Vec<QueueItem> = response.get_payload_array().context("Malformed queue")?;
+            len += page.len() as u32;
+            if page.len() < PAGE as usize {
+                return Ok(len as usize);
+            }
+        }
+    }
+
     pub async fn play_queue_item(&self, pid: i64, qid: i64) -> Result<()> {
         self.send(protocol::play_queue(pid, qid)).await
     }
 
+    pub async fn remove_from_queue(&self, pid: i64, qid: i64) -> Result<()> {
+        self.send(protocol::remove_from_queue(pid, qid)).await
+    }
+
+    /// Move the track at queue position `sqid` to `dqid`; both count from 1
+    pub async fn move_queue_item(&self, pid: i64, sqid: i64, dqid: i64) -> Result<()> {
+        self.send(protocol::move_queue_item(pid, sqid, dqid)).await
+    }
+
+    pub async fn clear_queue(&self, pid: i64) -> Result<()> {
+        self.send(protocol::clear_queue(pid)).await
+    }
+
+    pub async fn get_quickselects(&self, pid: i64) -> Result<()> {
+        self.send(protocol::get_quickselects(pid)).await
+    }
+
+    pub async fn set_quickselect(&self, pid: i64, id: i64) -> Result<()> {
+        self.send(protocol::set_quickselect(pid, id)).await
+    }
+
+    pub async fn play_quickselect(&self, pid: i64, id: i64) -> Result<()> {
+        self.send(protocol::play_quickselect(pid, id)).await
+    }
+
+    /// Append a track from container `cid` to the queue and wait until it's added
+    pub async fn add_track_to_queue(&self, pid: i64, sid: i64, cid: &str, mid: &str) -> Result<()> {
+        let command = protocol::add_container_track_to_queue(pid, sid, cid, mid, 3);
+        self.request(command).await?;
+        Ok(())
+    }
+
+    /// Queue a browse item: a container, a track, or a track within a container
+    pub async fn add_to_queue(
+        &self,
+        pid: i64,
+        sid: i64,
+        cid: Option<&str>,
+        mid: Option<&str>,
+        aid: u8,
+    ) -> Result<()> {
+        let command = match (cid, mid) {
+            (Some(cid), Some(mid)) => {
+                protocol::add_container_track_to_queue(pid, sid, cid, mid, aid)
+            }
+            (Some(cid), None) => protocol::add_container_to_queue(pid, sid, cid, aid),
+            // A track is only found through the container holding it
+            (None, Some(_)) => anyhow::bail!("No container to add the track from"),
+            (None, None) => anyhow::bail!("Nothing to add to the queue"),
+        };
+        self.send(command).await
+    }
+
     pub async fn get_music_sources(&self) -> Result<()> {
         self.send(protocol::get_music_sources()).await
     }
 
     pub async fn browse_source(&self, sid: i64) -> Result<()> {
-        self.send(protocol::browse_source(sid)).await
+        self.send(protocol::browse_source(sid, 0)).await
     }
 
     pub async fn browse_container(&self, sid: i64, cid: &str) -> Result<()> {
-        self.send(protocol::browse_source_container(sid, cid)).await
+        self.send(protocol::browse_source_container(sid, cid, 0))
+            .await
+    }
+
+    /// Fetch the page of a source (`cid` None) or container starting at item `start`
+    pub async fn browse_page(&self, sid: i64, cid: Option<&str>, start: usize) -> Result<()> {
+        let command = match cid {
+            Some(cid) => protocol::browse_source_container(sid, cid, start),
+            None => protocol::browse_source(sid, start),
+        };
+        self.send(command).await
+    }
+
+    pub async fn play_preset(&self, pid: i64, preset: usize) -> Result<()> {
+        self.send(protocol::play_preset(pid, preset)).await
+    }
+
+    pub async fn get_search_criteria(&self, sid: i64) -> Result<()> {
+        self.send(protocol::get_search_criteria(sid)).await
+    }
+
+    pub async fn search(&self, sid: i64, query: &str, scid: i64) -> Result<()> {
+        self.send(protocol::search(sid, query, scid)).await
+    }
+
+    pub async fn play_station(
+        &self,
+        pid: i64,
+        sid: i64,
+        cid: Option<&str>,
+        mid: &str,
+        name: &str,
+    ) -> Result<()> {
+        self.send(protocol::play_station(pid, sid, cid, mid, name))
+            .await
+    }
+
+    pub async fn set_thumbs(&self, pid: i64, up: bool) -> Result<()> {
+        self.send(protocol::set_thumbs(pid, up)).await
+    }
+
+    pub async fn set_service_option(
+        &self,
+        sid: i64,
+        option: i64,
+        pid: i64,
+        cid: Option<&str>,
+        mid: Option<&str>,
+        name: &str,
+    ) -> Result<()> {
+        self.send(protocol::set_service_option(
+            sid, option, pid, cid, mid, name,
+        ))
+        .await
+    }
+
+    /// Replace the queue with a container's tracks and start playing
+    pub async fn replace_queue(&self, pid: i64, sid: i64, cid: &str) -> Result<()> {
+        self.send(protocol::add_container_to_queue(pid, sid, cid, 4))
+            .await
+    }
+
+    /// Append a container's tracks to the end of the queue
+    pub async fn append_to_queue(&self, pid: i64, sid: i64, cid: &str) -> Result<()> {
+        self.send(protocol::add_container_to_queue(pid, sid, cid, 3))
+            .await
+    }
+
+    pub async fn play_url(&self, pid: i64, url: &str) -> Result<()> {
+        self.send(protocol::play_url(pid, url)).await
     }
 
     pub async fn play_input(&self, pid: i64, input: &str) -> Result<()> {
         self.send(protocol::play_input(pid, input)).await
     }
+
+    /// Play an input of player `spid` on player `pid`
+    pub async fn play_input_source(&self, pid: i64, spid: i64, input: &str) -> Result<()> {
+        self.send(protocol::play_input_source(pid, spid, input))
+            .await
+    }
+
+    /// Fetch the inputs in the background; they arrive as [`HeosEvent::InputSources`]
+    pub fn load_input_sources(&self) {
+        self.report(|handle| async move {
+            handle
+                .get_input_sources()
+                .await
+                .map(HeosEvent::InputSources)
+        });
+    }
+
+    /// Fetch the players in the background; they arrive as [`HeosEvent::Players`]
+    pub fn load_players(&self) {
+        self.report(|handle| async move { handle.get_players().await.map(HeosEvent::Players) });
+    }
+
+    /// Fetch a player's details in the background; they arrive as [`HeosEvent::PlayerInfo`]
+    pub fn load_player_info(&self, pid: i64) {
+        self.report(move |handle| async move {
+            handle.get_player_info(pid).await.map(HeosEvent::PlayerInfo)
+        });
+    }
+
+    /// Fetch the favorites in the background; they arrive as [`HeosEvent::Favorites`]
+    pub fn load_favorites(&self) {
+        self.report(|handle| async move {
+            let favorites = handle
+                .request(protocol::browse_source(protocol::FAVORITES_SID, 0))
+                .await?
+                .get_payload_array()
+                .context("Malformed favorites")?;
+            Ok(HeosEvent::Favorites(favorites))
+        });
+    }
+
+    /// Run requests off the caller's task and report the result, or the error, as an event
+    pub fn report<F, Fut>(&self, fetch: F)
+    where
+        F: FnOnce(HeosHandle) -> Fut,
+        Fut: std::future::Future<Output = Result<HeosEvent>> + Send + 'static,
+    {
+        let event_tx = self.event_tx.clone();
+        let fetch = fetch(self.clone());
+        tokio::spawn(async move {
+            let event = fetch
+                .await
+                .unwrap_or_else(|e| HeosEvent::Error(e.to_string()));
+            let _ = event_tx.send(event).await;
+        });
+    }
+
+    /// The inputs of every player on the network, from the AUX Input source
+    async fn get_input_sources(&self) -> Result<Vec<InputSource>> {
+        let devices: Vec<MusicSource> = self
+            .request(protocol::browse_source(protocol::AUX_INPUTS_SID, 0))
+            .await?
+            .get_payload_array()
+            .context("Malformed input device list")?;
+        let mut inputs = Vec::new();
+        for device in devices {
+            let items: Vec<BrowseItem> = self
+                .request(protocol::browse_source(device.sid, 0))
+                .await?
+                .get_payload_array()
+                .unwrap_or_default();
+            inputs.extend(items.into_iter().map(|item| InputSource {
+                sid: device.sid,
+                name: item.name,
+                input: item.mid,
+                device: device.name.clone(),
+            }));
+        }
+        Ok(inputs)
+    }
 }
 
 pub struct HeosClient {
@@ -136,40 +524,65 @@ pub struct HeosClient {
 }
 
 impl HeosClient {
+    /// Connect again after the connection dropped, retrying with exponential backoff
+    /// until the device answers
+    pub async fn reconnect(host: &str, event_tx: mpsc::Sender<HeosEvent>) -> HeosHandle {
+        let mut delay = RECONNECT_MIN_DELAY;
+        let mut attempt = 1;
+        loop {
+            let _ = event_tx
+                .send(HeosEvent::Reconnecting {
+                    attempt,
+                    delay_secs: delay.as_secs(),
+                })
+                .await;
+            tokio::time::sleep(delay).await;
+            if let Ok(handle) = Self::connect(host, event_tx.clone()).await {
+                return handle;
+            }
+            delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+            attempt += 1;
+        }
+    }
+
     pub async fn connect(
         host: &str,
         event_tx: mpsc::Sender<HeosEvent>,
     ) -> Result<HeosHandle> {
-        let addr = format!("{}:{}", host, HEOS_PORT);
+        let addr = host_port(host, HEOS_PORT);
         let stream = TcpStream::connect(&addr)
             .await
             .context("Failed to connect to HEOS device")?;
 
-        let (read_half, write_half) = stream.into_split();
+        let (mut read_half, write_half) = stream.into_split();
         let write_half = Arc::new(Mutex::new(Some(write_half)));
 
         // Create command channel
         let (cmd_tx, mut cmd_rx) = mpsc::channel::<HeosCommand>(100);
+        let pending = PendingRequests::default();
 
         // Spawn reader task
         let event_tx_clone = event_tx.clone();
         let write_half_clone = write_half.clone();
+        let reader_pending = pending.clone();
         tokio::spawn(async move {
-            let mut reader = BufReader::new(read_half);
-            let mut line = String::new();
+            let mut framer = MessageFramer::default();
+            let mut chunk = [0u8; 8192];
 
             loop {
-                line.clear();
-                match reader.read_line(&mut line).await {
+                match read_half.read(&mut chunk).await {
                     Ok(0) => {
                         let _ = event_tx_clone.send(HeosEvent::Disconnected).await;
                         break;
                     }
-                    Ok(_) => {
-                        if let Some(response) = Self::parse_response(&line) {
+                    Ok(n) => {
+                        framer.push(&chunk[..n]);
+                        for response in framer.drain() {
                             if response.is_event() {
                                 Self::handle_event(&response, &event_tx_clone).await;
-                            } else {
+                            } else if response.is_under_process() {
+                                // Keep the request pending until the final response
+                            } else if !Self::complete_request(&reader_pending, &response).await {
                                 let _ = event_tx_clone.send(HeosEvent::Response(response)).await;
                             }
                         }
@@ -178,12 +591,15 @@ impl HeosClient {
                         let _ = event_tx_clone
                             .send(HeosEvent::Error(format!("Read error: {}", e)))
                             .await;
+                        let _ = event_tx_clone.send(HeosEvent::Disconnected).await;
                         break;
                     }
                 }
             }
 
             *write_half_clone.lock().await = None;
+            // Fail whatever is still waiting
+            reader_pending.lock().await.clear();
         });
 
         // Spawn writer task
@@ -207,15 +623,24 @@ impl HeosClient {
 
         event_tx.send(HeosEvent::Connected).await?;
 
-        Ok(HeosHandle { cmd_tx })
+        Ok(HeosHandle {
+            cmd_tx,
+            pending,
+            sequence: Arc::default(),
+            event_tx,
+        })
     }
 
-    fn parse_response(line: &str) -> Option<HeosResponse> {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            return None;
+    /// Hand a response to the request waiting for it; false if none was
+    async fn complete_request(pending: &PendingRequests, response: &HeosResponse) -> bool {
+        match pending.lock().await.remove(&response.correlation_key()) {
+            Some(tx) => {
+                // The caller may have given up meanwhile; the response is still its own
+                let _ = tx.send(response.clone());
+                true
+            }
+            None => false,
         }
-        serde_json::from_str(trimmed).ok()
     }
 
     async fn handle_event(response: &HeosResponse, tx: &mpsc::Sender<HeosEvent>) {
@@ -235,6 +660,22 @@ impl HeosClient {
                 let pid = params.get("pid").and_then(|s| s.parse().ok()).unwrap_or(0);
                 Some(HeosEvent::NowPlayingChanged { pid })
             }
+            protocol::EVENT_PLAYER_NOW_PLAYING_PROGRESS => {
+                let pid = params.get("pid").and_then(|s| s.parse().ok()).unwrap_or(0);
+                let position_ms = params
+                    .get("cur_pos")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0);
+                let duration_ms = params
+                    .get("duration")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0);
+                Some(HeosEvent::ProgressChanged {
+                    pid,
+                    position_ms,
+                    duration_ms,
+                })
+            }
             protocol::EVENT_PLAYER_VOLUME_CHANGED => {
                 let pid = params.get("pid").and_then(|s| s.parse().ok()).unwrap_or(0);
                 let level = params.get("level").and_then(|s| s.parse().ok()).unwrap_or(0);
@@ -244,6 +685,11 @@ impl HeosClient {
                     .unwrap_or_default();
                 Some(HeosEvent::VolumeChanged { pid, level, mute })
             }
+            protocol::EVENT_GROUP_VOLUME_CHANGED => {
+                let gid = params.get("gid").and_then(|s| s.parse().ok()).unwrap_or(0);
+                let level = params.get("level").and_then(|s| s.parse().ok()).unwrap_or(0);
+                Some(HeosEvent::GroupVolumeChanged { gid, level })
+            }
             protocol::EVENT_REPEAT_MODE_CHANGED | protocol::EVENT_SHUFFLE_MODE_CHANGED => {
                 let pid = params.get("pid").and_then(|s| s.parse().ok()).unwrap_or(0);
                 let repeat = params
@@ -256,12 +702,27 @@ impl HeosClient {
                     .unwrap_or_default();
                 Some(HeosEvent::PlayModeChanged { pid, repeat, shuffle })
             }
+            protocol::EVENT_PLAYER_PLAYBACK_ERROR => {
+                let pid = params.get("pid").and_then(|s| s.parse().ok()).unwrap_or(0);
+                let error = params.get("error").cloned().unwrap_or_default();
+                Some(HeosEvent::PlaybackError { pid, error })
+            }
             protocol::EVENT_PLAYER_QUEUE_CHANGED => {
                 let pid = params.get("pid").and_then(|s| s.parse().ok()).unwrap_or(0);
                 Some(HeosEvent::QueueChanged { pid })
             }
+            protocol::EVENT_GROUPS_CHANGED => Some(HeosEvent::GroupsChanged),
+            protocol::EVENT_SOURCES_CHANGED => Some(HeosEvent::SourcesChanged),
+            protocol::EVENT_USER_CHANGED => {
+                let user = if params.contains_key("signed_in") {
+                    params.get("un").cloned()
+                } else {
+                    None
+                };
+                Some(HeosEvent::UserChanged(user))
+            }
             protocol::EVENT_PLAYERS_CHANGED => {
-                Some(HeosEvent::PlayersChanged(Vec::new()))
+                Some(HeosEvent::PlayersChanged)
             }
             _ => None,
         };
@@ -271,3 +732,54 @@ impl HeosClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue_response(message: &str, songs: &[&str]) -> HeosResponse {
+        let payload: Vec<_> = songs
+            .iter()
+            .enumerate()
+            .map(|(i, song)| serde_json::json!({ "qid": i + 1, "song": song }))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "heos": { "command": "player/get_queue", "result": "success", "message": message },
+            "payload": payload,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn request_ignores_plain_send_on_same_player() {
+        let (cmd_tx, mut cmd_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::channel(10);
+        let pending = PendingRequests::default();
+        let handle = HeosHandle {
+            cmd_tx,
+            pending: pending.clone(),
+            sequence: Arc::default(),
+            event_tx,
+        };
+
+        handle.get_queue(5, 0, 0).await.unwrap();
+        let request = tokio::spawn({
+            let handle = handle.clone();
+            async move { handle.fetch_queue(5, 100).await }
+        });
+        let sent = cmd_rx.recv().await.unwrap().to_string();
+        let requested = cmd_rx.recv().await.unwrap().to_string();
+        assert!(!sent.contains("SEQUENCE"));
+        let (_, sequence) = requested.trim_end().split_once("SEQUENCE=").unwrap();
+
+        // The plain send's response arrives first and must not resolve the request
+        let plain = queue_response("pid=5&range=0,0", &["First"]);
+        assert!(!HeosClient::complete_request(&pending, &plain).await);
+        let message = format!("pid=5&range=0,99&SEQUENCE={}", sequence);
+        let own = queue_response(&message, &["First", "Second"]);
+        assert!(HeosClient::complete_request(&pending, &own).await);
+
+        let queue = request.await.unwrap().unwrap();
+        assert_eq!(queue.len(), 2);
+    }
+}
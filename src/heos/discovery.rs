@@ -1,14 +1,19 @@
 use anyhow::Result;
-use std::net::{Ipv4Addr, SocketAddrV4};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 use std::time::Duration;
 use tokio::net::UdpSocket;
 use tokio::time::timeout;
 
 const SSDP_MULTICAST_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
+/// Link-local SSDP group; devices answer on both families when dual-stack
+const SSDP_MULTICAST_ADDR_V6: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xc);
 const SSDP_PORT: u16 = 1900;
 
+/// Startup discovery rounds before giving up when nothing answers
+pub const DISCOVERY_ROUNDS: u32 = 3;
+
 // Try multiple search targets for better compatibility
-const SEARCH_TARGETS: &[&str] = &[
+pub const SEARCH_TARGETS: &[&str] = &[
     "urn:schemas-denon-com:device:ACT-Denon:1",
     "urn:schemas-upnp-org:device:MediaRenderer:1",
     "ssdp:all",
@@ -21,29 +26,45 @@ pub struct DiscoveredDevice {
     pub friendly_name: Option<String>,
 }
 
+/// What a running discovery has done so far, for the progress display
+#[derive(Debug, Clone)]
+pub enum DiscoveryProgress {
+    /// A search round started, counting from 1
+    Round(u32),
+    /// An M-SEARCH went out for this search target
+    SearchSent(&'static str),
+    /// A HEOS device answered from this address
+    Responder(String),
+}
+
 pub async fn discover_devices(timeout_secs: u64) -> Result<Vec<DiscoveredDevice>> {
+    discover_devices_with_progress(timeout_secs, |_| {}).await
+}
+
+/// [`discover_devices`], reporting each search sent and each responder as it happens
+pub async fn discover_devices_with_progress(
+    timeout_secs: u64,
+    mut progress: impl FnMut(DiscoveryProgress),
+) -> Result<Vec<DiscoveredDevice>> {
     let socket = UdpSocket::bind("0.0.0.0:0").await?;
     socket.set_broadcast(true)?;
-
-    let multicast_addr = SocketAddrV4::new(SSDP_MULTICAST_ADDR, SSDP_PORT);
-
-    // Send search requests for each target
-    for search_target in SEARCH_TARGETS {
-        let search_msg = format!(
-            "M-SEARCH * HTTP/1.1\r\n\
-             HOST: {}:{}\r\n\
-             MAN: \"ssdp:discover\"\r\n\
-             MX: 3\r\n\
-             ST: {}\r\n\
-             \r\n",
-            SSDP_MULTICAST_ADDR, SSDP_PORT, search_target
-        );
-
-        let _ = socket.send_to(search_msg.as_bytes(), multicast_addr).await;
-    }
+    let multicast_addr = SocketAddr::V4(SocketAddrV4::new(SSDP_MULTICAST_ADDR, SSDP_PORT));
+    send_searches(&socket, multicast_addr, &mut progress).await;
+
+    // IPv6 is best effort: hosts without it still discover over IPv4
+    let socket_v6 = match UdpSocket::bind("[::]:0").await {
+        Ok(socket_v6) => {
+            let multicast_addr_v6 =
+                SocketAddr::V6(SocketAddrV6::new(SSDP_MULTICAST_ADDR_V6, SSDP_PORT, 0, 0));
+            send_searches(&socket_v6, multicast_addr_v6, &mut progress).await;
+            Some(socket_v6)
+        }
+        Err(_) => None,
+    };
 
     let mut devices = Vec::new();
     let mut buf = [0u8; 2048];
+    let mut buf_v6 = [0u8; 2048];
 
     let discovery_timeout = Duration::from_secs(timeout_secs);
     let deadline = tokio::time::Instant::now() + discovery_timeout;
@@ -54,9 +75,25 @@ pub async fn discover_devices(timeout_secs: u64) -> Result<Vec<DiscoveredDevice>
             break;
         }
 
-        match timeout(remaining, socket.recv_from(&mut buf)).await {
-            Ok(Ok((len, addr))) => {
-                let response = String::from_utf8_lossy(&buf[..len]);
+        let received = timeout(remaining, async {
+            match &socket_v6 {
+                Some(socket_v6) => tokio::select! {
+                    r = socket.recv_from(&mut buf) => r.map(|(len, addr)| (&buf[..len], addr)),
+                    r = socket_v6.recv_from(&mut buf_v6) => {
+                        r.map(|(len, addr)| (&buf_v6[..len], addr))
+                    }
+                },
+                None => socket
+                    .recv_from(&mut buf)
+                    .await
+                    .map(|(len, addr)| (&buf[..len], addr)),
+            }
+        })
+        .await;
+
+        match received {
+            Ok(Ok((data, addr))) => {
+                let response = String::from_utf8_lossy(data);
 
                 // Check if this looks like a HEOS/Denon device
                 let is_heos = response.to_lowercase().contains("heos")
@@ -65,11 +102,12 @@ pub async fn discover_devices(timeout_secs: u64) -> Result<Vec<DiscoveredDevice>
                     || response.contains("ACT-Denon");
 
                 if is_heos {
-                    let ip = addr.ip().to_string();
+                    let ip = host_of(addr);
                     let location = parse_header(&response, "LOCATION");
 
                     // Avoid duplicates
                     if !devices.iter().any(|d: &DiscoveredDevice| d.ip == ip) {
+                        progress(DiscoveryProgress::Responder(ip.clone()));
                         devices.push(DiscoveredDevice {
                             ip,
                             location: location.unwrap_or_default(),
@@ -86,6 +124,58 @@ pub async fn discover_devices(timeout_secs: u64) -> Result<Vec<DiscoveredDevice>
     Ok(devices)
 }
 
+/// Send an M-SEARCH for every search target to a multicast group
+async fn send_searches(
+    socket: &UdpSocket,
+    multicast_addr: SocketAddr,
+    progress: &mut impl FnMut(DiscoveryProgress),
+) {
+    for search_target in SEARCH_TARGETS {
+        let search_msg = format!(
+            "M-SEARCH * HTTP/1.1\r\n\
+             HOST: {}\r\n\
+             MAN: \"ssdp:discover\"\r\n\
+             MX: 3\r\n\
+             ST: {}\r\n\
+             \r\n",
+            multicast_addr, search_target
+        );
+
+        if socket
+            .send_to(search_msg.as_bytes(), multicast_addr)
+            .await
+            .is_ok()
+        {
+            progress(DiscoveryProgress::SearchSent(search_target));
+        }
+    }
+}
+
+/// Responder address as a host string; link-local IPv6 keeps its scope so it stays reachable
+fn host_of(addr: SocketAddr) -> String {
+    match addr {
+        SocketAddr::V6(v6) if v6.scope_id() != 0 && v6.ip().is_unicast_link_local() => {
+            format!("{}%{}", v6.ip(), v6.scope_id())
+        }
+        _ => addr.ip().to_string(),
+    }
+}
+
+/// `host:port` for a TCP connect, bracketing IPv6 literals (`[::1]:1255`)
+pub fn host_port(host: &str, port: u16) -> String {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let is_v6 = host.contains(':')
+        && host
+            .split('%')
+            .next()
+            .is_some_and(|ip| ip.parse::<IpAddr>().is_ok());
+    if is_v6 {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
 fn parse_header(response: &str, header: &str) -> Option<String> {
     for line in response.lines() {
         let line_upper = line.to_uppercase();
@@ -96,7 +186,26 @@ fn parse_header(response: &str, header: &str) -> Option<String> {
     None
 }
 
-pub async fn discover_first_device(timeout_secs: u64) -> Result<Option<String>> {
-    let devices = discover_devices(timeout_secs).await?;
-    Ok(devices.into_iter().next().map(|d| d.ip))
+/// Live discovery results followed by `known` devices it missed, deduplicated by IP.
+/// Configured names replace discovered ones, and a failed search still yields `known`.
+pub async fn discover_with_known(
+    timeout_secs: u64,
+    known: &[DiscoveredDevice],
+) -> Vec<DiscoveredDevice> {
+    let devices = discover_devices(timeout_secs).await.unwrap_or_default();
+    merge_known(devices, known)
+}
+
+/// Add configured known devices to discovery results, naming the ones that answered
+pub fn merge_known(
+    mut devices: Vec<DiscoveredDevice>,
+    known: &[DiscoveredDevice],
+) -> Vec<DiscoveredDevice> {
+    for device in known {
+        match devices.iter_mut().find(|d| d.ip == device.ip) {
+            Some(found) => found.friendly_name = device.friendly_name.clone(),
+            None => devices.push(device.clone()),
+        }
+    }
+    devices
 }
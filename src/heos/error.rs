@@ -0,0 +1,84 @@
+use super::protocol::{self, HeosResponse};
+use thiserror::Error;
+
+/// A failed HEOS command, from the `eid` (and for system errors `syserrno`) of the
+/// response. Messages say what to do where there's something to do.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum HeosError {
+    #[error("The device didn't recognize the command")]
+    UnrecognizedCommand,
+    #[error("That player, group or item no longer exists")]
+    InvalidId,
+    #[error("Wrong number of command arguments")]
+    WrongArguments,
+    #[error("The requested data isn't available")]
+    DataNotAvailable,
+    #[error("The service isn't available right now, try again later")]
+    ResourceNotAvailable,
+    #[error("Wrong HEOS account email or password")]
+    InvalidCredentials,
+    #[error("The command couldn't be run: {0}")]
+    CommandFailed(String),
+    #[error("Sign in to your HEOS account first (K)")]
+    NotSignedIn,
+    #[error("Value out of range")]
+    OutOfRange,
+    #[error("No HEOS account with that email")]
+    UserNotFound,
+    #[error("Internal device error")]
+    Internal,
+    #[error("System error {0}")]
+    System(i64),
+    #[error("The device is still busy with the previous command")]
+    Busy,
+    #[error("This media can't be played")]
+    MediaNotPlayable,
+    #[error("Option not supported")]
+    OptionNotSupported,
+    #[error("Too many commands queued on the device")]
+    TooManyCommands,
+    #[error("Skip limit reached for this station")]
+    SkipLimitReached,
+    /// An error id this doesn't know, with the device's own text
+    #[error("{text}")]
+    Other { eid: i64, text: String },
+}
+
+impl HeosError {
+    /// The error a response reports; `None` if it succeeded or is an event
+    pub fn from_response(response: &HeosResponse) -> Option<Self> {
+        if response.is_success() || response.is_event() {
+            return None;
+        }
+        let params = response.parse_message();
+        let number = |key: &str| params.get(key).and_then(|v| v.parse::<i64>().ok());
+        let text = params
+            .get("text")
+            .cloned()
+            .unwrap_or_else(|| protocol::decode_value(&response.heos.message));
+        let error = match number("eid") {
+            Some(1) => HeosError::UnrecognizedCommand,
+            Some(2) => HeosError::InvalidId,
+            Some(3) => HeosError::WrongArguments,
+            Some(4) => HeosError::DataNotAvailable,
+            Some(5) => HeosError::ResourceNotAvailable,
+            Some(6) => HeosError::InvalidCredentials,
+            Some(7) => HeosError::CommandFailed(text),
+            Some(8) => HeosError::NotSignedIn,
+            Some(9) => HeosError::OutOfRange,
+            Some(10) => HeosError::UserNotFound,
+            Some(11) => HeosError::Internal,
+            Some(12) => HeosError::System(number("syserrno").unwrap_or_default()),
+            Some(13) => HeosError::Busy,
+            Some(14) => HeosError::MediaNotPlayable,
+            Some(15) => HeosError::OptionNotSupported,
+            Some(16) => HeosError::TooManyCommands,
+            Some(17) => HeosError::SkipLimitReached,
+            eid => HeosError::Other {
+                eid: eid.unwrap_or_default(),
+                text,
+            },
+        };
+        Some(error)
+    }
+}
@@ -1,10 +1,19 @@
 pub mod avr;
 pub mod client;
 pub mod discovery;
+pub mod error;
 pub mod protocol;
 pub mod types;
 
-pub use avr::{AvrClient, AvrEvent, AvrHandle, SurroundMode};
+pub use avr::{
+    AvrClient, AvrEvent, AvrHandle, AvrVolume, AvrZone, DynamicCompression, DynamicVolume, EcoMode,
+    HdmiMonitor, ImaxMode, MultEq, QuickSelect, Restorer, SignalField, SurroundMode,
+    SurroundParameter, Tone, Upmixer, REFERENCE_LEVEL_OFFSETS, SLEEP_TIMER_MINUTES,
+};
 pub use client::{HeosClient, HeosEvent, HeosHandle};
-pub use discovery::discover_first_device;
+pub use discovery::{
+    discover_devices_with_progress, discover_with_known, merge_known, DiscoveredDevice,
+    DiscoveryProgress, DISCOVERY_ROUNDS, SEARCH_TARGETS,
+};
+pub use error::HeosError;
 pub use types::*;
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
+use super::types::ServiceOption;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeosResponse {
     pub heos: HeosHeader,
@@ -28,6 +30,11 @@ impl HeosResponse {
         self.heos.result.is_none()
     }
 
+    /// Interim answer to slow commands (browse, sign-in); the real response follows
+    pub fn is_under_process(&self) -> bool {
+        self.heos.message.starts_with("command under process")
+    }
+
     pub fn parse_message(&self) -> HashMap<String, String> {
         parse_message_string(&self.heos.message)
     }
@@ -36,9 +43,71 @@ impl HeosResponse {
         serde_json::from_value(self.payload.clone()).ok()
     }
 
+    /// Service options sent along with browse results. The CLI groups them by
+    /// context (`[{"browse": [...]}]`); the groups are flattened here
+    pub fn get_options(&self) -> Vec<ServiceOption> {
+        serde_json::from_value::<Vec<HashMap<String, Vec<ServiceOption>>>>(self.options.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(HashMap::into_values)
+            .flatten()
+            .collect()
+    }
+
     pub fn get_payload_object<T: for<'de> Deserialize<'de>>(&self) -> Option<T> {
         serde_json::from_value(self.payload.clone()).ok()
     }
+
+    /// Matches [`HeosCommand::correlation_key`] of the command this answers
+    pub fn correlation_key(&self) -> String {
+        let params = self.parse_message();
+        let target = params.get("pid").or_else(|| params.get("gid"));
+        let sequence = params.get("SEQUENCE");
+        correlation_key(
+            &self.heos.command,
+            target.map_or("", String::as_str),
+            sequence.map_or("", String::as_str),
+        )
+    }
+}
+
+/// Command name, the player or group it targets and its `SEQUENCE` number;
+/// the CLI echoes all three in responses
+fn correlation_key(command: &str, target: &str, sequence: &str) -> String {
+    format!("{}|{}|{}", command, target, sequence)
+}
+
+/// Splits the CLI byte stream into responses. A read can end mid-message or carry
+/// several messages at once; an incomplete tail stays buffered until the rest arrives.
+#[derive(Debug, Default)]
+pub struct MessageFramer {
+    buf: Vec<u8>,
+}
+
+impl MessageFramer {
+    pub fn push(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Every complete response buffered so far, in arrival order
+    pub fn drain(&mut self) -> Vec<HeosResponse> {
+        let mut responses = Vec::new();
+        // Messages end in CRLF; splitting on LF also copes with a bare newline
+        while let Some(end) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            // Back-to-back objects without a separator still yield each message
+            let stream =
+                serde_json::Deserializer::from_str(line.trim()).into_iter::<HeosResponse>();
+            for response in stream {
+                match response {
+                    Ok(response) => responses.push(response),
+                    Err(_) => break,
+                }
+            }
+        }
+        responses
+    }
 }
 
 pub fn parse_message_string(message: &str) -> HashMap<String, String> {
@@ -47,13 +116,47 @@ pub fn parse_message_string(message: &str) -> HashMap<String, String> {
         return map;
     }
     for pair in message.split('&') {
-        if let Some((key, value)) = pair.split_once('=') {
-            map.insert(key.to_string(), value.to_string());
-        }
+        match pair.split_once('=') {
+            Some((key, value)) => map.insert(decode_value(key), decode_value(value)),
+            // Bare flags such as `signed_in` or `signed_out`
+            None => map.insert(decode_value(pair), String::new()),
+        };
     }
     map
 }
 
+/// Undo the percent-encoding the CLI applies to message values (`%26`, `%C3%A9`, ...)
+pub fn decode_value(value: &str) -> String {
+    if !value.contains('%') {
+        return value.to_string();
+    }
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes.get(i + 1..i + 3) {
+            Some(hex) if bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit) => {
+                std::str::from_utf8(hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            }
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            // Leave stray `%` signs as they are
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
 #[derive(Debug, Clone)]
 pub struct HeosCommand {
     pub group: &'static str,
@@ -75,6 +178,21 @@ impl HeosCommand {
         self
     }
 
+    /// Key pairing this command with its response
+    pub fn correlation_key(&self) -> String {
+        let find = |keys: &[&str]| {
+            self.params
+                .iter()
+                .find(|(key, _)| keys.contains(&key.as_str()))
+                .map_or("", |(_, value)| value.as_str())
+        };
+        correlation_key(
+            &format!("{}/{}", self.group, self.command),
+            find(&["pid", "gid"]),
+            find(&["SEQUENCE"]),
+        )
+    }
+
     pub fn to_string(&self) -> String {
         let mut cmd = format!("heos://{}/{}", self.group, self.command);
         if !self.params.is_empty() {
@@ -91,6 +209,14 @@ impl HeosCommand {
     }
 }
 
+/// Escape the characters the CLI treats as separators in free-text values
+fn encode_value(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('&', "%26")
+        .replace('=', "%3D")
+}
+
 // System commands
 pub fn register_for_change_events(enable: bool) -> HeosCommand {
     HeosCommand::new("system", "register_for_change_events")
@@ -101,6 +227,16 @@ pub fn check_account() -> HeosCommand {
     HeosCommand::new("system", "check_account")
 }
 
+pub fn sign_in(username: &str, password: &str) -> HeosCommand {
+    HeosCommand::new("system", "sign_in")
+        .param("un", encode_value(username))
+        .param("pw", encode_value(password))
+}
+
+pub fn sign_out() -> HeosCommand {
+    HeosCommand::new("system", "sign_out")
+}
+
 pub fn heart_beat() -> HeosCommand {
     HeosCommand::new("system", "heart_beat")
 }
@@ -193,6 +329,13 @@ pub fn remove_from_queue(pid: i64, qid: i64) -> HeosCommand {
         .param("qid", qid.to_string())
 }
 
+pub fn move_queue_item(pid: i64, sqid: i64, dqid: i64) -> HeosCommand {
+    HeosCommand::new("player", "move_queue_item")
+        .param("pid", pid.to_string())
+        .param("sqid", sqid.to_string())
+        .param("dqid", dqid.to_string())
+}
+
 pub fn clear_queue(pid: i64) -> HeosCommand {
     HeosCommand::new("player", "clear_queue").param("pid", pid.to_string())
 }
@@ -205,7 +348,63 @@ pub fn play_previous(pid: i64) -> HeosCommand {
     HeosCommand::new("player", "play_previous").param("pid", pid.to_string())
 }
 
+/// Quick selects are the device's numbered source presets (1-6)
+pub fn get_quickselects(pid: i64) -> HeosCommand {
+    HeosCommand::new("player", "get_quickselects").param("pid", pid.to_string())
+}
+
+/// Save what the player is playing into quick select `id`
+pub fn set_quickselect(pid: i64, id: i64) -> HeosCommand {
+    HeosCommand::new("player", "set_quickselect")
+        .param("pid", pid.to_string())
+        .param("id", id.to_string())
+}
+
+pub fn play_quickselect(pid: i64, id: i64) -> HeosCommand {
+    HeosCommand::new("player", "play_quickselect")
+        .param("pid", pid.to_string())
+        .param("id", id.to_string())
+}
+
+// Group commands
+pub fn get_groups() -> HeosCommand {
+    HeosCommand::new("group", "get_groups")
+}
+
+pub fn get_group_info(gid: i64) -> HeosCommand {
+    HeosCommand::new("group", "get_group_info").param("gid", gid.to_string())
+}
+
+/// Group `pids` with the first as leader; a single pid ungroups that leader's group
+pub fn set_group(pids: &[i64]) -> HeosCommand {
+    let pids: Vec<String> = pids.iter().map(|pid| pid.to_string()).collect();
+    HeosCommand::new("group", "set_group").param("pid", pids.join(","))
+}
+
+pub fn get_group_volume(gid: i64) -> HeosCommand {
+    HeosCommand::new("group", "get_volume").param("gid", gid.to_string())
+}
+
+pub fn group_volume_up(gid: i64, step: u8) -> HeosCommand {
+    HeosCommand::new("group", "volume_up")
+        .param("gid", gid.to_string())
+        .param("step", step.to_string())
+}
+
+pub fn group_volume_down(gid: i64, step: u8) -> HeosCommand {
+    HeosCommand::new("group", "volume_down")
+        .param("gid", gid.to_string())
+        .param("step", step.to_string())
+}
+
 // Browse commands
+
+/// Source id of HEOS Favorites, browsable like any music source
+pub const FAVORITES_SID: i64 = 1028;
+
+/// Source id of the HEOS playlists saved on the account
+pub const PLAYLISTS_SID: i64 = 1025;
+
 pub fn get_music_sources() -> HeosCommand {
     HeosCommand::new("browse", "get_music_sources")
 }
@@ -214,21 +413,129 @@ pub fn get_source_info(sid: i64) -> HeosCommand {
     HeosCommand::new("browse", "get_source_info").param("sid", sid.to_string())
 }
 
-pub fn browse_source(sid: i64) -> HeosCommand {
-    HeosCommand::new("browse", "browse").param("sid", sid.to_string())
+/// Items fetched per `browse` request; larger containers are read in pages
+pub const BROWSE_PAGE_SIZE: usize = 100;
+
+/// `range` value for the page starting at item `start` (0-based, inclusive end)
+fn browse_range(start: usize) -> String {
+    format!("{},{}", start, start + BROWSE_PAGE_SIZE - 1)
 }
 
-pub fn browse_source_container(sid: i64, cid: &str) -> HeosCommand {
+/// Source listing every player with physical inputs; each entry browses to its inputs
+pub const AUX_INPUTS_SID: i64 = 1027;
+
+pub fn browse_source(sid: i64, start: usize) -> HeosCommand {
+    HeosCommand::new("browse", "browse")
+        .param("sid", sid.to_string())
+        .param("range", browse_range(start))
+}
+
+pub fn browse_source_container(sid: i64, cid: &str, start: usize) -> HeosCommand {
     HeosCommand::new("browse", "browse")
         .param("sid", sid.to_string())
         .param("cid", cid)
+        .param("range", browse_range(start))
 }
 
-pub fn play_station(pid: i64, sid: i64, mid: &str) -> HeosCommand {
-    HeosCommand::new("browse", "play_stream")
+/// `preset` is the 1-based position in HEOS Favorites
+pub fn play_preset(pid: i64, preset: usize) -> HeosCommand {
+    HeosCommand::new("browse", "play_preset")
+        .param("pid", pid.to_string())
+        .param("preset", preset.to_string())
+}
+
+pub fn get_search_criteria(sid: i64) -> HeosCommand {
+    HeosCommand::new("browse", "get_search_criteria").param("sid", sid.to_string())
+}
+
+pub fn search(sid: i64, query: &str, scid: i64) -> HeosCommand {
+    HeosCommand::new("browse", "search")
+        .param("sid", sid.to_string())
+        .param("search", encode_value(query))
+        .param("scid", scid.to_string())
+}
+
+/// Play a station or track; `cid` is the container it was browsed in, if any
+pub fn play_station(pid: i64, sid: i64, cid: Option<&str>, mid: &str, name: &str) -> HeosCommand {
+    let command = HeosCommand::new("browse", "play_stream")
+        .param("pid", pid.to_string())
+        .param("sid", sid.to_string());
+    let command = match cid {
+        Some(cid) => command.param("cid", cid),
+        None => command,
+    };
+    command.param("mid", mid).param("name", encode_value(name))
+}
+
+/// Rate what's playing on a Pandora or iHeartRadio station. The CLI has no
+/// `player/play_action`; thumbs are service options 11 (up) and 12 (down)
+pub fn set_thumbs(pid: i64, up: bool) -> HeosCommand {
+    HeosCommand::new("browse", "set_service_option")
+        .param("option", if up { "11" } else { "12" })
+        .param("pid", pid.to_string())
+}
+
+/// Run a service option from a browse response. Which of `cid`, `mid` and `name`
+/// go along depends on the option, per the CLI spec's option table
+pub fn set_service_option(
+    sid: i64,
+    option: i64,
+    pid: i64,
+    cid: Option<&str>,
+    mid: Option<&str>,
+    name: &str,
+) -> HeosCommand {
+    let cmd = HeosCommand::new("browse", "set_service_option")
+        .param("sid", sid.to_string())
+        .param("option", option.to_string());
+    match (option, cid, mid) {
+        // Add/remove track or station, remove from HEOS Favorites
+        (1 | 3 | 5 | 7 | 20, _, Some(mid)) => cmd.param("mid", mid),
+        // Add/remove album or playlist
+        (2 | 6 | 8, Some(cid), _) => cmd.param("cid", cid),
+        (4, Some(cid), _) => cmd.param("cid", cid).param("name", encode_value(name)),
+        // Thumbs and adding what's playing to HEOS Favorites act on the player
+        (11 | 12, _, _) | (19, _, None) => HeosCommand::new("browse", "set_service_option")
+            .param("option", option.to_string())
+            .param("pid", pid.to_string()),
+        (19, _, Some(mid)) => cmd.param("mid", mid).param("name", encode_value(name)),
+        // Create a new station from a search string
+        (13, _, _) => cmd.param("name", encode_value(name)),
+        _ => cmd,
+    }
+}
+
+/// `aid`: 1 play now, 2 play next, 3 add to end, 4 replace queue and play
+pub fn add_container_to_queue(pid: i64, sid: i64, cid: &str, aid: u8) -> HeosCommand {
+    HeosCommand::new("browse", "add_to_queue")
+        .param("pid", pid.to_string())
+        .param("sid", sid.to_string())
+        .param("cid", cid)
+        .param("aid", aid.to_string())
+}
+
+/// Add a track found inside a container (album, playlist); `aid` as for
+/// [`add_container_to_queue`]
+pub fn add_container_track_to_queue(
+    pid: i64,
+    sid: i64,
+    cid: &str,
+    mid: &str,
+    aid: u8,
+) -> HeosCommand {
+    HeosCommand::new("browse", "add_to_queue")
         .param("pid", pid.to_string())
         .param("sid", sid.to_string())
+        .param("cid", cid)
         .param("mid", mid)
+        .param("aid", aid.to_string())
+}
+
+/// Play a URL directly; `url` must stay the last parameter
+pub fn play_url(pid: i64, url: &str) -> HeosCommand {
+    HeosCommand::new("browse", "play_stream")
+        .param("pid", pid.to_string())
+        .param("url", url)
 }
 
 pub fn play_input(pid: i64, input: &str) -> HeosCommand {
@@ -255,4 +562,6 @@ pub const EVENT_REPEAT_MODE_CHANGED: &str = "event/repeat_mode_changed";
 pub const EVENT_SHUFFLE_MODE_CHANGED: &str = "event/shuffle_mode_changed";
 pub const EVENT_PLAYERS_CHANGED: &str = "event/players_changed";
 pub const EVENT_GROUPS_CHANGED: &str = "event/groups_changed";
+pub const EVENT_GROUP_VOLUME_CHANGED: &str = "event/group_volume_changed";
 pub const EVENT_SOURCES_CHANGED: &str = "event/sources_changed";
+pub const EVENT_USER_CHANGED: &str = "event/user_changed";
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
@@ -15,6 +16,41 @@ pub struct Player {
     pub lineout: i32,
     #[serde(default)]
     pub serial: String,
+    /// Group the player belongs to, if any
+    #[serde(default)]
+    pub gid: Option<i64>,
+}
+
+/// A HEOS group as returned by `group/get_groups`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    pub name: String,
+    pub gid: i64,
+    #[serde(default)]
+    pub players: Vec<GroupMember>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMember {
+    pub name: String,
+    pub pid: i64,
+    /// "leader" or "member"
+    #[serde(default)]
+    pub role: String,
+}
+
+impl Group {
+    pub fn contains(&self, pid: i64) -> bool {
+        self.players.iter().any(|p| p.pid == pid)
+    }
+
+    pub fn leader(&self) -> Option<i64> {
+        self.players
+            .iter()
+            .find(|p| p.role == "leader")
+            .or(self.players.first())
+            .map(|p| p.pid)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -37,6 +73,39 @@ pub struct NowPlayingMedia {
     pub station: String,
     #[serde(rename = "type", default)]
     pub media_type: String,
+    /// Audio quality some services report, like "HD"; kept as sent, string or number
+    #[serde(default)]
+    pub quality: Option<Value>,
+    /// Bit rate in kbps, where the payload has one
+    #[serde(default, alias = "bit_rate")]
+    pub bitrate: Option<Value>,
+}
+
+impl NowPlayingMedia {
+    pub fn is_station(&self) -> bool {
+        self.media_type == "station"
+    }
+
+    /// Quality and bit rate for display, like "HD 320 kbps"; `None` when neither is reported
+    pub fn quality_text(&self) -> Option<String> {
+        let quality = match &self.quality {
+            Some(Value::String(s)) => s.trim().to_string(),
+            Some(Value::Number(n)) => n.to_string(),
+            _ => String::new(),
+        };
+        let bitrate = match &self.bitrate {
+            Some(Value::Number(n)) => format!("{} kbps", n),
+            Some(Value::String(s)) if s.parse::<u32>().is_ok() => format!("{} kbps", s),
+            Some(Value::String(s)) => s.trim().to_string(),
+            _ => String::new(),
+        };
+        let text = [quality, bitrate]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!text.is_empty()).then_some(text)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -167,6 +236,9 @@ pub struct QueueItem {
     pub image_url: String,
     #[serde(default)]
     pub mid: String,
+    /// Track length in milliseconds, when the service reports one
+    #[serde(default)]
+    pub duration: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -200,12 +272,42 @@ pub struct BrowseItem {
     pub playable: String,
 }
 
+/// An action a music service offers on browse results, e.g. "Add to HEOS Favorites"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceOption {
+    pub id: i64,
+    pub name: String,
+}
+
+/// A named source preset stored on a HEOS device
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickSelectSlot {
+    pub id: i64,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A way a music service can be searched, e.g. by artist or track
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchCriteria {
+    pub name: String,
+    pub scid: i64,
+    #[serde(default)]
+    pub wildcard: String,
+}
+
+/// A physical input of a player on the network, from browsing the AUX Input source
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputSource {
+    /// The player's own source id, its pid; `spid` when played on another player
     pub sid: i64,
     pub name: String,
+    /// Input id like `inputs/hdmi_in_1`
     #[serde(default)]
     pub input: String,
+    /// Name of the player the input belongs to
+    #[serde(default)]
+    pub device: String,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -217,4 +319,7 @@ pub struct PlayerState {
     pub mute: MuteState,
     pub repeat: RepeatMode,
     pub shuffle: ShuffleMode,
+    /// Playback position and track length in milliseconds, from progress events
+    pub position_ms: u64,
+    pub duration_ms: u64,
 }
@@ -0,0 +1,138 @@
+use crate::heos::NowPlayingMedia;
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Entries kept; older ones are dropped from the file when it's loaded
+const HISTORY_LIMIT: usize = 500;
+
+/// A track or station that started playing
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unix time in seconds
+    pub played_at: i64,
+    #[serde(default)]
+    pub player: String,
+    #[serde(default)]
+    pub song: String,
+    #[serde(default)]
+    pub artist: String,
+    #[serde(default)]
+    pub album: String,
+    #[serde(default)]
+    pub station: String,
+    /// Name of the music source, resolved from `sid` when recorded
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub sid: i64,
+    #[serde(default)]
+    pub mid: String,
+    /// Container the track was queued from, when it was queued from Browse; tracks
+    /// are only played again through it
+    #[serde(default)]
+    pub cid: String,
+    #[serde(default)]
+    pub media_type: String,
+}
+
+impl HistoryEntry {
+    pub fn new(media: &NowPlayingMedia, player: &str, source: &str) -> Self {
+        Self {
+            played_at: Local::now().timestamp(),
+            player: player.to_string(),
+            song: media.song.clone(),
+            artist: media.artist.clone(),
+            album: media.album.clone(),
+            station: media.station.clone(),
+            source: source.to_string(),
+            sid: media.sid,
+            mid: media.mid.clone(),
+            cid: String::new(),
+            media_type: media.media_type.clone(),
+        }
+    }
+
+    /// Song and artist, or the station for streams without track info
+    pub fn title(&self) -> String {
+        match (self.song.is_empty(), self.artist.is_empty()) {
+            (true, _) => self.station.clone(),
+            (false, true) => self.song.clone(),
+            (false, false) => format!("{} – {}", self.song, self.artist),
+        }
+    }
+
+    pub fn played_at(&self) -> Option<DateTime<Local>> {
+        DateTime::from_timestamp(self.played_at, 0).map(|t| t.with_timezone(&Local))
+    }
+
+    pub fn is_station(&self) -> bool {
+        self.media_type == "station"
+    }
+}
+
+/// Recently played media, oldest first, kept one JSON object per line in
+/// `history.jsonl` under the XDG state directory
+#[derive(Debug, Default)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Load the history, skipping lines that don't parse; a missing file starts empty
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let mut entries: Vec<HistoryEntry> = std::fs::read_to_string(&path)?
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        if entries.len() > HISTORY_LIMIT {
+            entries.drain(..entries.len() - HISTORY_LIMIT);
+            let mut history = Self { entries };
+            history.rewrite()?;
+            return Ok(history);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Add an entry and append it to the file
+    pub fn record(&mut self, entry: HistoryEntry) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// The entry `idx` places from the newest
+    pub fn recent(&self, idx: usize) -> Option<&HistoryEntry> {
+        self.entries.iter().rev().nth(idx)
+    }
+
+    fn rewrite(&mut self) -> Result<()> {
+        let mut text = String::new();
+        for entry in &self.entries {
+            text.push_str(&serde_json::to_string(entry)?);
+            text.push('\n');
+        }
+        std::fs::write(Self::path(), text)?;
+        Ok(())
+    }
+
+    pub fn path() -> PathBuf {
+        dirs::state_dir()
+            .or_else(dirs::data_local_dir)
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("heos-tui")
+            .join("history.jsonl")
+    }
+}
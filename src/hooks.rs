@@ -0,0 +1,67 @@
+use crate::app::App;
+use crate::config::HookConfig;
+use crate::watch::StateEvent;
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Runs user-configured shell commands for state events
+pub struct Hooks {
+    hooks: Vec<HookConfig>,
+}
+
+impl Hooks {
+    pub fn new(hooks: Vec<HookConfig>) -> Self {
+        Self { hooks }
+    }
+
+    /// Start the matching hooks in the background; failures are ignored
+    pub fn run(&self, event: &StateEvent, app: &App) {
+        let fields = event.fields(app);
+        for hook in self.hooks.iter().filter(|h| matches(h, event)) {
+            let mut command = shell(&hook.command);
+            if let Some(fields) = fields.as_object() {
+                for (key, value) in fields {
+                    let name = format!("HEOS_{}", key.to_uppercase());
+                    command.env(name, value.as_str().unwrap_or_default());
+                }
+            }
+            let child = command
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn();
+            if let Ok(mut child) = child {
+                // Reap the process so it doesn't linger as a zombie
+                tokio::spawn(async move {
+                    let _ = child.wait().await;
+                });
+            }
+        }
+    }
+}
+
+fn matches(hook: &HookConfig, event: &StateEvent) -> bool {
+    if hook.event != event.name() {
+        return false;
+    }
+    match (event, hook.threshold) {
+        (StateEvent::VolumeChanged { from, to }, Some(threshold)) => {
+            *from < threshold && *to >= threshold
+        }
+        _ => true,
+    }
+}
+
+fn shell(command: &str) -> Command {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command);
+    cmd
+}
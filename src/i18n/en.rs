@@ -0,0 +1,391 @@
+use super::Msg;
+
+pub fn text(msg: Msg) -> Option<&'static str> {
+    let text = match msg {
+        // Main view
+        Msg::NoPlayer => "No Player",
+        Msg::NowPlayingTitle => "Now Playing",
+        Msg::NoMediaPlaying => "No media playing",
+        Msg::Artist => "Artist",
+        Msg::Album => "Album",
+        Msg::Station => "Station",
+        Msg::VolumeTitle => "Volume",
+        Msg::Muted => "MUTED",
+        Msg::ViaSource => "via {}",
+        Msg::SeekUnsupported => "HEOS devices don't support seeking",
+        Msg::NowPlayingToast => "♪ Now playing: {}",
+        Msg::PlaybackFailed => "⚠ {} couldn't play: {}",
+        Msg::PlaybackFailedRetry => "⚠ {} couldn't play: {} (Ctrl+r retries)",
+        Msg::PlaybackRetried => "Retrying playback",
+        Msg::PlayerMuted => "Muted {}",
+        Msg::GroupNeedsTwo => "Mark at least two players with Space to group them",
+        Msg::GroupCreated => "Grouping {} players",
+        Msg::GroupDissolved => "Ungrouping {}",
+        Msg::PlayerNotGrouped => "This player is not in a group",
+        Msg::PlaybackMoved => "Moved the music to {}",
+        Msg::PlaybackPartlyMoved => "Moved the music to {}; {} track(s) couldn't be moved",
+        Msg::NothingToMove => "Nothing on this player can be moved",
+        Msg::TrackNotMovable => "The playing track can't be moved",
+        Msg::NoPlayerConnected => "No player connected",
+        Msg::PlayerUnmuted => "Unmuted {}",
+        Msg::DevicesInstructions => {
+            "↑/↓ Navigate  Enter Select  Space Mark  J Group  X Ungroup  H Move music here  +/- Volume  m Mute  I Info  Esc Cancel"
+        }
+        Msg::Surround => "Surround",
+        Msg::Sound => "Sound",
+        Msg::Input => "Input",
+        Msg::AvrVolume => "AVR Vol",
+        Msg::SignalAudio => "Audio",
+        Msg::SignalVideo => "Video",
+        Msg::Devices => "Devices",
+        Msg::Queue => "Queue",
+        Msg::Help => "Help",
+        Msg::StatusHint => "Press ? for help",
+
+        // Queue view
+        Msg::QueueHeader => "Queue ({} items)",
+        Msg::QueueHeaderDurations => "Queue ({} items · {} total / {} remaining)",
+        Msg::QueueInstructions => {
+            "↑/↓ Navigate  0-9 Jump  gn Playing  Enter Play  Del Remove  u Undo  e Export  E Import URLs  Esc Back  c Clear queue"
+        }
+
+        // Browse view
+        Msg::MusicSources => "Music Sources",
+        Msg::SourcesTitle => "Sources",
+        Msg::BrowseTitle => "Browse",
+        Msg::BrowseInstructions => {
+            "↑/↓ Navigate  0-9 Jump  Enter Select/Play  / Search  A Play playlist  Q Queue  B Bookmark  Esc Back"
+        }
+        Msg::LocalPlaylists => "Local Playlists",
+        Msg::BrowseCount => "{} of {}",
+        Msg::ThumbsHint => "t 👍  D 👎",
+        Msg::ThumbsUpSent => "👍 Thumbs up",
+        Msg::ThumbsDownSent => "👎 Thumbs down",
+        Msg::ThumbsUnavailable => "Thumbs up/down only work on Pandora and iHeartRadio stations",
+        Msg::QuickSelectsTitle => "Quick Select",
+        Msg::QuickSelectsInstructions => "↑/↓ Navigate  Enter Play  s Save current  Esc Cancel",
+        Msg::NoQuickSelects => "This player has no quick selects",
+        Msg::QuickSelectPlaying => "Quick select: {}",
+        Msg::QuickSelectSaved => "Saved to quick select {}",
+        Msg::FavoritesTitle => "Favorites",
+        Msg::FavoritesInstructions => "↑/↓ Navigate  Enter Play  Esc Cancel",
+        Msg::NoFavorites => "No HEOS favorites (sign in with K to load them)",
+        Msg::PlayingFavorite => "Playing favorite: {}",
+        Msg::PlayingFavoriteNumber => "Playing favorite {}",
+        Msg::SearchCriteriaTitle => "Search by",
+        Msg::SearchCriteriaInstructions => "↑/↓ Navigate  Enter Choose  Esc Cancel",
+        Msg::SearchLoading => "Loading search options...",
+        Msg::SearchUnavailable => "This source can't be searched",
+        Msg::SearchNoResults => "No results",
+
+        // Popups
+        Msg::SelectDeviceTitle => "Select Device",
+        Msg::RoleLeader => "leader",
+        Msg::RoleMember => "member",
+        Msg::RoleStereoPair => "stereo pair",
+        Msg::RolePairSecondary => "paired with {}, control that one",
+        Msg::NetworkDevicesTitle => "Network Devices",
+        Msg::SelectInputTitle => "Select Input",
+        Msg::NoInputs => "No inputs found on the network",
+        Msg::PlayingInput => "Playing input {}",
+        Msg::AvrInputsTitle => "AVR Input",
+        Msg::NoAvrInputs => "The AVR hasn't listed its inputs",
+        Msg::AvrInputSet => "AVR input: {}",
+        Msg::HdmiMonitorTitle => "HDMI Monitor Out [{}]",
+        Msg::HdmiMonitorSet => "HDMI monitor out: {}",
+        Msg::SurroundModeTitle => "Surround Mode [{}]",
+        Msg::SoundSettingsTitle => "Sound Settings",
+        Msg::BookmarksTitle => "Bookmarks",
+        Msg::BookmarksInstructions => "↑/↓ Navigate  Enter Open  Del Remove  Esc Cancel",
+        Msg::NoBookmarks => "No bookmarks yet - press B while browsing",
+        Msg::PluginActionsTitle => "Plugin Actions",
+        Msg::NoPluginActions => "No plugin actions - plugins go in the config dir's plugins/",
+        Msg::DetailsTitle => "Now Playing Details",
+        Msg::StatsTitle => "Listening Stats",
+        Msg::HistoryTitle => "Recently Played",
+        Msg::HistoryInstructions => "↑/↓ Navigate  Enter Play again  Esc Close",
+        Msg::NoHistory => "Nothing played yet",
+        Msg::HistoryNotPlayable => "{} can't be played again",
+        Msg::StatsSession => "Session",
+        Msg::StatsAllTime => "All time",
+        Msg::StatsSummary => "{} tracks, {} listened",
+        Msg::StatsTopArtists => "Top Artists",
+        Msg::StatsTopStations => "Top Stations",
+        Msg::StatsEmpty => "Nothing played yet",
+        Msg::StatsInstructions => "Esc Close",
+        Msg::DetailsSong => "Song",
+        Msg::DetailsType => "Type",
+        Msg::DetailsSource => "Source",
+        Msg::DetailsQuality => "Quality",
+        Msg::DetailsQid => "Queue ID",
+        Msg::DetailsMid => "Media ID",
+        Msg::DetailsImage => "Image",
+        Msg::DetailsPlayer => "Player",
+        Msg::PlayerInfoTitle => "Player Info",
+        Msg::PlayerInfoName => "Name",
+        Msg::PlayerInfoModel => "Model",
+        Msg::PlayerInfoVersion => "Firmware",
+        Msg::PlayerInfoIp => "IP",
+        Msg::PlayerInfoNetwork => "Network",
+        Msg::PlayerInfoSerial => "Serial",
+        Msg::PlayerInfoLineout => "Line out",
+        Msg::PlayerInfoPid => "Player ID",
+        Msg::NetworkWired => "Wired",
+        Msg::NetworkWifi => "Wi-Fi",
+        Msg::LineoutVariable => "Variable",
+        Msg::LineoutFixed => "Fixed",
+        Msg::PromptCommand => "Command (play, pause, volume 20, mute on, player <name>, url <url>)",
+        Msg::PromptExportQueue => "Export queue to (.m3u or .json)",
+        Msg::PromptImportUrls => "Play stream URLs from file (.m3u or .txt)",
+        Msg::PromptPlayUrl => "Stream URL to play",
+        Msg::PlayingUrl => "Playing {}",
+        Msg::InvalidStreamUrl => "'{}' is not an http:// or https:// URL",
+        Msg::PromptSearch => "Search",
+        Msg::PromptVolume => "Volume (0-100)",
+        Msg::VolumeSet => "Volume set to {}",
+        Msg::VolumeCapped => "Volume capped at {} (max_volume)",
+        Msg::InvalidVolume => "Invalid volume '{}', expected 0-100",
+        Msg::PromptSignInUser => "HEOS account e-mail",
+        Msg::PromptSignInPassword => "HEOS account password",
+        Msg::SignedIn => "Signed in as {}",
+        Msg::SignedOut => "Signed out of HEOS account",
+        Msg::PromptInstructions => "Enter Confirm  Ctrl+U Clear  Esc Cancel",
+        Msg::PopupInstructions => "↑/↓ Navigate  Enter Select  Esc Cancel",
+        Msg::SoundSettingsInstructions => "↑/↓ Navigate  Enter Apply  Esc Cancel",
+        Msg::Unknown => "Unknown",
+
+        // Sound settings
+        Msg::Bass => "Bass",
+        Msg::Treble => "Treble",
+        Msg::SubwooferUp => "Subwoofer +",
+        Msg::SubwooferDown => "Subwoofer -",
+        Msg::LfeUp => "LFE +",
+        Msg::LfeDown => "LFE -",
+        Msg::BassSyncUp => "Bass Sync +",
+        Msg::BassSyncDown => "Bass Sync -",
+        Msg::LoudnessManagement => "Loudness Management",
+        Msg::CinemaEq => "Cinema EQ",
+        Msg::Lfc => "Audyssey LFC",
+        Msg::LfcAmountUp => "LFC Containment +",
+        Msg::LfcAmountDown => "LFC Containment -",
+        Msg::DynamicCompression => "Dynamic Compression",
+        Msg::Imax => "IMAX Enhanced",
+        Msg::Audyssey => "Audyssey",
+        Msg::DialogEnhancer => "Dialog Enhancer",
+        Msg::BassDesc => "Set the bass level, -6 to +6 dB",
+        Msg::TrebleDesc => "Set the treble level, -6 to +6 dB",
+        Msg::ToneSliderInstructions => "←/→ Adjust  Enter Apply  Esc Cancel",
+        Msg::ToneSet => "{} set to {}",
+        Msg::SubwooferUpDesc => "Increase subwoofer level",
+        Msg::SubwooferDownDesc => "Decrease subwoofer level",
+        Msg::LfeUpDesc => "Raise the LFE channel level, up to 0 dB",
+        Msg::LfeDownDesc => "Lower the LFE channel level, down to -10 dB",
+        Msg::BassSyncUpDesc => "Match more front speaker bass to the subwoofer",
+        Msg::BassSyncDownDesc => "Match less front speaker bass to the subwoofer",
+        Msg::LoudnessManagementDesc => "Toggle Dolby loudness management",
+        Msg::CinemaEqDesc => "Toggle Cinema EQ, which softens harsh movie soundtracks",
+        Msg::LfcDesc => "Toggle Low Frequency Containment to keep bass from carrying through walls",
+        Msg::LfcAmountUpDesc => "Contain more low frequencies",
+        Msg::LfcAmountDownDesc => "Contain fewer low frequencies",
+        Msg::DynamicCompressionDesc => "Choose how much loud passages are compressed",
+        Msg::ImaxDesc => "Cycle IMAX Enhanced processing: Auto, On, Off",
+        Msg::Restorer => "Restorer",
+        Msg::RestorerDesc => "Cycle the compressed audio enhancer: Off, Low, Medium, High",
+        Msg::Eco => "ECO Mode",
+        Msg::EcoDesc => "Cycle power saving: On, Auto, Off (Off gives the amplifier full power)",
+        Msg::DynamicCompressionTitle => "Dynamic Compression [{}]",
+        Msg::DynamicCompressionSet => "Dynamic compression: {}",
+        Msg::SleepTimer => "Sleep Timer",
+        Msg::SleepTimerDesc => "Put the receiver in standby after a while",
+        Msg::SleepTimerTitle => "Sleep Timer [{}]",
+        Msg::SleepTimerMinutes => "{} min",
+        Msg::SleepTimerSet => "Sleep timer: {}",
+        Msg::SurroundParametersTitle => "DSP Parameters",
+        Msg::UpmixerTitle => "Upmixer",
+        Msg::GroupMixerTitle => "Group Volume",
+        Msg::GroupsTitle => "Groups",
+        Msg::NoGroups => "No groups - group players in the device list (d)",
+        Msg::GroupSelected => "Controlling group {}",
+        Msg::GroupMixerTitleGroup => "Group Volume: {}",
+        Msg::GroupMixerNoGroup => "This player is not in a group",
+        Msg::GroupMixerInstructions => "←/→ Select  ↑/↓ Volume  Esc Close",
+        Msg::AvrPresetsTitle => "AVR Presets",
+        Msg::NoAvrPresets => "No presets - add [[avr_presets]] to config.toml",
+        Msg::AvrPresetApplied => "Applied preset: {}",
+        Msg::AvrQuickSelectTitle => "AVR Quick Select",
+        Msg::AvrQuickSelectName => "Quick Select {}",
+        Msg::AvrQuickSelectInstructions => "↑/↓ Navigate  Enter/1-5 Recall  s Save current  Esc Cancel",
+        Msg::AvrQuickSelectRecalled => "Recalled quick select {}",
+        Msg::AvrQuickSelectSaved => "Saved AVR settings to quick select {}",
+        Msg::SurroundParametersNone => "No adjustable parameters for this mode",
+        Msg::SettingOn => "On",
+        Msg::SettingOff => "Off",
+        Msg::AudysseyDesc => "MultEQ, Dynamic EQ, reference level and Dynamic Volume",
+        Msg::AudysseyTitle => "Audyssey",
+        Msg::AudysseyInstructions => "↑/↓ Navigate  ←/→ Enter Change  Esc Back",
+        Msg::MultEq => "MultEQ",
+        Msg::MultEqDesc => "Room correction curve",
+        Msg::DynamicEq => "Dynamic EQ",
+        Msg::DynamicEqDesc => "Keep bass and surround balanced at low volume",
+        Msg::ReferenceLevel => "Reference Level Offset",
+        Msg::ReferenceLevelDesc => "Less Dynamic EQ boost for content mixed louder (Dynamic EQ only)",
+        Msg::DynamicVolume => "Dynamic Volume",
+        Msg::DynamicVolumeDesc => "Even out loud and quiet passages",
+        Msg::DynamicVolumeTitle => "Dynamic Volume [{}]",
+        Msg::DynamicVolumeSet => "Dynamic Volume: {}",
+        Msg::DialogEnhancerDesc => "Enhance dialog clarity",
+
+        // Help
+        Msg::HelpTitle => "Help",
+        Msg::HelpPlayback => "Playback Controls",
+        Msg::HelpVolume => "Volume & Audio",
+        Msg::HelpAvr => "AVR Controls",
+        Msg::HelpNavigation => "Navigation",
+        Msg::HelpLists => "List Navigation",
+        Msg::HelpPlayPause => "Play / Pause",
+        Msg::HelpStop => "Stop",
+        Msg::HelpNextTrack => "Next track",
+        Msg::HelpPrevTrack => "Previous track",
+        Msg::HelpMediaKeys => "Keyboard media keys (if the terminal reports them)",
+        Msg::HelpVolumeUp => "Volume up",
+        Msg::HelpVolumeDown => "Volume down",
+        Msg::HelpSetVolume => "Set volume to a typed level",
+        Msg::HelpAvrVolumeUp => "AVR volume up (0.5 step)",
+        Msg::HelpAvrVolumeDown => "AVR volume down (0.5 step)",
+        Msg::HelpCycleZone => "Cycle AVR zone for [ / ] (Main → Z2 → Z3, as the receiver has them)",
+        Msg::HelpToggleMute => "Toggle mute",
+        Msg::HelpCycleRepeat => "Cycle repeat (off → all → one)",
+        Msg::HelpToggleShuffle => "Toggle shuffle",
+        Msg::HelpToggleVolumeUnit => "Toggle volume display (raw / dB)",
+        Msg::HelpFollowPlayback => "Follow playback across players",
+        Msg::HelpSurroundSelector => "Surround mode selector",
+        Msg::HelpSoundSettings => "Sound settings (bass, treble, etc.)",
+        Msg::HelpUpmixers => "Upmixer (Dolby Surround, Neural:X, Auro-3D)",
+        Msg::HelpAvrSignalInfo => "Show audio/video signal info in the AVR panel",
+        Msg::HelpAvrPresets => "AVR scene presets",
+        Msg::HelpAvrPower => "AVR power on / standby",
+        Msg::HelpAvrQuickSelect => "AVR quick select memories (recall or save)",
+        Msg::HelpDeviceSelector => "Device selector",
+        Msg::HelpGroupMixer => "Group volume mixer",
+        Msg::HelpGroups => "Groups",
+        Msg::HelpGroupPlayers => "Devices: mark players, group / ungroup",
+        Msg::HelpTransferPlayback => "Devices: move the music to the highlighted player",
+        Msg::HelpQueueView => "Queue view",
+        Msg::HelpBrowse => "Browse music sources",
+        Msg::HelpInputSelector => "HEOS input selector",
+        Msg::HelpAvrInputs => "AVR input selector (names as set on the receiver)",
+        Msg::HelpHdmiMonitor => "HDMI monitor output",
+        Msg::HelpBookmarks => "Browse bookmarks",
+        Msg::HelpAddBookmark => "Bookmark current browse location",
+        Msg::HelpOpenLink => "Show / open artwork link in browser",
+        Msg::HelpNowPlaying => "Now playing details",
+        Msg::HelpJumpTopBottom => "Jump to top / bottom of list",
+        Msg::HelpJumpNowPlaying => "Jump to the playing track (in Queue)",
+        Msg::NowPlayingNotInQueue => "The playing track isn't in the queue",
+        Msg::HelpOpenBookmarkNumber => "Open bookmark 1-9",
+        Msg::HelpStats => "Listening stats",
+        Msg::HelpHistory => "Recently played tracks and stations",
+        Msg::HelpPluginActions => "Plugin actions for the current track",
+        Msg::HelpCommandMode => "Command prompt (same commands as --pipe)",
+        Msg::HelpSignIn => "Sign in to a HEOS account",
+        Msg::HelpSearch => "Search the music service (in Browse)",
+        Msg::HelpServiceOptions => "Service options like add to favorites (in Browse)",
+        Msg::HelpFavorites => "HEOS Favorites",
+        Msg::HelpQuickSelects => "Quick selects of the player",
+        Msg::HelpPlayerInfo => "Devices: model, firmware and network of a player",
+        Msg::HelpThumbs => "Thumbs up / down (Pandora, iHeartRadio)",
+        Msg::HelpRetryPlayback => "Retry what failed to play",
+        Msg::HelpPlayFavorite => "Play favorite 1-9",
+        Msg::HelpExportQueue => "Export queue to M3U / JSON (Queue)",
+        Msg::HelpImportUrls => "Play a list of stream URLs (Queue, n skips)",
+        Msg::HelpPlayUrl => "Play a stream URL",
+        Msg::HelpPlayPlaylist => "Play highlighted playlist or album (Browse)",
+        Msg::HelpQueuePlaylist => "Add highlighted playlist or album to the queue (Browse)",
+        Msg::HelpShowHelp => "Show this help",
+        Msg::HelpBack => "Go back / Close popup",
+        Msg::HelpRefresh => "Refresh status (reload the list in Browse)",
+        Msg::HelpQuit => "Quit",
+        Msg::HelpMoveUp => "Move up",
+        Msg::HelpMoveDown => "Move down",
+        Msg::HelpSelect => "Select / Apply",
+        Msg::HelpJump => "Jump to item number (Queue / Browse)",
+
+        // Status messages
+        Msg::Connected => "Connected to HEOS device",
+        Msg::Disconnected => "Disconnected from HEOS device",
+        Msg::Reconnecting => "Connection lost - reconnecting in {}s (attempt {})",
+        Msg::Reconnected => "Reconnected to {}",
+        Msg::AvrConnected => "AVR control connected",
+        Msg::AvrHttpFallback => "AVR telnet port busy, controlling it over HTTP",
+        Msg::AvrReconnecting => "AVR connection lost - reconnecting in {}s (attempt {})",
+        Msg::Error => "Error: {}",
+        Msg::AvrError => "AVR Error: {}",
+        Msg::PlayerStateError => "Error getting player state: {}",
+        Msg::ConnectionFailed => "Connection failed: {}",
+        Msg::AvrConnectionFailed => "AVR connection failed: {}",
+        Msg::NoDeviceFound => "No HEOS device found",
+        Msg::DiscoveryTitle => "Discovering",
+        Msg::DiscoverySearching => "Searching for HEOS devices… {}  (round {}/{})",
+        Msg::DiscoverySearchTargets => "Search targets:",
+        Msg::DiscoveryResponders => "Responders:",
+        Msg::DiscoveryNoResponders => "none yet",
+        Msg::DiscoveryCancelHint => "Esc: cancel discovery",
+        Msg::DiscoveryCancelled => "Discovery cancelled",
+        Msg::SurroundModeSet => "Surround mode: {}",
+        Msg::DialogEnhancerAdjusted => "Dialog enhancer adjusted",
+        Msg::Applied => "Applied: {}",
+        Msg::VolumeStatus => "Volume: {}",
+        Msg::AvrVolumeStatus => "AVR Vol: {}",
+        Msg::AvrVolumeMax => "max {}",
+        Msg::AvrPowerOn => "AVR powering on",
+        Msg::AvrStandby => "AVR going to standby",
+        Msg::AvrPowerStandby => "Standby",
+        Msg::VolumeUnitDb => "Volume display: dB",
+        Msg::FollowPlaybackOn => "Follow playback: on",
+        Msg::FollowPlaybackOff => "Follow playback: off",
+        Msg::FollowingPlayback => "Following playback to {}",
+        Msg::FollowIndicator => "follow",
+        Msg::VolumeUnitRaw => "Volume display: raw",
+        Msg::ZoneSelected => "AVR zone: {}",
+        Msg::JumpTo => "Go to item: {}",
+        Msg::BookmarkAdded => "Bookmarked: {}",
+        Msg::BookmarkExists => "Already bookmarked: {}",
+        Msg::BookmarkRemoved => "Removed bookmark: {}",
+        Msg::NoBookmarkNumber => "No bookmark {}",
+        Msg::QueueItemRemoved => "Removed from queue: {} (u to undo)",
+        Msg::QueueRestored => "Re-added {} item(s) to the queue",
+        Msg::QueuePartlyRestored => "Re-added {} item(s) to the queue; {} couldn't be added back",
+        Msg::QueueRestoreFailed => "Couldn't re-add the removed items (u to try again)",
+        Msg::QueueNothingToUndo => "Nothing to undo",
+        Msg::BookmarkNothing => "Open a source or folder to bookmark it",
+        Msg::LinkConfirm => "{}  (press L again to open)",
+        Msg::LinkOpened => "Opened {}",
+        Msg::NoLink => "No link for this item",
+        Msg::QueueExported => "Exported {} items to {}",
+        Msg::QueueEmpty => "Queue is empty",
+        Msg::QueueCleared => "Cleared {} item(s) from the queue (u to undo)",
+        Msg::QueueClearedNoUndo => "Cleared the queue; it was too long to undo",
+        Msg::ConfirmTitle => "Confirm",
+        Msg::ConfirmInstructions => "y Yes  n No",
+        Msg::ConfirmClearQueue => "Clear the whole queue?",
+        Msg::ConfirmAvrStandby => "Music is playing - put the AVR in standby?",
+        Msg::UrlsImported => "Playing {} stream URL(s) in order",
+        Msg::NoUrls => "No stream URLs found in {}",
+        Msg::NextStream => "Next stream ({} left)",
+        Msg::PlaylistPlaying => "Playing {} playlist entries in order",
+        Msg::ContainerPlaying => "Playing {}",
+        Msg::ContainerQueued => "Added {} to the queue",
+        Msg::QueuedNext => "Playing {} next",
+        Msg::BrowseMenuInstructions => "↑/↓ Navigate  Enter Choose  Esc Cancel",
+        Msg::ServiceOptionsInstructions => "↑/↓ Navigate  Enter Run  Esc Cancel",
+        Msg::NoServiceOptions => "The service offers no options here",
+        Msg::ServiceOptionDone => "{}: done",
+        Msg::BrowseActionOpen => "Open",
+        Msg::BrowseActionPlayNow => "Play now",
+        Msg::BrowseActionPlayNext => "Play next",
+        Msg::BrowseActionAddToEnd => "Add to end of queue",
+        Msg::BrowseActionReplaceAndPlay => "Replace queue and play",
+        Msg::BrowseActionPlayFromHere => "Play playlist from here",
+    };
+    Some(text)
+}
@@ -0,0 +1,430 @@
+mod en;
+
+use std::fmt::Display;
+
+/// Keys for every user-visible string in the UI
+///
+/// To add a translation, create a module next to `en.rs` with the same
+/// `text` signature and add its locale code to [`Messages::for_locale`].
+/// Keys a catalog doesn't cover fall back to English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    // Main view
+    NoPlayer,
+    NowPlayingTitle,
+    NoMediaPlaying,
+    Artist,
+    Album,
+    Station,
+    VolumeTitle,
+    Muted,
+    ViaSource,
+    SeekUnsupported,
+    NowPlayingToast,
+    PlaybackFailed,
+    PlaybackFailedRetry,
+    PlaybackRetried,
+    PlayerMuted,
+    GroupNeedsTwo,
+    GroupCreated,
+    GroupDissolved,
+    PlayerNotGrouped,
+    PlaybackMoved,
+    PlaybackPartlyMoved,
+    NothingToMove,
+    TrackNotMovable,
+    NoPlayerConnected,
+    PlayerUnmuted,
+    DevicesInstructions,
+    Surround,
+    Sound,
+    Input,
+    AvrVolume,
+    SignalAudio,
+    SignalVideo,
+    Devices,
+    Queue,
+    Help,
+    StatusHint,
+
+    // Queue view
+    QueueHeader,
+    QueueHeaderDurations,
+    QueueInstructions,
+
+    // Browse view
+    MusicSources,
+    SourcesTitle,
+    BrowseTitle,
+    BrowseInstructions,
+    LocalPlaylists,
+    BrowseCount,
+    ThumbsHint,
+    ThumbsUpSent,
+    ThumbsDownSent,
+    ThumbsUnavailable,
+    QuickSelectsTitle,
+    QuickSelectsInstructions,
+    NoQuickSelects,
+    QuickSelectPlaying,
+    QuickSelectSaved,
+    FavoritesTitle,
+    FavoritesInstructions,
+    NoFavorites,
+    PlayingFavorite,
+    PlayingFavoriteNumber,
+    SearchCriteriaTitle,
+    SearchCriteriaInstructions,
+    SearchLoading,
+    SearchUnavailable,
+    SearchNoResults,
+
+    // Popups
+    SelectDeviceTitle,
+    RoleLeader,
+    RoleMember,
+    RoleStereoPair,
+    RolePairSecondary,
+    NetworkDevicesTitle,
+    SelectInputTitle,
+    NoInputs,
+    PlayingInput,
+    AvrInputsTitle,
+    NoAvrInputs,
+    AvrInputSet,
+    HdmiMonitorTitle,
+    HdmiMonitorSet,
+    SurroundModeTitle,
+    SoundSettingsTitle,
+    BookmarksTitle,
+    BookmarksInstructions,
+    NoBookmarks,
+    PluginActionsTitle,
+    NoPluginActions,
+    DetailsTitle,
+    StatsTitle,
+    HistoryTitle,
+    HistoryInstructions,
+    NoHistory,
+    HistoryNotPlayable,
+    StatsSession,
+    StatsAllTime,
+    StatsSummary,
+    StatsTopArtists,
+    StatsTopStations,
+    StatsEmpty,
+    StatsInstructions,
+    DetailsSong,
+    DetailsType,
+    DetailsSource,
+    DetailsQuality,
+    DetailsQid,
+    DetailsMid,
+    DetailsImage,
+    DetailsPlayer,
+    PlayerInfoTitle,
+    PlayerInfoName,
+    PlayerInfoModel,
+    PlayerInfoVersion,
+    PlayerInfoIp,
+    PlayerInfoNetwork,
+    PlayerInfoSerial,
+    PlayerInfoLineout,
+    PlayerInfoPid,
+    NetworkWired,
+    NetworkWifi,
+    LineoutVariable,
+    LineoutFixed,
+    PromptCommand,
+    PromptExportQueue,
+    PromptImportUrls,
+    PromptPlayUrl,
+    PlayingUrl,
+    InvalidStreamUrl,
+    PromptSearch,
+    PromptVolume,
+    VolumeSet,
+    VolumeCapped,
+    InvalidVolume,
+    PromptSignInUser,
+    PromptSignInPassword,
+    SignedIn,
+    SignedOut,
+    PromptInstructions,
+    PopupInstructions,
+    SoundSettingsInstructions,
+    Unknown,
+
+    // Sound settings
+    Bass,
+    Treble,
+    SubwooferUp,
+    SubwooferDown,
+    LfeUp,
+    LfeDown,
+    BassSyncUp,
+    BassSyncDown,
+    LoudnessManagement,
+    CinemaEq,
+    Lfc,
+    LfcAmountUp,
+    LfcAmountDown,
+    DynamicCompression,
+    Imax,
+    Audyssey,
+    DialogEnhancer,
+    BassDesc,
+    TrebleDesc,
+    ToneSliderInstructions,
+    ToneSet,
+    SubwooferUpDesc,
+    SubwooferDownDesc,
+    LfeUpDesc,
+    LfeDownDesc,
+    BassSyncUpDesc,
+    BassSyncDownDesc,
+    LoudnessManagementDesc,
+    CinemaEqDesc,
+    LfcDesc,
+    LfcAmountUpDesc,
+    LfcAmountDownDesc,
+    DynamicCompressionDesc,
+    ImaxDesc,
+    Restorer,
+    RestorerDesc,
+    Eco,
+    EcoDesc,
+    DynamicCompressionTitle,
+    DynamicCompressionSet,
+    SleepTimer,
+    SleepTimerDesc,
+    SleepTimerTitle,
+    SleepTimerMinutes,
+    SleepTimerSet,
+    SurroundParametersTitle,
+    UpmixerTitle,
+    GroupMixerTitle,
+    GroupsTitle,
+    NoGroups,
+    GroupSelected,
+    GroupMixerTitleGroup,
+    GroupMixerNoGroup,
+    GroupMixerInstructions,
+    AvrPresetsTitle,
+    NoAvrPresets,
+    AvrPresetApplied,
+    AvrQuickSelectTitle,
+    AvrQuickSelectName,
+    AvrQuickSelectInstructions,
+    AvrQuickSelectRecalled,
+    AvrQuickSelectSaved,
+    SurroundParametersNone,
+    SettingOn,
+    SettingOff,
+    AudysseyDesc,
+    AudysseyTitle,
+    AudysseyInstructions,
+    MultEq,
+    MultEqDesc,
+    DynamicEq,
+    DynamicEqDesc,
+    ReferenceLevel,
+    ReferenceLevelDesc,
+    DynamicVolume,
+    DynamicVolumeDesc,
+    DynamicVolumeTitle,
+    DynamicVolumeSet,
+    DialogEnhancerDesc,
+
+    // Help
+    HelpTitle,
+    HelpPlayback,
+    HelpVolume,
+    HelpAvr,
+    HelpNavigation,
+    HelpLists,
+    HelpPlayPause,
+    HelpStop,
+    HelpNextTrack,
+    HelpPrevTrack,
+    HelpMediaKeys,
+    HelpVolumeUp,
+    HelpVolumeDown,
+    HelpSetVolume,
+    HelpAvrVolumeUp,
+    HelpAvrVolumeDown,
+    HelpCycleZone,
+    HelpToggleMute,
+    HelpCycleRepeat,
+    HelpToggleShuffle,
+    HelpToggleVolumeUnit,
+    HelpFollowPlayback,
+    HelpSurroundSelector,
+    HelpSoundSettings,
+    HelpUpmixers,
+    HelpAvrSignalInfo,
+    HelpAvrPresets,
+    HelpAvrPower,
+    HelpAvrQuickSelect,
+    HelpDeviceSelector,
+    HelpGroupMixer,
+    HelpGroups,
+    HelpGroupPlayers,
+    HelpTransferPlayback,
+    HelpQueueView,
+    HelpBrowse,
+    HelpInputSelector,
+    HelpAvrInputs,
+    HelpHdmiMonitor,
+    HelpBookmarks,
+    HelpAddBookmark,
+    HelpOpenLink,
+    HelpNowPlaying,
+    HelpJumpTopBottom,
+    HelpJumpNowPlaying,
+    NowPlayingNotInQueue,
+    HelpOpenBookmarkNumber,
+    HelpStats,
+    HelpHistory,
+    HelpPluginActions,
+    HelpCommandMode,
+    HelpSignIn,
+    HelpSearch,
+    HelpServiceOptions,
+    HelpFavorites,
+    HelpThumbs,
+    HelpRetryPlayback,
+    HelpQuickSelects,
+    HelpPlayerInfo,
+    HelpPlayFavorite,
+    HelpExportQueue,
+    HelpImportUrls,
+    HelpPlayUrl,
+    HelpPlayPlaylist,
+    HelpQueuePlaylist,
+    HelpShowHelp,
+    HelpBack,
+    HelpRefresh,
+    HelpQuit,
+    HelpMoveUp,
+    HelpMoveDown,
+    HelpSelect,
+    HelpJump,
+
+    // Status messages
+    Connected,
+    Disconnected,
+    Reconnecting,
+    Reconnected,
+    AvrConnected,
+    AvrHttpFallback,
+    AvrReconnecting,
+    Error,
+    AvrError,
+    PlayerStateError,
+    ConnectionFailed,
+    AvrConnectionFailed,
+    NoDeviceFound,
+    DiscoveryTitle,
+    DiscoverySearching,
+    DiscoverySearchTargets,
+    DiscoveryResponders,
+    DiscoveryNoResponders,
+    DiscoveryCancelHint,
+    DiscoveryCancelled,
+    SurroundModeSet,
+    DialogEnhancerAdjusted,
+    Applied,
+    VolumeStatus,
+    AvrVolumeStatus,
+    AvrVolumeMax,
+    AvrPowerOn,
+    AvrStandby,
+    AvrPowerStandby,
+    VolumeUnitDb,
+    FollowPlaybackOn,
+    FollowPlaybackOff,
+    FollowingPlayback,
+    FollowIndicator,
+    VolumeUnitRaw,
+    ZoneSelected,
+    JumpTo,
+    BookmarkAdded,
+    BookmarkExists,
+    BookmarkRemoved,
+    NoBookmarkNumber,
+    QueueItemRemoved,
+    QueueRestored,
+    QueuePartlyRestored,
+    QueueRestoreFailed,
+    QueueNothingToUndo,
+    BookmarkNothing,
+    LinkConfirm,
+    LinkOpened,
+    NoLink,
+    QueueExported,
+    QueueEmpty,
+    QueueCleared,
+    QueueClearedNoUndo,
+    ConfirmTitle,
+    ConfirmInstructions,
+    ConfirmClearQueue,
+    ConfirmAvrStandby,
+    UrlsImported,
+    NoUrls,
+    NextStream,
+    PlaylistPlaying,
+    ContainerPlaying,
+    ContainerQueued,
+    QueuedNext,
+    BrowseMenuInstructions,
+    ServiceOptionsInstructions,
+    NoServiceOptions,
+    ServiceOptionDone,
+    BrowseActionOpen,
+    BrowseActionPlayNow,
+    BrowseActionPlayNext,
+    BrowseActionAddToEnd,
+    BrowseActionReplaceAndPlay,
+    BrowseActionPlayFromHere,
+}
+
+/// Message catalog for the configured locale
+#[derive(Debug, Clone, Copy)]
+pub struct Messages {
+    lookup: fn(Msg) -> Option<&'static str>,
+}
+
+impl Messages {
+    pub fn for_locale(locale: &str) -> Self {
+        // Only the language part of codes like "en_US" or "en-GB" matters
+        let language = locale.split(['_', '-', '.']).next().unwrap_or_default();
+        let lookup: fn(Msg) -> Option<&'static str> = match language.to_lowercase().as_str() {
+            "en" => en::text,
+            _ => en::text,
+        };
+        Self { lookup }
+    }
+
+    pub fn get(&self, msg: Msg) -> &'static str {
+        (self.lookup)(msg)
+            .or_else(|| en::text(msg))
+            .unwrap_or_default()
+    }
+
+    /// Look up a message and substitute each `{}` with the next argument
+    pub fn format(&self, msg: Msg, args: &[&dyn Display]) -> String {
+        let mut out = String::new();
+        let mut args = args.iter();
+        let mut parts = self.get(msg).split("{}").peekable();
+        while let Some(part) = parts.next() {
+            out.push_str(part);
+            if parts.peek().is_some() {
+                if let Some(arg) = args.next() {
+                    out.push_str(&arg.to_string());
+                }
+            }
+        }
+        out
+    }
+}
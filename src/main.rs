@@ -1,34 +1,75 @@
 mod app;
+mod command;
 mod config;
 mod event;
 mod heos;
+mod history;
+mod hooks;
+mod i18n;
+mod mqtt;
+mod pipe;
+mod playlist;
+mod plugin;
+mod stats;
 mod ui;
+mod watch;
+mod webhook;
 
 use anyhow::{Context, Result};
-use app::{App, ConnectionState, View};
+use app::{
+    App, BrowseAction, BrowseLevel, Confirm, ConnectionState, Fade, Prompt, PromptKind, View,
+};
 use clap::Parser;
-use config::Config;
+use command::Command;
+use config::{AccountConfig, Config};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crossterm::{
+    event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
+};
+use event::{Action, AppEvent, ChordStep, EventHandler};
+use heos::{
+    discover_devices_with_progress, discover_with_known, merge_known, AvrClient, AvrEvent,
+    AvrHandle, DiscoveryProgress, DynamicCompression, DynamicVolume, HdmiMonitor, HeosClient,
+    HeosEvent, HeosHandle, MuteState, PlayState, QuickSelect, Tone, DISCOVERY_ROUNDS,
+    SLEEP_TIMER_MINUTES,
 };
-use event::{Action, AppEvent, EventHandler};
-use heos::{discover_first_device, AvrClient, AvrEvent, AvrHandle, HeosClient, HeosEvent, HeosHandle};
+use history::{History, HistoryEntry};
+use hooks::Hooks;
+use i18n::Msg;
+use mqtt::MqttBridge;
+use playlist::LOCAL_PLAYLISTS_SID;
+use plugin::PluginHost;
 use ratatui::prelude::*;
-use std::io::stdout;
+use stats::Stats;
+use std::io::{stdout, IsTerminal};
 use std::time::Duration;
 use tokio::sync::mpsc;
+use watch::{StateEvent, StateWatcher};
+use webhook::Webhooks;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Terminal UI for HEOS devices")]
 struct Args {
-    /// HEOS device IP address (skips discovery)
+    /// HEOS device IP address, IPv4 or IPv6 (skips discovery)
     #[arg(short = 'H', long)]
     host: Option<String>,
 
     /// Discovery timeout in seconds
     #[arg(short, long, default_value = "5")]
     timeout: u64,
+
+    /// Read commands from stdin instead of starting the UI (implied when stdin is not a TTY)
+    #[arg(long)]
+    pipe: bool,
+
+    /// Play an internet radio or HTTP stream URL on the default player and exit
+    #[arg(long, value_name = "URL")]
+    play_url: Option<String>,
 }
 
 #[tokio::main]
@@ -36,19 +77,64 @@ async fn main() -> Result<()> {
     let args = Args::parse();
     let config = Config::load().unwrap_or_default();
 
+    if let Some(url) = args.play_url {
+        let host = args.host.or(config.connection.host.clone());
+        return pipe::play_url(config, host, args.timeout, &url).await;
+    }
+
+    if args.pipe || !std::io::stdin().is_terminal() {
+        let host = args.host.or(config.connection.host.clone());
+        return pipe::run(config, host, args.timeout).await;
+    }
+
     // Create event channels
     let (heos_tx, mut heos_rx) = mpsc::channel::<HeosEvent>(100);
     let (avr_tx, mut avr_rx) = mpsc::channel::<AvrEvent>(100);
-    let (handle_tx, mut handle_rx) = mpsc::channel::<HeosHandle>(1);
-    let (avr_handle_tx, mut avr_handle_rx) = mpsc::channel::<AvrHandle>(1);
+    let (handle_tx, mut handle_rx) = mpsc::channel::<(HeosHandle, String)>(1);
+    let (avr_handle_tx, mut avr_handle_rx) = mpsc::channel::<(AvrHandle, String)>(1);
 
     // Create app
     let mut app = App::new(config.clone());
 
+    // Optional MQTT bridge; commands from its command topic arrive on `command_rx`
+    let (command_tx, mut command_rx) = mpsc::channel::<Command>(10);
+    let mut mqtt = config
+        .mqtt
+        .clone()
+        .map(|mqtt_config| MqttBridge::start(mqtt_config, command_tx.clone()));
+    // Start plugins before the UI so their browse roots are ready
+    let (plugins, plugin_errors) = PluginHost::load().await;
+    app.plugins = plugins;
+    if let Some(e) = plugin_errors.first() {
+        app.set_error(e);
+    }
+
+    match Stats::load() {
+        Ok(stats) => app.stats = stats,
+        Err(e) => app.set_error(e),
+    }
+    match History::load() {
+        Ok(history) => app.history = history,
+        Err(e) => app.set_error(e),
+    }
+
+    let webhooks = Webhooks::new(config.webhooks.clone());
+    let hooks = Hooks::new(config.hooks.clone());
+    let mut watcher = StateWatcher::default();
+
     // Setup terminal
     enable_raw_mode().context("Failed to enable raw mode")?;
     let mut stdout = stdout();
     execute!(stdout, EnterAlternateScreen).context("Failed to enter alternate screen")?;
+    // Media keys are only reported with the kitty keyboard protocol
+    let keyboard_enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhanced {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )
+        .context("Failed to enable keyboard enhancement")?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).context("Failed to create terminal")?;
 
@@ -65,42 +151,107 @@ async fn main() -> Result<()> {
     let avr_host = host.clone();
     let connect_tx = heos_tx.clone();
     let discovery_timeout = args.timeout;
+    let messages = app.messages;
+    let known_devices = config.known_devices();
+    let account = config.account.clone();
+    let reconnect_handle_tx = handle_tx.clone();
+    let mut reconnect_task: Option<tokio::task::JoinHandle<()>> = None;
+    let avr_reconnect_handle_tx = avr_handle_tx.clone();
+    let mut avr_reconnect_task: Option<tokio::task::JoinHandle<()>> = None;
+    let avr_known_devices = known_devices.clone();
+    let background_known_devices = known_devices.clone();
+    let background_tx = heos_tx.clone();
 
     // Spawn HEOS connection task
-    tokio::spawn(async move {
+    let connect_task = tokio::spawn(async move {
         let target_host = if let Some(h) = connect_host {
+            let _ = connect_tx
+                .send(HeosEvent::DevicesDiscovered(known_devices))
+                .await;
             Some(h)
         } else {
-            match discover_first_device(discovery_timeout).await {
-                Ok(Some(ip)) => Some(ip),
-                Ok(None) => None,
-                Err(_) => None,
+            // Retry a few rounds before giving up; known devices make retries pointless
+            let mut found = Vec::new();
+            for round in 1..=DISCOVERY_ROUNDS {
+                let _ = connect_tx
+                    .send(HeosEvent::Discovery(DiscoveryProgress::Round(round)))
+                    .await;
+                found = discover_devices_with_progress(discovery_timeout, |progress| {
+                    let _ = connect_tx.try_send(HeosEvent::Discovery(progress));
+                })
+                .await
+                .unwrap_or_default();
+                if !found.is_empty() || !known_devices.is_empty() {
+                    break;
+                }
             }
+            let devices = merge_known(found, &known_devices);
+            let first = devices.first().map(|d| d.ip.clone());
+            let _ = connect_tx.send(HeosEvent::DevicesDiscovered(devices)).await;
+            first
         };
 
         if let Some(host) = target_host {
             match HeosClient::connect(&host, connect_tx.clone()).await {
                 Ok(handle) => {
                     // Send handle back to main thread
-                    let _ = handle_tx.send(handle.clone()).await;
+                    let _ = handle_tx.send((handle.clone(), host)).await;
 
-                    // Register for events and get initial state
-                    let _ = handle.register_for_events().await;
-                    let _ = handle.get_players().await;
+                    start_session(&handle, &account).await;
                 }
                 Err(e) => {
-                    let _ = connect_tx
-                        .send(HeosEvent::Error(format!("Connection failed: {}", e)))
-                        .await;
+                    let msg = messages.format(Msg::ConnectionFailed, &[&e]);
+                    let _ = connect_tx.send(HeosEvent::Error(msg)).await;
                 }
             }
         } else {
             let _ = connect_tx
-                .send(HeosEvent::Error("No HEOS device found".to_string()))
+                .send(HeosEvent::Error(
+                    messages.get(Msg::NoDeviceFound).to_string(),
+                ))
                 .await;
         }
     });
 
+    app.discovery.task = Some(connect_task.abort_handle());
+
+    // Keep searching at a low rate so devices powered on later show up in Devices
+    let rediscover_interval = config.connection.rediscover_interval;
+    if rediscover_interval > 0 {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(rediscover_interval));
+            // The first tick fires immediately; startup discovery already covers it
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let devices = discover_with_known(3, &background_known_devices).await;
+                if background_tx
+                    .send(HeosEvent::DevicesDiscovered(devices))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+    }
+
+    // Catch players that come and go without a players_changed event
+    let players_refresh_interval = config.connection.players_refresh_interval;
+    if players_refresh_interval > 0 {
+        let refresh_tx = heos_tx.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(players_refresh_interval));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if refresh_tx.send(HeosEvent::PlayersChanged).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
     // Spawn AVR connection task (uses same host)
     let avr_connect_tx = avr_tx.clone();
 
@@ -112,26 +263,32 @@ async fn main() -> Result<()> {
             Some(h)
         } else {
             // Try discovery again for AVR
-            match discover_first_device(3).await {
-                Ok(Some(ip)) => Some(ip),
-                _ => None,
-            }
+            discover_with_known(3, &avr_known_devices)
+                .await
+                .into_iter()
+                .next()
+                .map(|d| d.ip)
         };
 
         if let Some(host) = target_host {
             match AvrClient::connect(&host, avr_connect_tx.clone()).await {
                 Ok(handle) => {
                     // Send handle back to main thread
-                    let _ = avr_handle_tx.send(handle.clone()).await;
+                    let _ = avr_handle_tx.send((handle.clone(), host)).await;
 
                     // Query initial status
                     let _ = handle.query_status().await;
                 }
-                Err(e) => {
-                    let _ = avr_connect_tx
-                        .send(AvrEvent::Error(format!("AVR connection failed: {}", e)))
-                        .await;
-                }
+                // The receiver takes one telnet client; another controller may hold it
+                Err(e) => match AvrClient::connect_http(&host, avr_connect_tx.clone()).await {
+                    Ok(handle) => {
+                        let _ = avr_handle_tx.send((handle, host)).await;
+                    }
+                    Err(_) => {
+                        let msg = messages.format(Msg::AvrConnectionFailed, &[&e]);
+                        let _ = avr_connect_tx.send(AvrEvent::Error(msg)).await;
+                    }
+                },
             }
         }
     });
@@ -146,12 +303,25 @@ async fn main() -> Result<()> {
             Some(app_event) = event_handler.next() => {
                 match app_event {
                     AppEvent::Key(key) => {
-                        if let Some(action) = Action::from_key(key) {
-                            handle_action(&mut app, action).await?;
+                        if app.prompt.is_some() {
+                            handle_prompt_key(&mut app, key).await?;
+                        } else if let Some(confirm) = app.confirm {
+                            handle_confirm_key(&mut app, confirm, key).await;
+                        } else {
+                            match app.chord.feed(key) {
+                                ChordStep::Action(action) => handle_action(&mut app, action).await?,
+                                ChordStep::Single => {
+                                    if let Some(action) = Action::from_key(key) {
+                                        handle_action(&mut app, action).await?;
+                                    }
+                                }
+                                ChordStep::Pending | ChordStep::Cancelled => {}
+                            }
                         }
                     }
                     AppEvent::Tick => {
-                        // Could clear old status messages here
+                        app.chord.expire();
+                        app.tick();
                     }
                     AppEvent::Resize(_, _) => {
                         // Terminal will redraw on next iteration
@@ -159,44 +329,247 @@ async fn main() -> Result<()> {
                 }
             }
             Some(heos_event) = heos_rx.recv() => {
-                // Check if this is a now_playing_changed event and refresh
-                let should_refresh_now_playing = matches!(
+                // Refresh the media of whichever player changed, for the device list too
+                let now_playing_changed = match &heos_event {
+                    HeosEvent::NowPlayingChanged { pid } => Some(*pid),
+                    _ => None,
+                };
+
+                // A stopped player moves on to the next imported stream URL
+                let should_play_next_stream = !app.stream_queue.is_empty()
+                    && matches!(
+                        &heos_event,
+                        HeosEvent::PlayerStateChanged { pid, state: PlayState::Stop }
+                            if app.current_pid() == Some(*pid)
+                    );
+
+                // Regrouping changes groups and each player's gid
+                let group_set = matches!(
+                    &heos_event,
+                    HeosEvent::Response(response)
+                        if response.heos.command.contains("set_group") && response.is_success()
+                );
+
+                // Fresh group layout: fetch the volumes for the mixer
+                let groups_changed = matches!(&heos_event, HeosEvent::GroupsChanged);
+                let players_changed = matches!(&heos_event, HeosEvent::PlayersChanged);
+                // Signing in or out changes which services are available
+                let sources_changed = matches!(
+                    &heos_event,
+                    HeosEvent::SourcesChanged | HeosEvent::UserChanged(_)
+                );
+                let should_refresh_group_volumes = matches!(
                     &heos_event,
-                    HeosEvent::NowPlayingChanged { pid } if app.current_pid() == Some(*pid)
+                    HeosEvent::Response(response)
+                        if response.heos.command.contains("get_groups")
+                            || response.heos.command.contains("get_group_info")
                 );
 
+                // Startup: load the selected player and look for one that's playing
+                let players_loaded = matches!(&heos_event, HeosEvent::Players(_))
+                    && app.current_player().is_none();
+                let transferred_to = match &heos_event {
+                    HeosEvent::PlaybackTransferred { to, .. } => Some(*to),
+                    _ => None,
+                };
+                let follow_to = match &heos_event {
+                    HeosEvent::PlayerStateChanged { pid, state } => app.follow_target(*pid, *state),
+                    _ => None,
+                };
+                let is_play_state = matches!(
+                    &heos_event,
+                    HeosEvent::Response(response) if response.heos.command.contains("get_play_state")
+                );
+                let disconnected = matches!(&heos_event, HeosEvent::Disconnected);
+                let previous_pid = app.current_pid();
+
                 app.handle_heos_event(heos_event);
 
-                // Auto-refresh now playing when it changes
-                if should_refresh_now_playing {
-                    if let Some(pid) = app.current_pid() {
-                        if let Some(handle) = app.get_handle() {
-                            let _ = handle.get_now_playing(pid).await;
+                // The selected player went away: load the one that took its place
+                if !players_loaded && previous_pid.is_some() && app.current_pid() != previous_pid {
+                    if let Err(e) = app.refresh_player_state().await {
+                        app.set_error(e);
+                    }
+                }
+
+                if players_loaded && app.auto_select_pending {
+                    if let Err(e) = app.refresh_player_state().await {
+                        app.set_error(e);
+                    }
+                    if let Err(e) = app.refresh_play_states().await {
+                        app.set_error(e);
+                    }
+                }
+                if let Some(to) = transferred_to {
+                    if let Err(e) = app.finish_transfer(to).await {
+                        app.set_error(e);
+                    }
+                }
+                if let Some(idx) = follow_to {
+                    match app.switch_player(idx).await {
+                        Ok(()) => {
+                            let name = app.current_player().map(|p| p.name.clone()).unwrap_or_default();
+                            app.show_toast(app.messages.format(Msg::FollowingPlayback, &[&name]));
+                        }
+                        Err(e) => app.set_error(e),
+                    }
+                }
+                if is_play_state {
+                    if let Some(idx) = app.take_auto_select() {
+                        if let Err(e) = app.switch_player(idx).await {
+                            app.set_error(e);
                         }
                     }
                 }
+
+                if groups_changed {
+                    let _ = app.refresh_groups().await;
+                    app.refresh_players();
+                }
+                if group_set {
+                    let _ = app.refresh_groups().await;
+                    app.refresh_players();
+                }
+                if players_changed {
+                    app.refresh_players();
+                }
+                if sources_changed {
+                    let _ = app.refresh_music_sources().await;
+                }
+                if should_refresh_group_volumes {
+                    if let Err(e) = app.refresh_group_volumes().await {
+                        app.set_error(e);
+                    }
+                }
+
+                if should_play_next_stream {
+                    play_next_stream(&mut app).await;
+                }
+
+                // Keep retrying the same device in the background until it's back
+                let reconnecting = reconnect_task.as_ref().is_some_and(|task| !task.is_finished());
+                if disconnected && !reconnecting {
+                    if let Some(host) = app.connected_host.clone() {
+                        let event_tx = heos_tx.clone();
+                        let handle_tx = reconnect_handle_tx.clone();
+                        let account = config.account.clone();
+                        reconnect_task = Some(tokio::spawn(async move {
+                            let handle = HeosClient::reconnect(&host, event_tx).await;
+                            let _ = handle_tx.send((handle.clone(), host)).await;
+                            start_session(&handle, &account).await;
+                        }));
+                    }
+                }
+
+                // Auto-refresh now playing when it changes
+                if let Some(pid) = now_playing_changed {
+                    if let Some(handle) = app.get_handle() {
+                        let _ = handle.get_now_playing(pid).await;
+                    }
+                }
             }
             Some(avr_event) = avr_rx.recv() => {
+                let mode_changed = matches!(avr_event, AvrEvent::SurroundMode(_));
+                let input_changed = matches!(avr_event, AvrEvent::InputSource(_));
+                let headphones_plugged = matches!(avr_event, AvrEvent::Headphones(true))
+                    && !app.avr_state.headphones;
+                let avr_disconnected = matches!(avr_event, AvrEvent::Disconnected);
                 app.handle_avr_event(avr_event);
+                if mode_changed {
+                    let _ = app.avr_query_surround_parameters().await;
+                }
+                if headphones_plugged {
+                    if let Err(e) = app.avr_apply_headphone_volume().await {
+                        app.set_error(e);
+                    }
+                }
+                // A new source usually means a new signal
+                if (mode_changed || input_changed) && app.avr_panel_expanded {
+                    let _ = app.avr_query_signal_info().await;
+                }
+
+                // Retry the receiver in the background, then query everything again
+                let reconnecting = avr_reconnect_task
+                    .as_ref()
+                    .is_some_and(|task| !task.is_finished());
+                if avr_disconnected && !reconnecting {
+                    if let Some(host) = app.avr_host.clone() {
+                        let event_tx = avr_tx.clone();
+                        let handle_tx = avr_reconnect_handle_tx.clone();
+                        avr_reconnect_task = Some(tokio::spawn(async move {
+                            let handle = AvrClient::reconnect(&host, event_tx).await;
+                            let _ = handle_tx.send((handle.clone(), host)).await;
+                            let _ = handle.query_status().await;
+                        }));
+                    }
+                }
             }
-            Some(handle) = handle_rx.recv() => {
+            Some((handle, host)) = handle_rx.recv() => {
+                if app.connection_state == ConnectionState::Disconnected {
+                    app.set_status(app.messages.format(Msg::Reconnected, &[&host]));
+                }
                 app.set_handle(handle.clone());
+                app.connected_host = Some(host);
                 // Get initial player state
                 if let Err(e) = app.refresh_player_state().await {
-                    app.set_status(format!("Error getting player state: {}", e));
+                    app.set_status(app.messages.format(Msg::PlayerStateError, &[&e]));
                 }
             }
-            Some(avr_handle) = avr_handle_rx.recv() => {
+            Some((avr_handle, host)) = avr_handle_rx.recv() => {
                 app.set_avr_handle(avr_handle);
+                app.avr_host = Some(host);
+            }
+            Some(command) = command_rx.recv() => {
+                if let Err(e) = app.run_command(command).await {
+                    app.set_error(e);
+                }
+            }
+        }
+
+        if let Some(mqtt) = &mut mqtt {
+            mqtt.publish_state(&app);
+        }
+        for event in watcher.changes(&app) {
+            webhooks.notify(&event, &app);
+            hooks.run(&event, &app);
+            let (pid, media) = (app.current_pid(), &app.player_state.now_playing);
+            if app.stats.observe(&event, pid, media) {
+                if let Err(e) = app.stats.save() {
+                    app.set_error(e);
+                }
+            }
+            if event == StateEvent::TrackChanged {
+                let mut entry = HistoryEntry::new(
+                    &app.player_state.now_playing,
+                    app.current_player()
+                        .map(|p| p.name.as_str())
+                        .unwrap_or_default(),
+                    app.now_playing_source()
+                        .map(|s| s.name.as_str())
+                        .unwrap_or_default(),
+                );
+                if let Some(source) = app.queue_sources.get(&entry.mid) {
+                    entry.cid = source.cid.clone();
+                }
+                if let Err(e) = app.history.record(entry) {
+                    app.set_error(e);
+                }
             }
         }
 
         if app.should_quit {
+            if let Err(e) = app.stats.save() {
+                app.set_error(e);
+            }
             break;
         }
     }
 
     // Restore terminal
+    if keyboard_enhanced {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)
+            .context("Failed to disable keyboard enhancement")?;
+    }
     disable_raw_mode().context("Failed to disable raw mode")?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)
         .context("Failed to leave alternate screen")?;
@@ -205,199 +578,898 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Register for events and load the initial state of a new connection
+async fn start_session(handle: &HeosHandle, account: &AccountConfig) {
+    let _ = handle.register_for_events().await;
+    match (&account.username, &account.password) {
+        (Some(username), Some(password)) => {
+            let _ = handle.sign_in(username, password).await;
+        }
+        _ => {
+            let _ = handle.check_account().await;
+        }
+    }
+    handle.load_players();
+    // Sources resolve the "via ..." line in Now Playing
+    let _ = handle.get_music_sources().await;
+}
+
 async fn handle_action(app: &mut App, action: Action) -> Result<()> {
     match action {
         Action::Quit => {
             app.should_quit = true;
         }
+        Action::PlayPause if app.current_view == View::Devices => {
+            app.toggle_device_mark(app.device_selected);
+        }
+        Action::GroupPlayers => match app.group_marked_players().await {
+            Ok(count) => app.set_status(app.messages.format(Msg::GroupCreated, &[&count])),
+            Err(e) => app.set_error(e),
+        },
+        Action::UngroupPlayer => match app.ungroup_player(app.device_selected).await {
+            Ok(Some(name)) => app.set_status(app.messages.format(Msg::GroupDissolved, &[&name])),
+            Ok(None) => app.set_status(app.messages.get(Msg::PlayerNotGrouped)),
+            Err(e) => app.set_error(e),
+        },
+        Action::TransferPlayback if app.current_view == View::Devices => {
+            if let Err(e) = app.transfer_playback(app.device_selected) {
+                app.set_error(e);
+            }
+        }
+        Action::TransferPlayback => {}
         Action::PlayPause => {
             if let Err(e) = app.toggle_play_pause().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
+            }
+        }
+        Action::Stop if app.current_view == View::QuickSelects => {
+            // `s` inside the popup saves instead of stopping
+            match app.save_quickselect().await {
+                Ok(Some(id)) => app.set_status(app.messages.format(Msg::QuickSelectSaved, &[&id])),
+                Ok(None) => {}
+                Err(e) => app.set_error(e),
+            }
+        }
+        Action::Stop if app.current_view == View::AvrQuickSelect => {
+            // Same as the HEOS quick selects: `s` saves to the highlighted memory
+            if let Some(&quick) = QuickSelect::all().get(app.avr_quick_select_selected) {
+                if let Err(e) = app.avr_save_quick_select(quick).await {
+                    app.set_error(e);
+                } else {
+                    app.set_status(
+                        app.messages
+                            .format(Msg::AvrQuickSelectSaved, &[&quick.number()]),
+                    );
+                }
             }
         }
         Action::Stop => {
-            if let Err(e) = app.stop().await {
-                app.set_status(format!("Error: {}", e));
+            // Stopping also ends an imported stream list
+            app.stream_queue.clear();
+            if app.config.ui.soft_volume {
+                app.fade(Fade::Stop);
+            } else if let Err(e) = app.stop().await {
+                app.set_error(e);
             }
         }
         Action::NextTrack => {
-            if let Err(e) = app.next_track().await {
-                app.set_status(format!("Error: {}", e));
+            // URL streams have no HEOS queue, so skip within the imported list instead
+            if !app.stream_queue.is_empty() {
+                play_next_stream(app).await;
+            } else if let Err(e) = app.next_track().await {
+                app.set_error(e);
             }
         }
         Action::PrevTrack => {
             if let Err(e) = app.prev_track().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
+            }
+        }
+        Action::VolumeUp | Action::VolumeDown if app.current_view == View::Devices => {
+            let up = action == Action::VolumeUp;
+            if let Err(e) = app.step_player_volume(app.device_selected, up).await {
+                app.set_error(e);
             }
         }
         Action::VolumeUp => {
             if let Err(e) = app.volume_up().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
         }
         Action::VolumeDown => {
             if let Err(e) = app.volume_down().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
+            }
+        }
+        Action::AvrVolumeUp => {
+            if let Err(e) = app.avr_volume_half_up().await {
+                app.set_error(e);
+            }
+        }
+        Action::AvrVolumeDown => {
+            if let Err(e) = app.avr_volume_half_down().await {
+                app.set_error(e);
+            }
+        }
+        Action::RetryPlayback => match app.retry_playback().await {
+            Ok(true) => app.set_status(app.messages.get(Msg::PlaybackRetried)),
+            Ok(false) => {}
+            Err(e) => app.set_error(e),
+        },
+        Action::ToggleAvrPower => {
+            let on = !app.avr_state.power;
+            // Standby cuts off whatever HEOS is playing through the receiver
+            if !on && app.player_state.play_state == PlayState::Play {
+                app.confirm = Some(Confirm::AvrStandby);
+            } else {
+                set_avr_power(app, on).await;
+            }
+        }
+        Action::CycleZone => {
+            let zone = app.cycle_avr_zone();
+            app.set_status(
+                app.messages
+                    .format(Msg::ZoneSelected, &[&zone.display_name()]),
+            );
+        }
+        Action::ToggleMute if app.current_view == View::Devices => {
+            let idx = app.device_selected;
+            if let Err(e) = app.toggle_player_mute(idx).await {
+                app.set_error(e);
             }
         }
+        Action::ToggleMute if app.config.ui.soft_volume => {
+            let fade = if app.player_state.mute == MuteState::On {
+                Fade::Unmute
+            } else {
+                Fade::Mute
+            };
+            app.fade(fade);
+        }
         Action::ToggleMute => {
             if let Err(e) = app.toggle_mute().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
         }
         Action::CycleRepeat => {
             if let Err(e) = app.cycle_repeat().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
         }
         Action::ToggleShuffle => {
             if let Err(e) = app.toggle_shuffle().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
+            }
+        }
+        Action::ToggleVolumeUnit => {
+            let msg = if app.toggle_volume_units() {
+                Msg::VolumeUnitDb
+            } else {
+                Msg::VolumeUnitRaw
+            };
+            app.set_status(app.messages.get(msg));
+        }
+        Action::ToggleFollowPlayback => {
+            let ui = &mut app.config.ui;
+            ui.follow_playback = !ui.follow_playback;
+            let msg = if ui.follow_playback {
+                Msg::FollowPlaybackOn
+            } else {
+                Msg::FollowPlaybackOff
+            };
+            app.set_status(app.messages.get(msg));
+        }
+        Action::ToggleAvrPanel => {
+            app.avr_panel_expanded = !app.avr_panel_expanded;
+            if app.avr_panel_expanded {
+                if let Err(e) = app.avr_query_signal_info().await {
+                    app.set_error(e);
+                }
             }
         }
         Action::ShowDevices => {
             app.show_view(View::Devices);
-            if let Err(e) = app.refresh_players().await {
-                app.set_status(format!("Error: {}", e));
+            app.device_marks.clear();
+            if let Err(e) = app.refresh_groups().await {
+                app.set_error(e);
+            }
+            app.refresh_players();
+            if let Err(e) = app.refresh_player_volumes().await {
+                app.set_error(e);
+            }
+            if let Err(e) = app.refresh_play_states().await {
+                app.set_error(e);
+            }
+        }
+        Action::ShowQueue if app.current_view == View::Queue => {
+            // `u` again inside the queue undoes the last removal
+            if !app.undo_queue_edit() {
+                app.set_status(app.messages.get(Msg::QueueNothingToUndo));
             }
         }
         Action::ShowQueue => {
             app.show_view(View::Queue);
             if let Err(e) = app.refresh_queue().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
         }
         Action::ShowBrowse => {
             app.show_view(View::Browse);
             app.browse_stack.clear();
+            if let Err(e) = app.reload_local_playlists() {
+                app.set_error(e);
+            }
             if let Err(e) = app.refresh_music_sources().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
         }
         Action::ShowInputs => {
             app.show_view(View::Inputs);
+            app.refresh_input_sources();
+        }
+        Action::ShowHdmiMonitor => {
+            // Start the picker on the current output
+            app.hdmi_monitor_selected = app
+                .avr_state
+                .hdmi_monitor
+                .and_then(|output| HdmiMonitor::all().iter().position(|o| *o == output))
+                .unwrap_or(0);
+            app.show_view(View::HdmiMonitor);
+        }
+        Action::ShowAvrInputs => {
+            app.show_view(View::AvrInputs);
+            // Start on the input playing now
+            let current = app.avr_state.input_source.clone();
+            app.avr_input_selected = app
+                .avr_visible_inputs()
+                .iter()
+                .position(|input| input.code == current)
+                .unwrap_or(0);
         }
         Action::ShowSurroundModes => {
             app.show_view(View::SurroundModes);
             app.surround_selected = 0;
         }
+        Action::ShowUpmixers => {
+            app.show_view(View::Upmixers);
+            app.upmixer_selected = 0;
+        }
+        Action::ShowGroups => {
+            app.show_view(View::Groups);
+            if let Err(e) = app.refresh_groups().await {
+                app.set_error(e);
+            }
+        }
+        Action::ShowGroupMixer => {
+            app.show_view(View::GroupMixer);
+            app.mixer_focus = 0;
+            // A known group only needs its membership refreshed
+            let result = match app.active_group().map(|g| g.gid) {
+                Some(gid) => app.refresh_group(gid).await,
+                None => app.refresh_groups().await,
+            };
+            if let Err(e) = result {
+                app.set_error(e);
+            }
+        }
+        Action::ShowAvrQuickSelect => {
+            app.show_view(View::AvrQuickSelect);
+            app.avr_quick_select_selected = app
+                .avr_state
+                .quick_select
+                .map_or(0, |quick| usize::from(quick.number() - 1));
+        }
+        Action::ShowAvrPresets => {
+            app.show_view(View::AvrPresets);
+            app.avr_preset_selected = 0;
+        }
         Action::ShowSoundSettings => {
             app.show_view(View::SoundSettings);
             app.sound_setting_selected = 0;
         }
+        Action::ThumbsUp | Action::ThumbsDown => {
+            match app.rate_track(action == Action::ThumbsUp).await {
+                Ok(true) => {}
+                Ok(false) => app.set_status(app.messages.get(Msg::ThumbsUnavailable)),
+                Err(e) => app.set_error(e),
+            }
+        }
+        Action::ShowQuickSelects => {
+            app.show_view(View::QuickSelects);
+            if let Err(e) = app.refresh_quickselects().await {
+                app.set_error(e);
+            }
+        }
+        Action::ShowFavorites => {
+            app.show_view(View::Favorites);
+            app.refresh_favorites();
+        }
+        Action::ShowBookmarks => {
+            app.show_view(View::Bookmarks);
+            app.bookmark_selected = 0;
+        }
+        Action::ShowPluginActions => {
+            app.show_view(View::PluginActions);
+            app.plugin_action_selected = 0;
+        }
+        Action::ShowHistory => {
+            app.history_selected = 0;
+            app.show_view(View::History);
+        }
+        Action::ShowStats => {
+            app.show_view(View::Stats);
+        }
+        Action::ShowNowPlaying if app.current_view == View::Devices => {
+            app.load_player_info();
+        }
+        Action::ShowNowPlaying => {
+            app.show_view(View::NowPlaying);
+            // Needed to resolve the source name of the current media
+            if app.music_sources.is_empty() {
+                if let Err(e) = app.refresh_music_sources().await {
+                    app.set_error(e);
+                }
+            }
+        }
+        Action::AddBookmark => {
+            if app.current_view == View::Browse {
+                let name = app.browse_stack.last().map(|l| l.name.clone());
+                match (app.add_bookmark(), name) {
+                    (Ok(true), Some(name)) => {
+                        app.set_status(app.messages.format(Msg::BookmarkAdded, &[&name]))
+                    }
+                    (Ok(false), Some(name)) => {
+                        app.set_status(app.messages.format(Msg::BookmarkExists, &[&name]))
+                    }
+                    (Ok(_), None) => app.set_status(app.messages.get(Msg::BookmarkNothing)),
+                    (Err(e), _) => app.set_error(e),
+                }
+            }
+        }
+        Action::ClearQueue => {
+            if app.current_view == View::Queue {
+                if app.queue.is_empty() {
+                    app.set_status(app.messages.get(Msg::QueueEmpty));
+                } else {
+                    app.confirm = Some(Confirm::ClearQueue);
+                }
+            }
+        }
+        Action::DeleteItem => {
+            if app.current_view == View::Queue {
+                match app.remove_queue_item(app.queue_selected).await {
+                    Ok(Some(song)) => {
+                        app.set_status(app.messages.format(Msg::QueueItemRemoved, &[&song]))
+                    }
+                    Ok(None) => {}
+                    Err(e) => app.set_error(e),
+                }
+            } else if app.current_view == View::Bookmarks {
+                match app.remove_bookmark(app.bookmark_selected) {
+                    Ok(Some(removed)) => {
+                        app.set_status(app.messages.format(Msg::BookmarkRemoved, &[&removed.name]))
+                    }
+                    Ok(None) => {}
+                    Err(e) => app.set_error(e),
+                }
+            }
+        }
+        Action::CommandMode => {
+            app.open_prompt(PromptKind::Command, "");
+        }
+        Action::Search => {
+            if app.current_view == View::Browse {
+                match app.start_search().await {
+                    Ok(true) => app.set_status(app.messages.get(Msg::SearchLoading)),
+                    Ok(false) => app.set_status(app.messages.get(Msg::SearchUnavailable)),
+                    Err(e) => app.set_error(e),
+                }
+            }
+        }
+        Action::ShowServiceOptions => {
+            if app.current_view == View::Browse && !app.open_service_options() {
+                app.set_status(app.messages.get(Msg::NoServiceOptions));
+            }
+        }
+        Action::SetVolume => {
+            let level = app.player_state.volume.to_string();
+            app.open_prompt(PromptKind::Volume, level);
+        }
+        Action::SignIn => {
+            let username = app.config.account.username.clone().unwrap_or_default();
+            app.open_prompt(PromptKind::SignInUser, username);
+        }
+        Action::ExportQueue => {
+            if app.current_view == View::Queue {
+                app.open_prompt(PromptKind::ExportQueue, "~/heos-queue.m3u");
+            }
+        }
+        Action::ImportUrls => {
+            if app.current_view == View::Queue {
+                app.open_prompt(PromptKind::ImportUrls, "~/");
+            }
+        }
+        Action::PlayUrl => {
+            app.open_prompt(PromptKind::PlayUrl, "");
+        }
+        Action::PlayPlaylist => {
+            // Plays the highlighted local playlist from its first entry
+            if let Some(BrowseLevel {
+                sid: LOCAL_PLAYLISTS_SID,
+                cid: None,
+                ..
+            }) = app.browse_stack.last()
+            {
+                if let Some(item) = app.browse_items.get(app.browse_selected).cloned() {
+                    play_local_playlist(app, &item.cid, 0).await;
+                }
+            } else if let Some((sid, item)) = app.playable_container() {
+                let (cid, name) = (item.cid.clone(), item.name.clone());
+                match app.play_container(sid, &cid, 0).await {
+                    Ok(()) => app.set_status(app.messages.format(Msg::ContainerPlaying, &[&name])),
+                    Err(e) => app.set_error(e),
+                }
+            }
+        }
+        Action::QueuePlaylist => {
+            if let Some((sid, item)) = app.playable_container() {
+                let (cid, name) = (item.cid.clone(), item.name.clone());
+                match app.append_container(sid, &cid).await {
+                    Ok(()) => app.set_status(app.messages.format(Msg::ContainerQueued, &[&name])),
+                    Err(e) => app.set_error(e),
+                }
+            }
+        }
+        Action::OpenLink => match app.current_item_link() {
+            // Show the URL first; a second press on the same item opens it
+            Some(url) if app.pending_link.as_deref() == Some(url.as_str()) => {
+                app.pending_link = None;
+                match app.open_link(&url) {
+                    Ok(()) => app.set_status(app.messages.format(Msg::LinkOpened, &[&url])),
+                    Err(e) => app.set_error(e),
+                }
+            }
+            Some(url) => {
+                app.set_status(app.messages.format(Msg::LinkConfirm, &[&url]));
+                app.pending_link = Some(url);
+            }
+            None => {
+                app.pending_link = None;
+                app.set_status(app.messages.get(Msg::NoLink));
+            }
+        },
         Action::ShowHelp => {
             app.show_view(View::Help);
         }
         Action::Back => {
-            app.go_back();
+            if app.current_view == View::Main && app.cancel_discovery() {
+                // Esc on the discovery screen only stops the search
+            } else if !app.jump_input.is_empty() {
+                app.jump_input.clear();
+                app.clear_status();
+            } else if app.current_view == View::Browse && !app.browse_stack.is_empty() {
+                if let Err(e) = app.browse_back().await {
+                    app.set_error(e);
+                }
+            } else {
+                app.go_back();
+            }
+        }
+        Action::Refresh if app.current_view == View::Browse => {
+            if let Err(e) = app.refresh_browse().await {
+                app.set_error(e);
+            }
         }
         Action::Select => {
-            handle_select(app).await?;
+            if app.jump_input.is_empty() {
+                handle_select(app).await?;
+            } else {
+                handle_jump(app);
+            }
+        }
+        Action::MoveUp if app.current_view == View::GroupMixer => {
+            if let Err(e) = app.mixer_volume_up().await {
+                app.set_error(e);
+            }
+        }
+        Action::MoveDown if app.current_view == View::GroupMixer => {
+            if let Err(e) = app.mixer_volume_down().await {
+                app.set_error(e);
+            }
         }
         Action::MoveUp => {
+            app.jump_input.clear();
             handle_move_up(app);
         }
         Action::MoveDown => {
+            app.jump_input.clear();
             handle_move_down(app);
         }
+        Action::MoveLeft if app.current_view == View::GroupMixer => {
+            app.mixer_focus_move(false);
+        }
+        Action::MoveRight if app.current_view == View::GroupMixer => {
+            app.mixer_focus_move(true);
+        }
+        Action::MoveLeft | Action::MoveRight if app.current_view == View::ToneSlider => {
+            app.tone_slider_move(action == Action::MoveRight);
+        }
+        Action::MoveLeft | Action::MoveRight if app.current_view == View::Audyssey => {
+            step_audyssey(app, action == Action::MoveRight).await;
+        }
         Action::MoveLeft | Action::MoveRight => {
-            // Could be used for seeking in future
+            // The HEOS CLI protocol has no seek command; say so instead of doing nothing
+            if app.current_view == View::Main && app.playback_progress().is_some() {
+                app.set_status(app.messages.get(Msg::SeekUnsupported));
+            }
         }
         Action::Refresh => {
             if let Err(e) = app.refresh_player_state().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
             if let Err(e) = app.avr_query_status().await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
         }
-    }
-    Ok(())
-}
-
-fn handle_move_up(app: &mut App) {
-    match app.current_view {
-        View::Devices => {
-            if app.device_selected > 0 {
-                app.device_selected -= 1;
-            }
+        Action::JumpTop => {
+            app.jump_input.clear();
+            select_first(app);
         }
-        View::Queue => {
-            if app.queue_selected > 0 {
-                app.queue_selected -= 1;
+        Action::JumpBottom => {
+            app.jump_input.clear();
+            select_last(app);
+        }
+        Action::JumpNowPlaying => {
+            if app.current_view == View::Queue && !app.jump_to_now_playing() {
+                app.set_status(app.messages.get(Msg::NowPlayingNotInQueue));
             }
         }
-        View::Browse => {
-            if app.browse_selected > 0 {
-                app.browse_selected -= 1;
+        Action::OpenBookmark(n) => {
+            let idx = usize::from(n) - 1;
+            if idx < app.config.bookmarks.len() {
+                if let Err(e) = app.open_bookmark(idx).await {
+                    app.set_error(e);
+                }
+            } else {
+                app.set_status(app.messages.format(Msg::NoBookmarkNumber, &[&n]));
             }
         }
-        View::Inputs => {
-            if app.input_selected > 0 {
-                app.input_selected -= 1;
+        Action::Digit(digit) if app.current_view == View::AvrQuickSelect => {
+            if let Some(quick) = QuickSelect::from_number(digit) {
+                recall_avr_quick_select(app, quick).await;
             }
         }
-        View::SurroundModes => {
-            if app.surround_selected > 0 {
-                app.surround_selected -= 1;
+        Action::Digit(digit @ 1..=9) if app.current_view == View::Main => {
+            play_favorite(app, usize::from(digit)).await;
+        }
+        Action::Digit(digit) => {
+            if matches!(app.current_view, View::Queue | View::Browse) && app.jump_input.len() < 6 {
+                app.jump_input.push(char::from(b'0' + digit));
+                app.set_status(app.messages.format(Msg::JumpTo, &[&app.jump_input]));
             }
         }
-        View::SoundSettings => {
-            if app.sound_setting_selected > 0 {
-                app.sound_setting_selected -= 1;
+    }
+
+    // Large containers load in pages as the selection moves down
+    if app.current_view == View::Browse {
+        if let Err(e) = app.fetch_more_browse_items().await {
+            app.set_error(e);
+        }
+    }
+    Ok(())
+}
+
+/// Change the highlighted Audyssey setting; the popup updates when the AVR answers
+async fn step_audyssey(app: &mut App, forward: bool) {
+    if let Some(setting) = ui::audyssey::get_setting_at_index(app.audyssey_selected) {
+        if let Err(e) = app.avr_audyssey_step(setting, forward).await {
+            app.set_error(e);
+        }
+    }
+}
+
+/// Recall an AVR quick select and close the popup
+async fn recall_avr_quick_select(app: &mut App, quick: QuickSelect) {
+    if let Err(e) = app.avr_recall_quick_select(quick).await {
+        app.set_error(e);
+    } else {
+        let status = app
+            .messages
+            .format(Msg::AvrQuickSelectRecalled, &[&quick.number()]);
+        app.set_status(status);
+    }
+    app.current_view = View::Main;
+}
+
+/// Play the nth (1-based) HEOS favorite on the current player
+async fn play_favorite(app: &mut App, preset: usize) {
+    match app.play_favorite(preset).await {
+        Ok(Some(name)) => app.set_status(app.messages.format(Msg::PlayingFavorite, &[&name])),
+        Ok(None) => app.set_status(app.messages.format(Msg::PlayingFavoriteNumber, &[&preset])),
+        Err(e) => app.set_error(e),
+    }
+}
+
+/// y / Enter answers yes, n / Esc no; other keys are ignored
+async fn handle_confirm_key(app: &mut App, confirm: Confirm, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {}
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            app.confirm = None;
+            return;
+        }
+        _ => return,
+    }
+    app.confirm = None;
+    match confirm {
+        Confirm::ClearQueue => match app.clear_queue().await {
+            Ok(Some(count)) => app.set_status(app.messages.format(Msg::QueueCleared, &[&count])),
+            Ok(None) => app.set_status(app.messages.get(Msg::QueueClearedNoUndo)),
+            Err(e) => app.set_error(e),
+        },
+        Confirm::AvrStandby => set_avr_power(app, false).await,
+    }
+}
+
+async fn set_avr_power(app: &mut App, on: bool) {
+    match app.avr_set_power(on).await {
+        Ok(()) => {
+            let msg = if on { Msg::AvrPowerOn } else { Msg::AvrStandby };
+            app.set_status(app.messages.get(msg));
+        }
+        Err(e) => app.set_error(e),
+    }
+}
+
+/// Edit the open text prompt; Enter submits it and Esc closes it
+async fn handle_prompt_key(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(prompt) = app.prompt.as_mut() else {
+        return Ok(());
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Esc, _) => app.prompt = None,
+        (KeyCode::Enter, _) => {
+            if let Some(prompt) = app.prompt.take() {
+                submit_prompt(app, prompt).await?;
             }
         }
+        (KeyCode::Backspace, _) => {
+            prompt.input.pop();
+        }
+        (KeyCode::Char('u'), KeyModifiers::CONTROL) => prompt.input.clear(),
+        (KeyCode::Char(c), m) if !m.contains(KeyModifiers::CONTROL) => prompt.input.push(c),
         _ => {}
     }
+    Ok(())
 }
 
-fn handle_move_down(app: &mut App) {
-    match app.current_view {
-        View::Devices => {
-            if app.device_selected < app.players.len().saturating_sub(1) {
-                app.device_selected += 1;
+async fn submit_prompt(app: &mut App, prompt: Prompt) -> Result<()> {
+    match prompt.kind {
+        PromptKind::Command => match Command::parse(&prompt.input) {
+            Ok(Some(command)) => {
+                if let Err(e) = app.run_command(command).await {
+                    app.set_error(e);
+                }
             }
-        }
-        View::Queue => {
-            if app.queue_selected < app.queue.len().saturating_sub(1) {
-                app.queue_selected += 1;
+            Ok(None) => {}
+            Err(e) => app.set_error(e),
+        },
+        PromptKind::ExportQueue => {
+            if app.queue.is_empty() {
+                app.set_status(app.messages.get(Msg::QueueEmpty));
+                return Ok(());
+            }
+            match app.export_queue(&prompt.input) {
+                Ok(path) => app.set_status(
+                    app.messages
+                        .format(Msg::QueueExported, &[&app.queue.len(), &path.display()]),
+                ),
+                Err(e) => app.set_error(e),
             }
         }
-        View::Browse => {
-            let max = if app.browse_stack.is_empty() {
-                app.music_sources.len()
-            } else {
-                app.browse_items.len()
-            };
-            if app.browse_selected < max.saturating_sub(1) {
-                app.browse_selected += 1;
+        PromptKind::Volume => match prompt.input.trim().parse::<u8>() {
+            Ok(level) if level <= 100 => match app.set_volume(level).await {
+                Ok(sent) if sent < level => {
+                    app.set_status(app.messages.format(Msg::VolumeCapped, &[&sent]))
+                }
+                Ok(sent) => app.set_status(app.messages.format(Msg::VolumeSet, &[&sent])),
+                Err(e) => app.set_error(e),
+            },
+            _ => app.set_error(
+                app.messages
+                    .format(Msg::InvalidVolume, &[&prompt.input.trim()]),
+            ),
+        },
+        PromptKind::Search => {
+            let query = prompt.input.trim();
+            if !query.is_empty() {
+                if let Err(e) = app.search(query).await {
+                    app.set_error(e);
+                }
             }
         }
-        View::Inputs => {
-            if app.input_selected < ui::inputs::input_count().saturating_sub(1) {
-                app.input_selected += 1;
+        PromptKind::SignInUser => {
+            let username = prompt.input.trim();
+            if !username.is_empty() {
+                app.sign_in_user = Some(username.to_string());
+                app.open_prompt(PromptKind::SignInPassword, "");
             }
         }
-        View::SurroundModes => {
-            if app.surround_selected < ui::surround::mode_count().saturating_sub(1) {
-                app.surround_selected += 1;
+        PromptKind::SignInPassword => {
+            if let Some(username) = app.sign_in_user.take() {
+                if let Err(e) = app.sign_in(&username, &prompt.input).await {
+                    app.set_error(e);
+                }
             }
         }
-        View::SoundSettings => {
-            if app.sound_setting_selected < ui::sound_settings::setting_count().saturating_sub(1) {
-                app.sound_setting_selected += 1;
+        PromptKind::ImportUrls => match app.import_url_list(&prompt.input).await {
+            Ok(0) => app.set_status(app.messages.format(Msg::NoUrls, &[&prompt.input])),
+            Ok(count) => app.set_status(app.messages.format(Msg::UrlsImported, &[&count])),
+            Err(e) => app.set_error(e),
+        },
+        PromptKind::PlayUrl => {
+            let url = prompt.input.trim();
+            if !url.is_empty() {
+                match app.play_stream_url(url).await {
+                    Ok(()) => app.set_status(app.messages.format(Msg::PlayingUrl, &[&url])),
+                    Err(e) => app.set_error(e),
+                }
             }
         }
+    }
+    Ok(())
+}
+
+async fn play_local_playlist(app: &mut App, id: &str, start: usize) {
+    match app.play_local_playlist(id, start).await {
+        Ok(0) => {}
+        Ok(count) => app.set_status(app.messages.format(Msg::PlaylistPlaying, &[&count])),
+        Err(e) => app.set_error(e),
+    }
+}
+
+/// Browse into the highlighted container
+async fn open_browse_container(app: &mut App) {
+    let (Some(level), Some(item)) = (
+        app.browse_stack.last(),
+        app.browse_items.get(app.browse_selected),
+    ) else {
+        return;
+    };
+    let level = BrowseLevel {
+        sid: level.sid,
+        cid: Some(item.cid.clone()),
+        name: item.name.clone(),
+        search: None,
+    };
+    if let Err(e) = app.enter_browse_level(level).await {
+        app.set_error(e);
+    }
+}
+
+async fn play_next_stream(app: &mut App) {
+    match app.play_next_stream().await {
+        Ok(true) => {
+            let left = app.stream_queue.len();
+            app.set_status(app.messages.format(Msg::NextStream, &[&left]));
+        }
+        Ok(false) => {}
+        Err(e) => app.set_error(e),
+    }
+}
+
+/// Move the selection to the item number typed with the digit keys
+fn handle_jump(app: &mut App) {
+    let number: usize = app.jump_input.parse().unwrap_or(0);
+    app.jump_input.clear();
+    app.clear_status();
+
+    let len = match app.current_view {
+        View::Queue => app.queue.len(),
+        View::Browse if app.browse_stack.is_empty() => app.music_sources.len(),
+        View::Browse => app.browse_items.len(),
+        _ => 0,
+    };
+    if number == 0 || len == 0 {
+        return;
+    }
+
+    let index = number.min(len) - 1;
+    match app.current_view {
+        View::Queue => app.queue_selected = index,
+        View::Browse => app.browse_selected = index,
         _ => {}
     }
 }
 
+fn select_first(app: &mut App) {
+    if let Some((selected, _)) = list_selection(app) {
+        *selected = 0;
+    }
+}
+
+fn select_last(app: &mut App) {
+    if let Some((selected, len)) = list_selection(app) {
+        *selected = len.saturating_sub(1);
+    }
+}
+
+/// The highlighted row of the open list and how many rows it has
+fn list_selection(app: &mut App) -> Option<(&mut usize, usize)> {
+    let selection = match app.current_view {
+        View::Devices => (&mut app.device_selected, app.players.len()),
+        View::Queue => (&mut app.queue_selected, app.queue.len()),
+        View::Browse if app.browse_stack.is_empty() => {
+            (&mut app.browse_selected, app.music_sources.len())
+        }
+        View::Browse => (&mut app.browse_selected, app.browse_items.len()),
+        View::Inputs => (&mut app.input_selected, app.input_sources.len()),
+        View::HdmiMonitor => (
+            &mut app.hdmi_monitor_selected,
+            ui::hdmi_monitor::option_count(),
+        ),
+        View::AvrInputs => {
+            let len = app.avr_visible_inputs().len();
+            (&mut app.avr_input_selected, len)
+        }
+        View::SurroundModes => (&mut app.surround_selected, ui::surround::mode_count()),
+        View::Upmixers => (&mut app.upmixer_selected, ui::upmixer::upmixer_count()),
+        View::Groups => (&mut app.group_selected, app.groups.len()),
+        View::AvrQuickSelect => (&mut app.avr_quick_select_selected, QuickSelect::all().len()),
+        View::AvrPresets => (&mut app.avr_preset_selected, app.config.avr_presets.len()),
+        View::SoundSettings => {
+            let len = ui::sound_settings::setting_count(app);
+            (&mut app.sound_setting_selected, len)
+        }
+        View::DynamicCompression => (
+            &mut app.compression_selected,
+            ui::compression::option_count(),
+        ),
+        View::SleepTimer => (
+            &mut app.sleep_timer_selected,
+            ui::sleep_timer::option_count(),
+        ),
+        View::Audyssey => (&mut app.audyssey_selected, ui::audyssey::setting_count()),
+        View::DynamicVolume => (
+            &mut app.dynamic_volume_selected,
+            ui::dynamic_volume::option_count(),
+        ),
+        View::Bookmarks => (&mut app.bookmark_selected, app.config.bookmarks.len()),
+        View::BrowseMenu => (&mut app.browse_menu_selected, app.browse_menu.len()),
+        View::ServiceOptions => (&mut app.service_option_selected, app.service_options.len()),
+        View::QuickSelects => (&mut app.quickselect_selected, app.quickselects.len()),
+        View::History => (&mut app.history_selected, app.history.entries.len()),
+        View::Favorites => (&mut app.favorite_selected, app.favorites.len()),
+        View::SearchCriteria => (&mut app.search_criteria_selected, app.search_criteria.len()),
+        View::PluginActions => {
+            let len = app.plugins.actions().len();
+            (&mut app.plugin_action_selected, len)
+        }
+        _ => return None,
+    };
+    Some(selection)
+}
+
+fn handle_move_up(app: &mut App) {
+    if let Some((selected, _)) = list_selection(app) {
+        *selected = selected.saturating_sub(1);
+    }
+}
+
+fn handle_move_down(app: &mut App) {
+    if let Some((selected, len)) = list_selection(app) {
+        if *selected + 1 < len {
+            *selected += 1;
+        }
+    }
+}
+
 async fn handle_select(app: &mut App) -> Result<()> {
     match app.current_view {
         View::Devices => {
             let idx = app.device_selected;
             if let Err(e) = app.select_player(idx).await {
-                app.set_status(format!("Error: {}", e));
+                app.set_error(e);
             }
             app.current_view = View::Main;
         }
@@ -405,7 +1477,7 @@ async fn handle_select(app: &mut App) -> Result<()> {
             if let Some(item) = app.queue.get(app.queue_selected) {
                 let qid = item.qid;
                 if let Err(e) = app.play_queue_item(qid).await {
-                    app.set_status(format!("Error: {}", e));
+                    app.set_error(e);
                 }
             }
         }
@@ -413,78 +1485,350 @@ async fn handle_select(app: &mut App) -> Result<()> {
             if app.browse_stack.is_empty() {
                 // Select a music source
                 if let Some(source) = app.music_sources.get(app.browse_selected) {
-                    let sid = source.sid;
-                    app.browse_stack.push((sid, source.name.clone()));
-                    if let Err(e) = app.browse_source(sid).await {
-                        app.set_status(format!("Error: {}", e));
-                        app.browse_stack.pop();
+                    let level = BrowseLevel {
+                        sid: source.sid,
+                        cid: None,
+                        name: source.name.clone(),
+                        search: None,
+                    };
+                    if let Err(e) = app.enter_browse_level(level).await {
+                        app.set_error(e);
                     }
                 }
+            } else if let Some(BrowseLevel {
+                sid: LOCAL_PLAYLISTS_SID,
+                cid: Some(id),
+                ..
+            }) = app.browse_stack.last().cloned()
+            {
+                // Local playlist entries play from the selected one onwards
+                play_local_playlist(app, &id, app.browse_selected).await;
+            } else if app.open_browse_menu() {
+                // Playable items ask what to do with them; keep the selection for the menu
+                return Ok(());
             } else {
                 // Select a browse item
                 if let Some(item) = app.browse_items.get(app.browse_selected) {
                     if item.container == "yes" {
-                        if let Some((sid, _)) = app.browse_stack.last() {
-                            let sid = *sid;
-                            let cid = item.cid.clone();
-                            app.browse_stack.push((sid, item.name.clone()));
-                            if let Err(e) = app.browse_container(sid, &cid).await {
-                                app.set_status(format!("Error: {}", e));
-                                app.browse_stack.pop();
+                        open_browse_container(app).await;
+                    } else if !item.mid.is_empty()
+                        && app
+                            .browse_stack
+                            .last()
+                            .is_some_and(|level| PluginHost::is_plugin_sid(level.sid))
+                    {
+                        // Plugin items carry their stream URL in `mid`
+                        let url = item.mid.clone();
+                        if let Err(e) = app.run_command(Command::PlayUrl(url)).await {
+                            app.set_error(e);
+                        }
+                    } else if item.playable == "yes" {
+                        // Stations (and tracks that can't be queued) stream directly
+                        match app.play_browse_item().await {
+                            Ok(Some(name)) => {
+                                app.set_status(app.messages.format(Msg::ContainerPlaying, &[&name]))
                             }
+                            Ok(None) => {}
+                            Err(e) => app.set_error(e),
                         }
                     }
-                    // TODO: Handle playable items
                 }
             }
             app.browse_selected = 0;
         }
-        View::Inputs => {
-            if let Some(input) = ui::inputs::get_input_at_index(app.input_selected) {
-                if let Err(e) = app.play_input(input).await {
-                    app.set_status(format!("Error: {}", e));
+        View::HdmiMonitor => {
+            if let Some(output) = ui::hdmi_monitor::get_option_at_index(app.hdmi_monitor_selected) {
+                if let Err(e) = app.avr_set_hdmi_monitor(output).await {
+                    app.set_error(e);
+                } else {
+                    app.set_status(
+                        app.messages
+                            .format(Msg::HdmiMonitorSet, &[&output.display_name()]),
+                    );
+                }
+            }
+            app.current_view = View::Main;
+        }
+        View::AvrInputs => {
+            let input = app
+                .avr_visible_inputs()
+                .get(app.avr_input_selected)
+                .map(|input| (input.code.clone(), input.name.clone()));
+            if let Some((code, name)) = input {
+                match app.avr_set_input(&code).await {
+                    Ok(()) => app.set_status(app.messages.format(Msg::AvrInputSet, &[&name])),
+                    Err(e) => app.set_error(e),
                 }
             }
             app.current_view = View::Main;
         }
+        View::Inputs => {
+            match app.play_input_source().await {
+                Ok(Some(name)) => app.set_status(app.messages.format(Msg::PlayingInput, &[&name])),
+                Ok(None) => {}
+                Err(e) => app.set_error(e),
+            }
+            app.current_view = View::Main;
+        }
         View::SurroundModes => {
             if let Some(mode) = ui::surround::get_mode_at_index(app.surround_selected) {
                 if let Err(e) = app.avr_set_surround_mode(mode).await {
-                    app.set_status(format!("Error: {}", e));
+                    app.set_error(e);
+                } else {
+                    app.set_status(
+                        app.messages
+                            .format(Msg::SurroundModeSet, &[&mode.display_name()]),
+                    );
+                }
+            }
+            app.current_view = View::Main;
+        }
+        View::Upmixers => {
+            if let Some(upmixer) = ui::upmixer::get_upmixer_at_index(app.upmixer_selected) {
+                if let Err(e) = app.avr_set_upmixer(upmixer).await {
+                    app.set_error(e);
                 } else {
-                    app.set_status(format!("Surround mode: {}", mode.display_name()));
+                    app.set_status(
+                        app.messages
+                            .format(Msg::SurroundModeSet, &[&upmixer.display_name()]),
+                    );
                 }
             }
             app.current_view = View::Main;
         }
+        View::AvrQuickSelect => {
+            if let Some(&quick) = QuickSelect::all().get(app.avr_quick_select_selected) {
+                recall_avr_quick_select(app, quick).await;
+            }
+        }
+        View::AvrPresets => {
+            if let Some(name) = app.avr_apply_preset(app.avr_preset_selected) {
+                let status = app.messages.format(Msg::AvrPresetApplied, &[&name]);
+                app.set_status(status);
+            }
+            app.current_view = View::Main;
+        }
         View::SoundSettings => {
-            if let Some(setting) = ui::sound_settings::get_setting_at_index(app.sound_setting_selected) {
+            if let Some(setting) =
+                ui::sound_settings::get_setting_at_index(app, app.sound_setting_selected)
+            {
                 use ui::sound_settings::SoundSetting;
                 let result = match setting {
-                    SoundSetting::BassUp => app.avr_bass_up().await,
-                    SoundSetting::BassDown => app.avr_bass_down().await,
-                    SoundSetting::TrebleUp => app.avr_treble_up().await,
-                    SoundSetting::TrebleDown => app.avr_treble_down().await,
+                    SoundSetting::Bass | SoundSetting::Treble => {
+                        let tone = if setting == SoundSetting::Bass {
+                            Tone::Bass
+                        } else {
+                            Tone::Treble
+                        };
+                        app.open_tone_slider(tone);
+                        return Ok(());
+                    }
                     SoundSetting::SubwooferUp => app.avr_subwoofer_up().await,
                     SoundSetting::SubwooferDown => app.avr_subwoofer_down().await,
-                    SoundSetting::DynamicEq => app.avr_dynamic_eq_toggle().await,
+                    SoundSetting::LfeUp => app.avr_lfe_up().await,
+                    SoundSetting::LfeDown => app.avr_lfe_down().await,
+                    SoundSetting::BassSyncUp => app.avr_bass_sync_up().await,
+                    SoundSetting::BassSyncDown => app.avr_bass_sync_down().await,
+                    SoundSetting::LoudnessManagement => app.avr_loudness_management_toggle().await,
+                    SoundSetting::CinemaEq => app.avr_cinema_eq_toggle().await,
+                    SoundSetting::Lfc => app.avr_lfc_toggle().await,
+                    SoundSetting::LfcAmountUp => app.avr_lfc_amount_up().await,
+                    SoundSetting::LfcAmountDown => app.avr_lfc_amount_down().await,
+                    SoundSetting::Audyssey => {
+                        app.audyssey_selected = 0;
+                        app.show_view(View::Audyssey);
+                        if let Err(e) = app.avr_query_audyssey().await {
+                            app.set_error(e);
+                        }
+                        return Ok(());
+                    }
+                    SoundSetting::DynamicCompression => {
+                        // Start the picker on the current setting
+                        app.compression_selected = app
+                            .avr_state
+                            .dynamic_compression
+                            .and_then(|drc| {
+                                DynamicCompression::all().iter().position(|d| *d == drc)
+                            })
+                            .unwrap_or(0);
+                        app.show_view(View::DynamicCompression);
+                        return Ok(());
+                    }
+                    SoundSetting::SleepTimer => {
+                        // Start on the running length, or Off
+                        app.sleep_timer_selected = app
+                            .avr_state
+                            .sleep_timer
+                            .and_then(|(minutes, _)| {
+                                SLEEP_TIMER_MINUTES.iter().position(|m| *m == minutes)
+                            })
+                            .map_or(0, |i| i + 1);
+                        app.show_view(View::SleepTimer);
+                        return Ok(());
+                    }
+                    SoundSetting::Restorer => app.avr_restorer_cycle().await,
+                    SoundSetting::Imax => app.avr_imax_cycle().await,
+                    SoundSetting::Eco => app.avr_eco_cycle().await,
                     SoundSetting::DialogEnhancer => {
                         // TODO: Could prompt for level
-                        app.set_status("Dialog enhancer adjusted");
+                        app.set_status(app.messages.get(Msg::DialogEnhancerAdjusted));
                         Ok(())
                     }
                 };
                 if let Err(e) = result {
-                    app.set_status(format!("Error: {}", e));
+                    app.set_error(e);
                 } else {
-                    app.set_status(format!("Applied: {}", setting.display_name()));
+                    app.set_status(
+                        app.messages
+                            .format(Msg::Applied, &[&app.messages.get(setting.display_name())]),
+                    );
                 }
             }
             // Don't close - allow multiple adjustments
         }
-        View::Help => {
+        View::DynamicCompression => {
+            if let Some(drc) = ui::compression::get_option_at_index(app.compression_selected) {
+                if let Err(e) = app.avr_set_dynamic_compression(drc).await {
+                    app.set_error(e);
+                } else {
+                    app.set_status(
+                        app.messages
+                            .format(Msg::DynamicCompressionSet, &[&drc.display_name()]),
+                    );
+                }
+            }
+            app.current_view = View::SoundSettings;
+        }
+        View::Audyssey => {
+            use ui::audyssey::AudysseySetting;
+            if ui::audyssey::get_setting_at_index(app.audyssey_selected)
+                == Some(AudysseySetting::DynamicVolume)
+            {
+                // Start the picker on the current mode
+                app.dynamic_volume_selected = app
+                    .avr_state
+                    .dynamic_volume
+                    .and_then(|mode| DynamicVolume::all().iter().position(|m| *m == mode))
+                    .unwrap_or(0);
+                app.show_view(View::DynamicVolume);
+            } else {
+                step_audyssey(app, true).await;
+            }
+        }
+        View::DynamicVolume => {
+            if let Some(mode) = ui::dynamic_volume::get_option_at_index(app.dynamic_volume_selected)
+            {
+                if let Err(e) = app.avr_set_dynamic_volume(mode).await {
+                    app.set_error(e);
+                } else {
+                    app.set_status(
+                        app.messages
+                            .format(Msg::DynamicVolumeSet, &[&mode.display_name()]),
+                    );
+                }
+            }
+            app.current_view = View::Audyssey;
+        }
+        View::ToneSlider => {
+            if let Some((tone, db)) = app.apply_tone_slider() {
+                let name = app.messages.get(ui::tone_slider::tone_name(tone));
+                let level = ui::sound_settings::db(f32::from(db));
+                app.set_status(app.messages.format(Msg::ToneSet, &[&name, &level]));
+            }
+        }
+        View::SleepTimer => {
+            if let Some(minutes) = ui::sleep_timer::get_option_at_index(app.sleep_timer_selected) {
+                if let Err(e) = app.avr_set_sleep_timer(minutes).await {
+                    app.set_error(e);
+                } else {
+                    let name = ui::sleep_timer::option_name(app, minutes);
+                    app.set_status(app.messages.format(Msg::SleepTimerSet, &[&name]));
+                }
+            }
+            app.current_view = View::SoundSettings;
+        }
+        View::Bookmarks => {
+            let idx = app.bookmark_selected;
+            if let Err(e) = app.open_bookmark(idx).await {
+                app.set_error(e);
+            }
+        }
+        View::ServiceOptions => {
+            app.current_view = View::Browse;
+            if let Err(e) = app.run_service_option().await {
+                app.set_error(e);
+            }
+        }
+        View::BrowseMenu => {
+            app.current_view = View::Browse;
+            let Some(action) = app.browse_menu.get(app.browse_menu_selected).copied() else {
+                return Ok(());
+            };
+            if action == BrowseAction::Open {
+                open_browse_container(app).await;
+                app.browse_selected = 0;
+                return Ok(());
+            }
+            match app.run_browse_action(action).await {
+                Ok(Some(name)) => app.set_status(app.messages.format(action.done(), &[&name])),
+                Ok(None) => {}
+                Err(e) => app.set_error(e),
+            }
+        }
+        View::QuickSelects => match app.play_quickselect().await {
+            Ok(Some(name)) => {
+                app.set_status(app.messages.format(Msg::QuickSelectPlaying, &[&name]));
+                app.current_view = View::Main;
+            }
+            Ok(None) => {}
+            Err(e) => app.set_error(e),
+        },
+        View::Favorites => {
+            if !app.favorites.is_empty() {
+                app.current_view = View::Main;
+                play_favorite(app, app.favorite_selected + 1).await;
+            }
+        }
+        View::History => match app.play_history_entry(app.history_selected).await {
+            Ok(Some(title)) => {
+                app.set_status(app.messages.format(Msg::ContainerPlaying, &[&title]));
+                app.current_view = View::Main;
+            }
+            Ok(None) => {}
+            Err(e) => app.set_error(e),
+        },
+        View::SearchCriteria => {
+            app.current_view = View::Browse;
+            if !app.search_criteria.is_empty() {
+                app.open_prompt(PromptKind::Search, "");
+            }
+        }
+        View::PluginActions => {
+            let idx = app.plugin_action_selected;
+            app.current_view = View::Main;
+            match app.run_plugin_action(idx).await {
+                Ok(Some(message)) => app.set_status(message),
+                Ok(None) => {}
+                Err(e) => app.set_error(e),
+            }
+        }
+        View::NowPlaying | View::Help | View::GroupMixer | View::Stats => {
             app.current_view = View::Main;
         }
+        View::PlayerInfo => {
+            app.current_view = View::Devices;
+        }
+        View::Groups => {
+            let idx = app.group_selected;
+            match app.select_group(idx).await {
+                Ok(Some(name)) => {
+                    app.set_status(app.messages.format(Msg::GroupSelected, &[&name]));
+                    app.current_view = View::Main;
+                }
+                Ok(None) => {}
+                Err(e) => app.set_error(e),
+            }
+        }
         View::Main => {}
     }
     Ok(())
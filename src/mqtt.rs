@@ -0,0 +1,104 @@
+use crate::app::App;
+use crate::command::Command;
+use crate::config::MqttConfig;
+use crate::heos::MuteState;
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Publishes player/AVR state to MQTT and forwards commands from the command topic
+pub struct MqttBridge {
+    client: AsyncClient,
+    config: MqttConfig,
+    /// Last payload sent per topic, so unchanged state isn't republished every loop
+    published: HashMap<String, String>,
+}
+
+impl MqttBridge {
+    /// Connect in the background; parsed commands are sent to `command_tx`
+    pub fn start(config: MqttConfig, command_tx: mpsc::Sender<Command>) -> Self {
+        let mut options = MqttOptions::new(&config.client_id, &config.host, config.port);
+        options.set_keep_alive(Duration::from_secs(30));
+        if let Some(username) = &config.username {
+            options.set_credentials(username, config.password.as_deref().unwrap_or_default());
+        }
+
+        let (client, mut eventloop) = AsyncClient::new(options, 10);
+        let subscriber = client.clone();
+        let command_topic = config.command_topic.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match eventloop.poll().await {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        // Subscriptions don't survive a reconnect
+                        let _ = subscriber.try_subscribe(&command_topic, QoS::AtLeastOnce);
+                    }
+                    Ok(Event::Incoming(Packet::Publish(publish))) => {
+                        let payload = String::from_utf8_lossy(&publish.payload);
+                        if let Ok(Some(command)) = Command::parse(&payload) {
+                            if command_tx.send(command).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(_) => {
+                        // Broker unreachable; the event loop reconnects on the next poll
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
+                }
+            }
+        });
+
+        Self {
+            client,
+            config,
+            published: HashMap::new(),
+        }
+    }
+
+    /// Publish (retained) any state that changed since the last call
+    pub fn publish_state(&mut self, app: &App) {
+        let state = &app.player_state;
+        let media = &state.now_playing;
+        let now_playing = json!({
+            "player": app.current_player().map(|p| p.name.as_str()),
+            "state": state.play_state.as_str(),
+            "song": media.song,
+            "artist": media.artist,
+            "album": media.album,
+            "station": media.station,
+            "image_url": media.image_url,
+        });
+        let volume = json!({
+            "level": state.volume,
+            "muted": state.mute == MuteState::On,
+            "avr_volume": app.avr_state.master_volume.to_string(),
+            "avr_muted": app.avr_state.muted,
+        });
+        let power = if app.avr_state.power { "on" } else { "off" };
+
+        let topics = [
+            (
+                self.config.now_playing_topic.clone(),
+                now_playing.to_string(),
+            ),
+            (self.config.volume_topic.clone(), volume.to_string()),
+            (self.config.power_topic.clone(), power.to_string()),
+        ];
+        for (topic, payload) in topics {
+            if self.published.get(&topic) == Some(&payload) {
+                continue;
+            }
+            let sent = self
+                .client
+                .try_publish(&topic, QoS::AtLeastOnce, true, payload.clone());
+            if sent.is_ok() {
+                self.published.insert(topic, payload);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::app::App;
+use crate::command::Command;
+use crate::config::Config;
+use crate::heos::{discover_with_known, HeosClient, HeosEvent};
+use anyhow::{Context, Result};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::mpsc;
+
+/// How long to wait for the device to answer a command before moving on
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Run newline-delimited commands from stdin against the device, without the TUI
+pub async fn run(config: Config, host: Option<String>, discovery_timeout: u64) -> Result<()> {
+    let (mut app, mut heos_rx) = connect(config, host, discovery_timeout).await?;
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    while let Some(line) = lines.next_line().await? {
+        let command = match Command::parse(&line) {
+            Ok(Some(command)) => command,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!("{}", e);
+                continue;
+            }
+        };
+        if matches!(command, Command::AvrVolume(_)) {
+            eprintln!("{}: the AVR is only controlled from the TUI", line.trim());
+            continue;
+        }
+        let remote = command.is_remote();
+        let moving = matches!(command, Command::Move(_));
+        if let Err(e) = app.run_command(command).await {
+            eprintln!("{}: {}", line.trim(), e);
+            continue;
+        }
+        if moving {
+            // The move runs in the background; take over the player once it's done
+            let mut moved_to = None;
+            wait_for(&mut app, &mut heos_rx, |event| match event {
+                HeosEvent::PlaybackTransferred { to, .. } => {
+                    moved_to = Some(*to);
+                    true
+                }
+                HeosEvent::Error(_) => true,
+                _ => false,
+            })
+            .await;
+            if let Some(to) = moved_to {
+                if let Err(e) = app.finish_transfer(to).await {
+                    eprintln!("{}: {}", line.trim(), e);
+                }
+            }
+        } else if remote {
+            wait_for_response(&mut app, &mut heos_rx, "").await;
+        }
+    }
+    Ok(())
+}
+
+/// Play a stream URL on the default player, for `--play-url`
+pub async fn play_url(
+    config: Config,
+    host: Option<String>,
+    discovery_timeout: u64,
+    url: &str,
+) -> Result<()> {
+    let (mut app, mut heos_rx) = connect(config, host, discovery_timeout).await?;
+    app.play_stream_url(url).await?;
+    wait_for_response(&mut app, &mut heos_rx, "play_stream").await;
+    match app.last_error.take() {
+        Some(e) => anyhow::bail!(e),
+        None => Ok(()),
+    }
+}
+
+/// Connect to the given or first discovered device and load its players
+async fn connect(
+    config: Config,
+    host: Option<String>,
+    discovery_timeout: u64,
+) -> Result<(App, mpsc::Receiver<HeosEvent>)> {
+    let host = match host {
+        Some(host) => host,
+        None => discover_with_known(discovery_timeout, &config.known_devices())
+            .await
+            .into_iter()
+            .next()
+            .map(|d| d.ip)
+            .context("No HEOS device found")?,
+    };
+
+    let (heos_tx, heos_rx) = mpsc::channel::<HeosEvent>(100);
+    let handle = HeosClient::connect(&host, heos_tx).await?;
+    let mut app = App::new(config);
+    app.set_handle(handle.clone());
+
+    // Commands need a player id, so wait for the player list first
+    let players = handle.get_players().await?;
+    app.handle_heos_event(HeosEvent::Players(players));
+    Ok((app, heos_rx))
+}
+
+/// Feed events to the app until a response to `command` (or any response, if empty) arrives
+async fn wait_for_response(app: &mut App, rx: &mut mpsc::Receiver<HeosEvent>, command: &str) {
+    wait_for(app, rx, |event| {
+        matches!(
+            event,
+            HeosEvent::Response(response) if response.heos.command.contains(command)
+        )
+    })
+    .await;
+}
+
+/// Feed events to the app until one for which `done` is true arrives
+async fn wait_for(
+    app: &mut App,
+    rx: &mut mpsc::Receiver<HeosEvent>,
+    mut done: impl FnMut(&HeosEvent) -> bool,
+) {
+    let _ = tokio::time::timeout(RESPONSE_TIMEOUT, async {
+        while let Some(event) = rx.recv().await {
+            let done = done(&event);
+            if let HeosEvent::Error(e) = &event {
+                eprintln!("{}", e);
+            }
+            app.handle_heos_event(event);
+            if done {
+                break;
+            }
+        }
+    })
+    .await;
+}
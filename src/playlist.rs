@@ -0,0 +1,172 @@
+use crate::config::Config;
+use crate::heos::{BrowseItem, MusicSource, QueueItem};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Source id used for the local playlists browse root; HEOS ids are positive
+pub const LOCAL_PLAYLISTS_SID: i64 = -1;
+
+/// A playlist file from the `playlists` directory next to the config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalPlaylist {
+    /// File stem, used as the browse container id
+    #[serde(skip)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub items: Vec<PlaylistEntry>,
+}
+
+/// One playable entry: a stream `url`, a station/favorite (`sid` + `mid`)
+/// or a container (`sid` + `cid`) that replaces the queue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlaylistEntry {
+    #[serde(default)]
+    pub name: String,
+    pub url: Option<String>,
+    pub sid: Option<i64>,
+    pub mid: Option<String>,
+    pub cid: Option<String>,
+}
+
+impl PlaylistEntry {
+    pub fn from_url(url: String) -> Self {
+        Self {
+            name: url.clone(),
+            url: Some(url),
+            ..Default::default()
+        }
+    }
+}
+
+impl LocalPlaylist {
+    pub fn browse_item(&self) -> BrowseItem {
+        BrowseItem {
+            container: "yes".to_string(),
+            cid: self.id.clone(),
+            mid: String::new(),
+            name: self.name.clone(),
+            item_type: "playlist".to_string(),
+            image_url: String::new(),
+            playable: "yes".to_string(),
+        }
+    }
+
+    pub fn entry_items(&self) -> Vec<BrowseItem> {
+        self.items
+            .iter()
+            .map(|entry| BrowseItem {
+                container: "no".to_string(),
+                cid: entry.cid.clone().unwrap_or_default(),
+                mid: entry
+                    .mid
+                    .clone()
+                    .or_else(|| entry.url.clone())
+                    .unwrap_or_default(),
+                name: entry.name.clone(),
+                item_type: "station".to_string(),
+                image_url: String::new(),
+                playable: "yes".to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Pseudo music source shown at the browse root for local playlists
+pub fn local_source(name: &str) -> MusicSource {
+    MusicSource {
+        sid: LOCAL_PLAYLISTS_SID,
+        name: name.to_string(),
+        source_type: "local_playlists".to_string(),
+        image_url: String::new(),
+        available: "true".to_string(),
+        service_username: String::new(),
+    }
+}
+
+/// Load every `*.toml` playlist, sorted by name; a missing directory means none
+pub fn load_local_playlists() -> Result<Vec<LocalPlaylist>> {
+    let dir = Config::playlists_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut playlists = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "toml") {
+            continue;
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        let mut playlist: LocalPlaylist = toml::from_str(&contents)
+            .with_context(|| format!("Invalid playlist {}", path.display()))?;
+        playlist.id = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if playlist.name.is_empty() {
+            playlist.name = playlist.id.clone();
+        }
+        playlists.push(playlist);
+    }
+    playlists.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(playlists)
+}
+
+/// Expand a leading `~` to the home directory
+pub fn expand_path(input: &str) -> PathBuf {
+    match input.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => dirs::home_dir()
+            .map(|home| home.join(rest.trim_start_matches('/')))
+            .unwrap_or_else(|| PathBuf::from(input)),
+        _ => PathBuf::from(input),
+    }
+}
+
+/// Read stream URLs from a plain text or M3U file, skipping comments and blank lines
+pub fn read_url_list(path: &Path) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#') && line.contains("://"))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Write queue items to `path`; `.json` files get JSON, anything else M3U
+pub fn export_queue(path: &Path, items: &[QueueItem]) -> Result<()> {
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+
+    let contents = if is_json {
+        serde_json::to_string_pretty(items)?
+    } else {
+        to_m3u(items)
+    };
+
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn to_m3u(items: &[QueueItem]) -> String {
+    let mut out = String::from("#EXTM3U\n");
+    for item in items {
+        let title = if item.artist.is_empty() {
+            item.song.clone()
+        } else {
+            format!("{} - {}", item.artist, item.song)
+        };
+        let _ = writeln!(out, "#EXTINF:-1,{}", title);
+        if !item.album.is_empty() {
+            let _ = writeln!(out, "#EXTALB:{}", item.album);
+        }
+        // HEOS only exposes the media id; for streams this is usually the URL
+        let _ = writeln!(out, "{}", item.mid);
+    }
+    out
+}
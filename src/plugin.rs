@@ -0,0 +1,229 @@
+use crate::config::Config;
+use crate::heos::{BrowseItem, MusicSource};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+
+/// Browse roots from plugins get source ids counting down from here
+pub const PLUGIN_SID_BASE: i64 = -100;
+
+/// How long a plugin gets to answer a request
+const PLUGIN_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// What a plugin announces in reply to `init`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PluginInfo {
+    pub name: String,
+    #[serde(default)]
+    pub roots: Vec<PluginRoot>,
+    #[serde(default)]
+    pub actions: Vec<PluginAction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginRoot {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginAction {
+    pub id: String,
+    pub name: String,
+}
+
+/// An entry returned by `browse`: a container to open or a stream URL to play
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginItem {
+    pub name: String,
+    pub cid: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub image_url: String,
+}
+
+impl PluginItem {
+    fn browse_item(self) -> BrowseItem {
+        BrowseItem {
+            container: if self.cid.is_some() { "yes" } else { "no" }.to_string(),
+            cid: self.cid.unwrap_or_default(),
+            playable: if self.url.is_some() { "yes" } else { "no" }.to_string(),
+            mid: self.url.unwrap_or_default(),
+            name: self.name,
+            item_type: "plugin".to_string(),
+            image_url: self.image_url,
+        }
+    }
+}
+
+/// A running plugin process speaking newline-delimited JSON over stdio
+///
+/// Requests are `{"id": n, "method": "...", "params": {...}}`; the plugin
+/// answers each with `{"id": n, "result": ...}` or `{"id": n, "error": "..."}`.
+/// Methods: `init`, `browse` (`root`, `cid`) and `action` (`action`, `context`).
+struct Plugin {
+    info: PluginInfo,
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+    next_id: u64,
+    _child: Child,
+}
+
+impl Plugin {
+    async fn spawn(path: &Path) -> Result<Self> {
+        let mut child = Command::new(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("Failed to start plugin {}", path.display()))?;
+        let stdin = child.stdin.take().context("Plugin stdin unavailable")?;
+        let stdout = child.stdout.take().context("Plugin stdout unavailable")?;
+
+        let mut plugin = Self {
+            info: PluginInfo::default(),
+            stdin,
+            stdout: BufReader::new(stdout).lines(),
+            next_id: 1,
+            _child: child,
+        };
+        let result = plugin.request("init", json!({})).await?;
+        plugin.info = serde_json::from_value(result)
+            .with_context(|| format!("Invalid init reply from {}", path.display()))?;
+        Ok(plugin)
+    }
+
+    async fn request(&mut self, method: &str, params: Value) -> Result<Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let mut line = json!({ "id": id, "method": method, "params": params }).to_string();
+        line.push('\n');
+        self.stdin.write_all(line.as_bytes()).await?;
+        self.stdin.flush().await?;
+
+        tokio::time::timeout(PLUGIN_TIMEOUT, async {
+            // Skip replies to earlier requests that timed out
+            while let Some(line) = self.stdout.next_line().await? {
+                let Ok(reply) = serde_json::from_str::<Value>(&line) else {
+                    continue;
+                };
+                if reply["id"].as_u64() != Some(id) {
+                    continue;
+                }
+                if let Some(error) = reply["error"].as_str() {
+                    bail!("{}", error);
+                }
+                return Ok(reply["result"].clone());
+            }
+            bail!("Plugin exited")
+        })
+        .await
+        .with_context(|| format!("Plugin '{}' did not answer {}", self.info.name, method))?
+    }
+}
+
+/// Starts the plugins in the `plugins` directory and routes requests to them
+#[derive(Default)]
+pub struct PluginHost {
+    plugins: Vec<Plugin>,
+    /// Every plugin's browse roots as (plugin index, root), in source id order
+    roots: Vec<(usize, PluginRoot)>,
+}
+
+impl PluginHost {
+    /// Start every executable in the plugins directory; returns the host and
+    /// an error per plugin that failed to start
+    pub async fn load() -> (Self, Vec<anyhow::Error>) {
+        let mut host = Self::default();
+        let mut errors = Vec::new();
+
+        let dir = Config::plugins_dir();
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return (host, errors);
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.is_file())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            match Plugin::spawn(&path).await {
+                Ok(plugin) => {
+                    let idx = host.plugins.len();
+                    host.roots
+                        .extend(plugin.info.roots.iter().cloned().map(|root| (idx, root)));
+                    host.plugins.push(plugin);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+        (host, errors)
+    }
+
+    /// Pseudo music sources for the browse root, one per plugin root
+    pub fn sources(&self) -> Vec<MusicSource> {
+        self.roots
+            .iter()
+            .enumerate()
+            .map(|(i, (_, root))| MusicSource {
+                sid: PLUGIN_SID_BASE - i as i64,
+                name: root.name.clone(),
+                source_type: "plugin".to_string(),
+                image_url: String::new(),
+                available: "true".to_string(),
+                service_username: String::new(),
+            })
+            .collect()
+    }
+
+    pub fn is_plugin_sid(sid: i64) -> bool {
+        sid <= PLUGIN_SID_BASE
+    }
+
+    /// List a plugin root (`cid` of `None`) or one of its containers
+    pub async fn browse(&mut self, sid: i64, cid: Option<&str>) -> Result<Vec<BrowseItem>> {
+        let idx = (PLUGIN_SID_BASE - sid) as usize;
+        let Some((plugin_idx, root)) = self.roots.get(idx).cloned() else {
+            bail!("Unknown plugin source {}", sid);
+        };
+        let plugin = &mut self.plugins[plugin_idx];
+        let result = plugin
+            .request("browse", json!({ "root": root.id, "cid": cid }))
+            .await?;
+        let items: Vec<PluginItem> = serde_json::from_value(result["items"].clone())
+            .with_context(|| format!("Invalid browse reply from '{}'", plugin.info.name))?;
+        Ok(items.into_iter().map(PluginItem::browse_item).collect())
+    }
+
+    /// Every plugin's actions as (plugin name, action), in menu order
+    pub fn actions(&self) -> Vec<(&str, &PluginAction)> {
+        self.plugins
+            .iter()
+            .flat_map(|p| p.info.actions.iter().map(|a| (p.info.name.as_str(), a)))
+            .collect()
+    }
+
+    /// Run the `idx`th action from [`Self::actions`]; returns the plugin's message, if any
+    pub async fn run_action(&mut self, idx: usize, context: Value) -> Result<Option<String>> {
+        let mut remaining = idx;
+        for plugin in &mut self.plugins {
+            if remaining < plugin.info.actions.len() {
+                let action = plugin.info.actions[remaining].id.clone();
+                let result = plugin
+                    .request("action", json!({ "action": action, "context": context }))
+                    .await?;
+                return Ok(result["message"].as_str().map(str::to_string));
+            }
+            remaining -= plugin.info.actions.len();
+        }
+        Ok(None)
+    }
+}
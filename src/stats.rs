@@ -0,0 +1,153 @@
+use crate::config::Config;
+use crate::heos::{NowPlayingMedia, PlayState};
+use crate::watch::StateEvent;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Listening counters, for one session or all time
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListeningStats {
+    #[serde(default)]
+    pub tracks_played: u64,
+    #[serde(default)]
+    pub seconds_listened: u64,
+    /// Plays per artist
+    #[serde(default)]
+    pub artists: HashMap<String, u64>,
+    /// Plays per station
+    #[serde(default)]
+    pub stations: HashMap<String, u64>,
+}
+
+impl ListeningStats {
+    fn record_track(&mut self, media: &NowPlayingMedia) {
+        if !media.song.is_empty() {
+            self.tracks_played += 1;
+        }
+        if !media.artist.is_empty() {
+            *self.artists.entry(media.artist.clone()).or_default() += 1;
+        }
+        if !media.station.is_empty() {
+            *self.stations.entry(media.station.clone()).or_default() += 1;
+        }
+    }
+
+    /// The `n` most played artists, most played first
+    pub fn top_artists(&self, n: usize) -> Vec<(&str, u64)> {
+        top(&self.artists, n)
+    }
+
+    pub fn top_stations(&self, n: usize) -> Vec<(&str, u64)> {
+        top(&self.stations, n)
+    }
+}
+
+fn top(counts: &HashMap<String, u64>, n: usize) -> Vec<(&str, u64)> {
+    let mut entries: Vec<(&str, u64)> = counts.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    entries.truncate(n);
+    entries
+}
+
+/// Session and cumulative stats, the latter kept in `stats.toml` next to the config
+#[derive(Debug, Default)]
+pub struct Stats {
+    pub session: ListeningStats,
+    pub total: ListeningStats,
+    playing_since: Option<Instant>,
+    /// Player the last track change was seen on; a change on another one is a switch
+    track_pid: Option<i64>,
+    /// False until the file is read, so a failed load isn't saved over with zeros
+    loaded: bool,
+}
+
+impl Stats {
+    /// Load the cumulative stats; a missing file starts from zero
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        let total = if path.exists() {
+            toml::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            ListeningStats::default()
+        };
+        Ok(Self {
+            total,
+            loaded: true,
+            ..Default::default()
+        })
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        self.flush_listening(Instant::now());
+        if !self.loaded {
+            return Ok(());
+        }
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, toml::to_string_pretty(&self.total)?)?;
+        Ok(())
+    }
+
+    pub fn path() -> PathBuf {
+        Config::config_path().with_file_name("stats.toml")
+    }
+
+    /// Update the counters from a state change on player `pid`; returns true when worth saving
+    pub fn observe(
+        &mut self,
+        event: &StateEvent,
+        pid: Option<i64>,
+        media: &NowPlayingMedia,
+    ) -> bool {
+        match event {
+            StateEvent::TrackChanged => {
+                // The track found on connecting or switching players isn't a new play
+                let previous = std::mem::replace(&mut self.track_pid, pid);
+                if pid.is_none() || previous != pid {
+                    return false;
+                }
+                self.session.record_track(media);
+                self.total.record_track(media);
+                true
+            }
+            StateEvent::PlayStateChanged(PlayState::Play) => {
+                self.playing_since.get_or_insert_with(Instant::now);
+                false
+            }
+            StateEvent::PlayStateChanged(_) | StateEvent::Disconnected => {
+                self.flush_listening(Instant::now());
+                self.playing_since = None;
+                if *event == StateEvent::Disconnected {
+                    self.track_pid = None;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Listening time including the current, still running stretch
+    pub fn listened(&self, stats: &ListeningStats, now: Instant) -> Duration {
+        let running = self
+            .playing_since
+            .map(|since| now.saturating_duration_since(since))
+            .unwrap_or_default();
+        Duration::from_secs(stats.seconds_listened) + running
+    }
+
+    /// Move the running stretch into the counters
+    fn flush_listening(&mut self, now: Instant) {
+        if let Some(since) = self.playing_since {
+            let secs = now.saturating_duration_since(since).as_secs();
+            self.session.seconds_listened += secs;
+            self.total.seconds_listened += secs;
+            // Carry the partial second over to the next flush
+            self.playing_since = Some(since + Duration::from_secs(secs));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudysseySetting {
+    MultEq,
+    DynamicEq,
+    ReferenceLevel,
+    DynamicVolume,
+}
+
+impl AudysseySetting {
+    pub fn all() -> &'static [AudysseySetting] {
+        &[
+            AudysseySetting::MultEq,
+            AudysseySetting::DynamicEq,
+            AudysseySetting::ReferenceLevel,
+            AudysseySetting::DynamicVolume,
+        ]
+    }
+
+    pub fn display_name(&self) -> Msg {
+        match self {
+            AudysseySetting::MultEq => Msg::MultEq,
+            AudysseySetting::DynamicEq => Msg::DynamicEq,
+            AudysseySetting::ReferenceLevel => Msg::ReferenceLevel,
+            AudysseySetting::DynamicVolume => Msg::DynamicVolume,
+        }
+    }
+
+    pub fn description(&self) -> Msg {
+        match self {
+            AudysseySetting::MultEq => Msg::MultEqDesc,
+            AudysseySetting::DynamicEq => Msg::DynamicEqDesc,
+            AudysseySetting::ReferenceLevel => Msg::ReferenceLevelDesc,
+            AudysseySetting::DynamicVolume => Msg::DynamicVolumeDesc,
+        }
+    }
+}
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let state = &app.avr_state;
+
+    let items: Vec<ListItem> = AudysseySetting::all()
+        .iter()
+        .enumerate()
+        .map(|(i, setting)| {
+            let is_highlighted = i == app.audyssey_selected;
+
+            // "---" until the AVR answers the query sent on open
+            let value = match setting {
+                AudysseySetting::MultEq => state.multeq.map(|mode| mode.display_name().to_string()),
+                AudysseySetting::DynamicEq => state.dynamic_eq.map(|on| {
+                    let msg = if on { Msg::SettingOn } else { Msg::SettingOff };
+                    app.messages.get(msg).to_string()
+                }),
+                AudysseySetting::ReferenceLevel => {
+                    state.reference_level.map(|offset| format!("{} dB", offset))
+                }
+                AudysseySetting::DynamicVolume => state
+                    .dynamic_volume
+                    .map(|mode| mode.display_name().to_string()),
+            }
+            .unwrap_or_else(|| "---".to_string());
+
+            let content = format!(
+                "  {}  [{}]",
+                app.messages.get(setting.display_name()),
+                value
+            );
+
+            // The offset does nothing while Dynamic EQ is off
+            let inactive =
+                *setting == AudysseySetting::ReferenceLevel && state.dynamic_eq == Some(false);
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else if inactive {
+                app.theme.dim
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", app.messages.get(Msg::AudysseyTitle)))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Show description for selected item
+    if let Some(setting) = AudysseySetting::all().get(app.audyssey_selected) {
+        let desc_area = Rect {
+            x: area.x + 1,
+            y: area.y + area.height - 3,
+            width: area.width - 2,
+            height: 1,
+        };
+
+        let desc = Paragraph::new(app.messages.get(setting.description()))
+            .style(app.theme.accent)
+            .alignment(Alignment::Center);
+
+        frame.render_widget(desc, desc_area);
+    }
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::AudysseyInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
+
+pub fn get_setting_at_index(index: usize) -> Option<AudysseySetting> {
+    AudysseySetting::all().get(index).copied()
+}
+
+pub fn setting_count() -> usize {
+    AudysseySetting::all().len()
+}
@@ -0,0 +1,72 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(40, 60, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let inputs = app.avr_visible_inputs();
+    let items: Vec<ListItem> = if inputs.is_empty() {
+        vec![
+            ListItem::new(format!("  {}", app.messages.get(Msg::NoAvrInputs))).style(app.theme.dim),
+        ]
+    } else {
+        inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let is_highlighted = i == app.avr_input_selected;
+                let is_current = input.code == app.avr_state.input_source;
+
+                let style = if is_highlighted {
+                    app.theme.highlight
+                } else if is_current {
+                    app.theme.active
+                } else {
+                    Style::default()
+                };
+
+                let prefix = if is_current { "● " } else { "  " };
+                // The code too, where the input was renamed
+                let mut line = vec![Span::raw(format!("{}{}", prefix, input.name))];
+                if input.name != input.code {
+                    line.push(Span::styled(format!("  {}", input.code), app.theme.dim));
+                }
+                ListItem::new(Line::from(line)).style(style)
+            })
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", app.messages.get(Msg::AvrInputsTitle)))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
@@ -0,0 +1,81 @@
+use crate::app::App;
+use crate::config::AvrPreset;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 50, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::AvrPresetsTitle)))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    if app.config.avr_presets.is_empty() {
+        let empty = Paragraph::new(app.messages.get(Msg::NoAvrPresets))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .config
+        .avr_presets
+        .iter()
+        .enumerate()
+        .map(|(i, preset)| {
+            let is_highlighted = i == app.avr_preset_selected;
+
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("  {}", preset.name)),
+                Span::styled(format!("  {}", summary(preset)), app.theme.dim),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
+
+/// What the preset changes, e.g. "BD · MOVIE · 45"
+fn summary(preset: &AvrPreset) -> String {
+    let mut parts = Vec::new();
+    parts.extend(preset.input.clone());
+    parts.extend(preset.surround.clone());
+    parts.extend(preset.volume.map(|v| v.to_string()));
+    parts.join(" · ")
+}
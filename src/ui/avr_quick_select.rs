@@ -0,0 +1,66 @@
+use crate::app::App;
+use crate::heos::QuickSelect;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(40, 40, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = QuickSelect::all()
+        .iter()
+        .enumerate()
+        .map(|(i, quick)| {
+            let is_highlighted = i == app.avr_quick_select_selected;
+            let is_current = app.avr_state.quick_select == Some(*quick);
+
+            let prefix = if is_current { "● " } else { "  " };
+            let name = app
+                .messages
+                .format(Msg::AvrQuickSelectName, &[&quick.number()]);
+            let content = format!("{}{}", prefix, name);
+
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else if is_current {
+                app.theme.active
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", app.messages.get(Msg::AvrQuickSelectTitle)))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::AvrQuickSelectInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
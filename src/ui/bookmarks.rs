@@ -0,0 +1,73 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 50, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::BookmarksTitle)))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    if app.config.bookmarks.is_empty() {
+        let empty = Paragraph::new(app.messages.get(Msg::NoBookmarks))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .config
+        .bookmarks
+        .iter()
+        .enumerate()
+        .map(|(i, bookmark)| {
+            let is_highlighted = i == app.bookmark_selected;
+
+            let content = if bookmark.cid.is_some() && !bookmark.source_name.is_empty() {
+                format!("  {} ({})", bookmark.name, bookmark.source_name)
+            } else {
+                format!("  {}", bookmark.name)
+            };
+
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::BookmarksInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
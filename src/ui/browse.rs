@@ -1,4 +1,7 @@
 use crate::app::App;
+use crate::heos::MusicSource;
+use crate::i18n::Msg;
+use crate::ui;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
@@ -12,14 +15,29 @@ pub fn render(frame: &mut Frame, app: &App) {
 
     // Header with breadcrumb
     let breadcrumb = if app.browse_stack.is_empty() {
-        "Music Sources".to_string()
+        app.messages.get(Msg::MusicSources).to_string()
     } else {
         let path: Vec<String> = app
             .browse_stack
             .iter()
-            .map(|(_, cid)| cid.clone())
+            .map(|level| level.name.clone())
             .collect();
-        format!("Music Sources > {}", path.join(" > "))
+        format!(
+            "{} > {}",
+            app.messages.get(Msg::MusicSources),
+            path.join(" > ")
+        )
+    };
+
+    // How much of a paged container is loaded
+    let breadcrumb = match &app.browse_paging {
+        Some(paging) if !app.browse_stack.is_empty() => format!(
+            "{}  ({})",
+            breadcrumb,
+            app.messages
+                .format(Msg::BrowseCount, &[&app.browse_items.len(), &paging.total])
+        ),
+        _ => breadcrumb,
     };
 
     let header = Paragraph::new(format!(" {}", breadcrumb))
@@ -41,14 +59,32 @@ pub fn render(frame: &mut Frame, app: &App) {
     }
 
     // Instructions
-    let instructions = " ↑/↓ Navigate  Enter Select/Play  Esc Back ";
-    let instructions_para = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
+    let instructions_para = match ui::notice(app) {
+        Some((text, style)) => Paragraph::new(text).style(style),
+        None => {
+            let instructions = format!(" {} ", app.messages.get(Msg::BrowseInstructions));
+            Paragraph::new(instructions).style(app.theme.dim)
+        }
+    }
+    .alignment(Alignment::Center);
 
     frame.render_widget(instructions_para, chunks[2]);
 }
 
+/// Icon for a music source, also shown next to the source in Now Playing
+pub fn source_icon(source: &MusicSource) -> &'static str {
+    match source.source_type.as_str() {
+        // TuneIn, iHeartRadio, SiriusXM
+        "music_service" if matches!(source.sid, 3 | 7 | 8) => "📻",
+        "music_service" => "♪",
+        "heos_server" => "📁",
+        "dlna_server" => "💻",
+        "local_playlists" => "☰",
+        "plugin" => "⚙",
+        _ => "•",
+    }
+}
+
 fn render_sources(frame: &mut Frame, app: &App, area: Rect) {
     let items: Vec<ListItem> = app
         .music_sources
@@ -57,17 +93,10 @@ fn render_sources(frame: &mut Frame, app: &App, area: Rect) {
         .map(|(i, source)| {
             let is_highlighted = i == app.browse_selected;
 
-            let icon = match source.source_type.as_str() {
-                "music_service" => "♪",
-                "heos_server" => "📁",
-                "dlna_server" => "💻",
-                _ => "•",
-            };
-
-            let content = format!("{} {}", icon, source.name);
+            let content = format!("{} {}", source_icon(source), source.name);
 
             let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                app.theme.highlight
             } else {
                 Style::default()
             };
@@ -81,10 +110,10 @@ fn render_sources(frame: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Sources ")
+                .title(format!(" {} ", app.messages.get(Msg::SourcesTitle)))
                 .title_alignment(Alignment::Left),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
     frame.render_widget(list, area);
 }
@@ -108,7 +137,7 @@ fn render_items(frame: &mut Frame, app: &App, area: Rect) {
             let content = format!("{} {}", icon, item.name);
 
             let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                app.theme.highlight
             } else {
                 Style::default()
             };
@@ -122,10 +151,10 @@ fn render_items(frame: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Browse ")
+                .title(format!(" {} ", app.messages.get(Msg::BrowseTitle)))
                 .title_alignment(Alignment::Left),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
     frame.render_widget(list, area);
 }
@@ -0,0 +1,38 @@
+use crate::app::{App, Confirm};
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App, confirm: Confirm) {
+    let popup = centered_rect(50, 20, frame.area());
+    let area = Rect {
+        height: popup.height.min(5),
+        ..popup
+    };
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::ConfirmTitle)))
+        .title_alignment(Alignment::Center)
+        .title_bottom(
+            Line::styled(
+                format!(" {} ", app.messages.get(Msg::ConfirmInstructions)),
+                app.theme.dim,
+            )
+            .centered(),
+        )
+        .style(app.theme.popup);
+
+    let question = Paragraph::new(app.messages.get(confirm.question()))
+        .style(app.theme.warning)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(block);
+
+    frame.render_widget(question, area);
+}
@@ -1,4 +1,6 @@
-use crate::app::App;
+use crate::app::{App, PlayerRole};
+use crate::heos::{MuteState, PlayState, PlayerState};
+use crate::i18n::Msg;
 use crate::ui::centered_rect;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
@@ -9,6 +11,15 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Clear the popup area
     frame.render_widget(Clear, area);
 
+    // Players on top, devices found on the network below
+    let network_height = if app.network_devices.is_empty() {
+        0
+    } else {
+        (app.network_devices.len() as u16 + 2).min(area.height / 2)
+    };
+    let chunks =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(network_height)]).split(area);
+
     let items: Vec<ListItem> = app
         .players
         .iter()
@@ -18,17 +29,60 @@ pub fn render(frame: &mut Frame, app: &App) {
             let is_highlighted = i == app.device_selected;
 
             let prefix = if is_selected { "● " } else { "  " };
-            let content = format!("{}{} ({})", prefix, player.name, player.model);
+            let mark = if app.device_marks.contains(&player.pid) {
+                "☑ "
+            } else {
+                ""
+            };
+            let state = app.player_states.get(&player.pid);
+            let mut content = format!("{}{} ({})", mark, player.name, player.model);
+            if let Some(group) = app.group_of(player.pid) {
+                content.push_str(&format!(" [{}]", group.name));
+            }
+            let role = app.player_role(player.pid);
+            match role {
+                PlayerRole::Alone => {}
+                PlayerRole::GroupLeader => {
+                    content.push_str(&format!(" ★ {}", app.messages.get(Msg::RoleLeader)));
+                }
+                PlayerRole::GroupMember => {
+                    content.push_str(&format!(" {}", app.messages.get(Msg::RoleMember)));
+                }
+                PlayerRole::PairPrimary => {
+                    content.push_str(&format!(" ⇄ {}", app.messages.get(Msg::RoleStereoPair)));
+                }
+                PlayerRole::PairSecondary { primary } => {
+                    let name = app
+                        .players
+                        .iter()
+                        .find(|p| p.pid == primary)
+                        .map_or_else(|| primary.to_string(), |p| p.name.clone());
+                    let text = app.messages.format(Msg::RolePairSecondary, &[&name]);
+                    content.push_str(&format!(" ⇄ {}", text));
+                }
+            }
+            if state.is_some_and(|s| s.mute == MuteState::On) {
+                content.push_str(" 🔇");
+            }
+            if let Some(playing) = state.and_then(playing_summary) {
+                content.push_str(&format!("  {}", playing));
+            }
 
             let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                app.theme.highlight
             } else if is_selected {
-                Style::default().fg(Color::Green)
+                app.theme.active
+            } else if matches!(role, PlayerRole::PairSecondary { .. }) {
+                // Can't be controlled directly
+                app.theme.dim
             } else {
                 Style::default()
             };
 
-            ListItem::new(content).style(style)
+            let mut spans = vec![Span::raw(prefix)];
+            spans.extend(volume_bar(app, state.map(|s| s.volume)));
+            spans.push(Span::raw(content));
+            ListItem::new(Line::from(spans)).style(style)
         })
         .collect();
 
@@ -37,26 +91,91 @@ pub fn render(frame: &mut Frame, app: &App) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Select Device ")
+                .title(format!(" {} ", app.messages.get(Msg::SelectDeviceTitle)))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().bg(Color::Black)),
+                .style(app.theme.popup),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
-    frame.render_widget(list, area);
+    frame.render_widget(list, chunks[0]);
+    if network_height > 0 {
+        render_network_devices(frame, app, chunks[1]);
+    }
 
     // Instructions
-    let instructions = " ↑/↓ Navigate  Enter Select  Esc Cancel ";
+    let instructions = format!(" {} ", app.messages.get(Msg::DevicesInstructions));
     let instructions_area = Rect {
-        x: area.x,
-        y: area.y + area.height - 1,
-        width: area.width,
+        x: chunks[0].x,
+        y: chunks[0].y + chunks[0].height - 1,
+        width: chunks[0].width,
         height: 1,
     };
 
     let instructions_para = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(app.theme.dim)
         .alignment(Alignment::Center);
 
     frame.render_widget(instructions_para, instructions_area);
 }
+
+/// `━━━━────── 40 `, or blanks while the level is unknown
+fn volume_bar(app: &App, volume: Option<u8>) -> Vec<Span<'static>> {
+    const WIDTH: usize = 10;
+    let Some(volume) = volume else {
+        return vec![Span::raw(" ".repeat(WIDTH + 5))];
+    };
+    let filled = (volume as usize * WIDTH).div_ceil(100).min(WIDTH);
+    vec![
+        Span::styled("━".repeat(filled), app.theme.accent),
+        Span::styled("─".repeat(WIDTH - filled), app.theme.dim),
+        Span::raw(format!(" {:>3} ", volume)),
+    ]
+}
+
+/// What a player is playing or has paused, like "▶ Song – Artist"
+fn playing_summary(state: &PlayerState) -> Option<String> {
+    let icon = match state.play_state {
+        PlayState::Play => "▶",
+        PlayState::Pause => "⏸",
+        PlayState::Stop | PlayState::Unknown => return None,
+    };
+    let media = &state.now_playing;
+    let title = match (media.song.is_empty(), media.artist.is_empty()) {
+        (true, _) => media.station.clone(),
+        (false, true) => media.song.clone(),
+        (false, false) => format!("{} – {}", media.song, media.artist),
+    };
+    Some(format!("{} {}", icon, title).trim_end().to_string())
+}
+
+fn render_network_devices(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .network_devices
+        .iter()
+        .map(|device| {
+            let is_connected = app.connected_host.as_deref() == Some(device.ip.as_str());
+            let prefix = if is_connected { "● " } else { "  " };
+            let content = match &device.friendly_name {
+                Some(name) => format!("{}{} ({})", prefix, name, device.ip),
+                None => format!("{}{}", prefix, device.ip),
+            };
+            let style = if is_connected {
+                app.theme.active
+            } else {
+                app.theme.dim
+            };
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!(" {} ", app.messages.get(Msg::NetworkDevicesTitle)))
+            .title_alignment(Alignment::Center)
+            .style(app.theme.popup),
+    );
+
+    frame.render_widget(list, area);
+}
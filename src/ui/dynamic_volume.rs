@@ -0,0 +1,82 @@
+use crate::app::App;
+use crate::heos::DynamicVolume;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(40, 40, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let options = DynamicVolume::all();
+
+    let items: Vec<ListItem> = options
+        .iter()
+        .enumerate()
+        .map(|(i, mode)| {
+            let is_highlighted = i == app.dynamic_volume_selected;
+            let is_current = app.avr_state.dynamic_volume == Some(*mode);
+
+            let prefix = if is_current { "● " } else { "  " };
+            let content = format!("{}{}", prefix, mode.display_name());
+
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else if is_current {
+                app.theme.active
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let current = app
+        .avr_state
+        .dynamic_volume
+        .map(|mode| mode.display_name())
+        .unwrap_or(app.messages.get(Msg::Unknown));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(
+                    " {} ",
+                    app.messages.format(Msg::DynamicVolumeTitle, &[&current])
+                ))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
+
+pub fn get_option_at_index(index: usize) -> Option<DynamicVolume> {
+    DynamicVolume::all().get(index).copied()
+}
+
+pub fn option_count() -> usize {
+    DynamicVolume::all().len()
+}
@@ -0,0 +1,72 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 60, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::FavoritesTitle)))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    if app.favorites.is_empty() {
+        let empty = Paragraph::new(app.messages.get(Msg::NoFavorites))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .favorites
+        .iter()
+        .enumerate()
+        .map(|(i, favorite)| {
+            // The first nine double as digit shortcuts in the main view
+            let number = if i < 9 {
+                format!("{}", i + 1)
+            } else {
+                " ".to_string()
+            };
+            let content = format!("  {}  {}", number, favorite.name);
+
+            let style = if i == app.favorite_selected {
+                app.theme.highlight
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::FavoritesInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
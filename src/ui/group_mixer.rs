@@ -0,0 +1,101 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let Some(group) = app.active_group() else {
+        let message = Paragraph::new(app.messages.get(Msg::GroupMixerNoGroup))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .title(format!(" {} ", app.messages.get(Msg::GroupMixerTitle)))
+                    .title_alignment(Alignment::Center)
+                    .style(app.theme.popup),
+            );
+        frame.render_widget(message, area);
+        return;
+    };
+
+    // Master gauge first, then one per member
+    let mut gauges = vec![(
+        group.name.as_str(),
+        app.group_volumes.get(&group.gid).copied(),
+    )];
+    gauges.extend(group.players.iter().map(|member| {
+        (
+            member.name.as_str(),
+            app.player_states.get(&member.pid).map(|s| s.volume),
+        )
+    }));
+
+    let bars: Vec<Bar> = gauges
+        .iter()
+        .enumerate()
+        .map(|(i, (name, level))| {
+            let style = if i == app.mixer_focus {
+                app.theme.highlight
+            } else if i == 0 {
+                app.theme.accent
+            } else {
+                app.theme.active
+            };
+            Bar::default()
+                .value(level.unwrap_or(0) as u64)
+                .text_value(level.map_or_else(|| "-".to_string(), |l| l.to_string()))
+                .label(Line::from(name.to_string()))
+                .style(style)
+                .value_style(style.add_modifier(Modifier::REVERSED))
+        })
+        .collect();
+
+    // Spread the gauges over the popup width
+    let inner_width = area.width.saturating_sub(2);
+    let count = bars.len() as u16;
+    let bar_gap = 2;
+    let bar_width = (inner_width.saturating_sub(bar_gap * count) / count.max(1)).clamp(3, 12);
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(
+                    " {} ",
+                    app.messages
+                        .format(Msg::GroupMixerTitleGroup, &[&group.name])
+                ))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(bar_gap)
+        .max(100);
+
+    frame.render_widget(chart, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::GroupMixerInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
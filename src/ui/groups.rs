@@ -0,0 +1,92 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 60, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::GroupsTitle)))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    if app.groups.is_empty() {
+        let empty = Paragraph::new(app.messages.get(Msg::NoGroups))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let volume = |level: Option<&u8>| level.map_or_else(|| "-".to_string(), |l| l.to_string());
+
+    // One item per group: the group line, then its leader and members
+    let items: Vec<ListItem> = app
+        .groups
+        .iter()
+        .enumerate()
+        .map(|(i, group)| {
+            let is_highlighted = i == app.group_selected;
+            let is_target = app.group_target == Some(group.gid);
+
+            let prefix = if is_target { "● " } else { "  " };
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else if is_target {
+                app.theme.active
+            } else {
+                Style::default()
+            };
+            let mut lines = vec![Line::styled(
+                format!(
+                    "{}{}  🔊 {}",
+                    prefix,
+                    group.name,
+                    volume(app.group_volumes.get(&group.gid))
+                ),
+                style,
+            )];
+            lines.extend(group.players.iter().map(|member| {
+                let role = if member.role == "leader" { "★" } else { "·" };
+                Line::styled(
+                    format!(
+                        "     {} {:<24} {:>3}",
+                        role,
+                        member.name,
+                        volume(app.player_states.get(&member.pid).map(|s| &s.volume))
+                    ),
+                    app.theme.dim,
+                )
+            }));
+
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
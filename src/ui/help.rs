@@ -1,142 +1,136 @@
 use crate::app::App;
+use crate::i18n::Msg;
 use crate::ui::centered_rect;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-pub fn render(frame: &mut Frame, _app: &App) {
+/// Help sections: heading and (key, description) rows
+const SECTIONS: &[(Msg, &[(&str, Msg)])] = &[
+    (
+        Msg::HelpPlayback,
+        &[
+            ("Space / p", Msg::HelpPlayPause),
+            ("s", Msg::HelpStop),
+            ("n / Ctrl+→", Msg::HelpNextTrack),
+            ("b / Ctrl+←", Msg::HelpPrevTrack),
+            ("Media keys", Msg::HelpMediaKeys),
+            ("1-9", Msg::HelpPlayFavorite),
+            ("t / D", Msg::HelpThumbs),
+            ("Ctrl+r", Msg::HelpRetryPlayback),
+        ],
+    ),
+    (
+        Msg::HelpVolume,
+        &[
+            ("+ / =", Msg::HelpVolumeUp),
+            ("-", Msg::HelpVolumeDown),
+            ("v", Msg::HelpSetVolume),
+            ("]", Msg::HelpAvrVolumeUp),
+            ("[", Msg::HelpAvrVolumeDown),
+            ("Z", Msg::HelpCycleZone),
+            ("m", Msg::HelpToggleMute),
+            ("r", Msg::HelpCycleRepeat),
+            ("z", Msg::HelpToggleShuffle),
+            ("U", Msg::HelpToggleVolumeUnit),
+        ],
+    ),
+    (
+        Msg::HelpAvr,
+        &[
+            ("Ctrl+p", Msg::HelpAvrPower),
+            ("C", Msg::HelpAvrInputs),
+            ("N", Msg::HelpHdmiMonitor),
+            ("a", Msg::HelpSurroundSelector),
+            ("M", Msg::HelpUpmixers),
+            ("V", Msg::HelpAvrSignalInfo),
+            ("S", Msg::HelpAvrPresets),
+            ("Y", Msg::HelpAvrQuickSelect),
+            ("w", Msg::HelpSoundSettings),
+        ],
+    ),
+    (
+        Msg::HelpNavigation,
+        &[
+            ("d", Msg::HelpDeviceSelector),
+            ("F", Msg::HelpFollowPlayback),
+            ("R", Msg::HelpGroups),
+            ("G", Msg::HelpGroupMixer),
+            ("Space J X", Msg::HelpGroupPlayers),
+            ("H", Msg::HelpTransferPlayback),
+            ("I", Msg::HelpPlayerInfo),
+            ("u", Msg::HelpQueueView),
+            ("o", Msg::HelpBrowse),
+            ("/", Msg::HelpSearch),
+            (".", Msg::HelpServiceOptions),
+            ("i", Msg::HelpInputSelector),
+            ("f", Msg::HelpFavorites),
+            ("y", Msg::HelpQuickSelects),
+            ("O", Msg::HelpBookmarks),
+            ("B", Msg::HelpAddBookmark),
+            ("L", Msg::HelpOpenLink),
+            ("I", Msg::HelpNowPlaying),
+            ("T", Msg::HelpStats),
+            ("P", Msg::HelpHistory),
+            ("x", Msg::HelpPluginActions),
+            (":", Msg::HelpCommandMode),
+            ("K", Msg::HelpSignIn),
+            ("e", Msg::HelpExportQueue),
+            ("E", Msg::HelpImportUrls),
+            ("W", Msg::HelpPlayUrl),
+            ("A", Msg::HelpPlayPlaylist),
+            ("Q", Msg::HelpQueuePlaylist),
+            ("g g / g e", Msg::HelpJumpTopBottom),
+            ("g n", Msg::HelpJumpNowPlaying),
+            ("\\ 1-9", Msg::HelpOpenBookmarkNumber),
+            ("?", Msg::HelpShowHelp),
+            ("Esc", Msg::HelpBack),
+            ("F5", Msg::HelpRefresh),
+            ("q / Ctrl+c", Msg::HelpQuit),
+        ],
+    ),
+    (
+        Msg::HelpLists,
+        &[
+            ("↑ / k", Msg::HelpMoveUp),
+            ("↓ / j", Msg::HelpMoveDown),
+            ("Enter", Msg::HelpSelect),
+            ("0-9 Enter", Msg::HelpJump),
+        ],
+    ),
+];
+
+pub fn render(frame: &mut Frame, app: &App) {
     let area = centered_rect(70, 85, frame.area());
 
     // Clear the popup area
     frame.render_widget(Clear, area);
 
-    let help_text = vec![
-        Line::from(Span::styled(
-            "Playback Controls",
-            Style::default().bold().fg(Color::Cyan),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  Space / p  ", Style::default().fg(Color::Yellow)),
-            Span::raw("Play / Pause"),
-        ]),
-        Line::from(vec![
-            Span::styled("  s          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Stop"),
-        ]),
-        Line::from(vec![
-            Span::styled("  n / Ctrl+→ ", Style::default().fg(Color::Yellow)),
-            Span::raw("Next track"),
-        ]),
-        Line::from(vec![
-            Span::styled("  b / Ctrl+← ", Style::default().fg(Color::Yellow)),
-            Span::raw("Previous track"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Volume & Audio",
-            Style::default().bold().fg(Color::Cyan),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  + / =      ", Style::default().fg(Color::Yellow)),
-            Span::raw("Volume up"),
-        ]),
-        Line::from(vec![
-            Span::styled("  -          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Volume down"),
-        ]),
-        Line::from(vec![
-            Span::styled("  m          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Toggle mute"),
-        ]),
-        Line::from(vec![
-            Span::styled("  r          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Cycle repeat (off → all → one)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  z          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Toggle shuffle"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "AVR Controls",
-            Style::default().bold().fg(Color::Cyan),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  a          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Surround mode selector"),
-        ]),
-        Line::from(vec![
-            Span::styled("  w          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Sound settings (bass, treble, etc.)"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Navigation",
-            Style::default().bold().fg(Color::Cyan),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  d          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Device selector"),
-        ]),
-        Line::from(vec![
-            Span::styled("  u          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Queue view"),
-        ]),
-        Line::from(vec![
-            Span::styled("  o          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Browse music sources"),
-        ]),
-        Line::from(vec![
-            Span::styled("  i          ", Style::default().fg(Color::Yellow)),
-            Span::raw("HEOS input selector"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ?          ", Style::default().fg(Color::Yellow)),
-            Span::raw("Show this help"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Esc        ", Style::default().fg(Color::Yellow)),
-            Span::raw("Go back / Close popup"),
-        ]),
-        Line::from(vec![
-            Span::styled("  F5         ", Style::default().fg(Color::Yellow)),
-            Span::raw("Refresh status"),
-        ]),
-        Line::from(vec![
-            Span::styled("  q / Ctrl+c ", Style::default().fg(Color::Yellow)),
-            Span::raw("Quit"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "List Navigation",
-            Style::default().bold().fg(Color::Cyan),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ↑ / k      ", Style::default().fg(Color::Yellow)),
-            Span::raw("Move up"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ↓ / j      ", Style::default().fg(Color::Yellow)),
-            Span::raw("Move down"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Enter      ", Style::default().fg(Color::Yellow)),
-            Span::raw("Select / Apply"),
-        ]),
-    ];
+    let mut help_text = Vec::new();
+    for (i, (heading, rows)) in SECTIONS.iter().enumerate() {
+        if i > 0 {
+            help_text.push(Line::from(""));
+        }
+        help_text.push(Line::from(Span::styled(
+            app.messages.get(*heading),
+            app.theme.accent.bold(),
+        )));
+        help_text.push(Line::from(""));
+        for (key, description) in rows.iter() {
+            help_text.push(Line::from(vec![
+                Span::styled(format!("  {:<11}", key), app.theme.warning),
+                Span::raw(app.messages.get(*description)),
+            ]));
+        }
+    }
 
     let para = Paragraph::new(help_text)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Help ")
+                .title(format!(" {} ", app.messages.get(Msg::HelpTitle)))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().bg(Color::Black)),
+                .style(app.theme.popup),
         )
         .alignment(Alignment::Left);
 
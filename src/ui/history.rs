@@ -0,0 +1,84 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use chrono::Local;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 70, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::HistoryTitle)))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    if app.history.entries.is_empty() {
+        let empty = Paragraph::new(app.messages.get(Msg::NoHistory))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let today = Local::now().date_naive();
+    let items: Vec<ListItem> = app
+        .history
+        .entries
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, entry)| {
+            // Today's plays only need the time
+            let when = match entry.played_at() {
+                Some(t) if t.date_naive() == today => t.format("%H:%M").to_string(),
+                Some(t) => t.format("%b %d").to_string(),
+                None => String::new(),
+            };
+            let icon = if entry.is_station() { "📻" } else { "♪" };
+            let mut spans = vec![
+                Span::styled(format!("  {:>6}  ", when), app.theme.dim),
+                Span::raw(format!("{} {}", icon, entry.title())),
+            ];
+            if !entry.source.is_empty() {
+                spans.push(Span::styled(format!("  {}", entry.source), app.theme.dim));
+            }
+
+            let style = if i == app.history_selected {
+                app.theme.highlight
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Line::from(spans)).style(style)
+        })
+        .collect();
+
+    let mut state = ListState::default().with_selected(Some(app.history_selected));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight);
+
+    frame.render_stateful_widget(list, area, &mut state);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::HistoryInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::i18n::Msg;
 use crate::ui::centered_rect;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
@@ -9,57 +10,48 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Clear the popup area
     frame.render_widget(Clear, area);
 
-    // Common inputs for Denon AVR
-    let common_inputs = vec![
-        ("HDMI 1", "inputs/hdmi_in_1"),
-        ("HDMI 2", "inputs/hdmi_in_2"),
-        ("HDMI 3", "inputs/hdmi_in_3"),
-        ("HDMI 4", "inputs/hdmi_in_4"),
-        ("HDMI 5", "inputs/hdmi_in_5"),
-        ("HDMI 6", "inputs/hdmi_in_6"),
-        ("TV Audio", "inputs/tv_audio"),
-        ("Optical 1", "inputs/optical_in_1"),
-        ("Optical 2", "inputs/optical_in_2"),
-        ("Coax 1", "inputs/coaxial_in_1"),
-        ("Aux 1", "inputs/aux_in_1"),
-        ("Aux 2", "inputs/aux_in_2"),
-        ("Bluetooth", "inputs/bluetooth"),
-        ("Tuner", "inputs/tuner"),
-        ("Phono", "inputs/phono"),
-        ("CD", "inputs/cd"),
-    ];
+    // Inputs of other players are labelled with their name
+    let pid = app.current_pid();
+    let items: Vec<ListItem> = if app.input_sources.is_empty() {
+        vec![ListItem::new(format!("  {}", app.messages.get(Msg::NoInputs))).style(app.theme.dim)]
+    } else {
+        app.input_sources
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let is_highlighted = i == app.input_selected;
 
-    let items: Vec<ListItem> = common_inputs
-        .iter()
-        .enumerate()
-        .map(|(i, (name, _))| {
-            let is_highlighted = i == app.input_selected;
+                let style = if is_highlighted {
+                    app.theme.highlight
+                } else {
+                    Style::default()
+                };
 
-            let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
-            } else {
-                Style::default()
-            };
-
-            ListItem::new(format!("  {}  ", name)).style(style)
-        })
-        .collect();
+                let label = if Some(input.sid) == pid {
+                    input.name.clone()
+                } else {
+                    format!("{} › {}", input.device, input.name)
+                };
+                ListItem::new(format!("  {}  ", label)).style(style)
+            })
+            .collect()
+    };
 
     let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Select Input ")
+                .title(format!(" {} ", app.messages.get(Msg::SelectInputTitle)))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().bg(Color::Black)),
+                .style(app.theme.popup),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
     frame.render_widget(list, area);
 
     // Instructions
-    let instructions = " ↑/↓ Navigate  Enter Select  Esc Cancel ";
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
     let instructions_area = Rect {
         x: area.x,
         y: area.y + area.height - 1,
@@ -68,35 +60,8 @@ pub fn render(frame: &mut Frame, app: &App) {
     };
 
     let instructions_para = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(app.theme.dim)
         .alignment(Alignment::Center);
 
     frame.render_widget(instructions_para, instructions_area);
 }
-
-pub fn get_input_at_index(index: usize) -> Option<&'static str> {
-    let common_inputs = vec![
-        "inputs/hdmi_in_1",
-        "inputs/hdmi_in_2",
-        "inputs/hdmi_in_3",
-        "inputs/hdmi_in_4",
-        "inputs/hdmi_in_5",
-        "inputs/hdmi_in_6",
-        "inputs/tv_audio",
-        "inputs/optical_in_1",
-        "inputs/optical_in_2",
-        "inputs/coaxial_in_1",
-        "inputs/aux_in_1",
-        "inputs/aux_in_2",
-        "inputs/bluetooth",
-        "inputs/tuner",
-        "inputs/phono",
-        "inputs/cd",
-    ];
-
-    common_inputs.get(index).copied()
-}
-
-pub fn input_count() -> usize {
-    16
-}
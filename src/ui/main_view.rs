@@ -1,16 +1,22 @@
 use crate::app::{App, ConnectionState};
-use crate::heos::{MuteState, PlayState, RepeatMode, ShuffleMode};
+use crate::heos::{
+    MuteState, PlayState, RepeatMode, ShuffleMode, SignalField, DISCOVERY_ROUNDS, SEARCH_TARGETS,
+};
+use crate::i18n::Msg;
+use crate::ui::{self, browse};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
+use std::time::Duration;
 
 pub fn render(frame: &mut Frame, app: &App) {
+    let avr_height = if app.avr_panel_expanded { 4 } else { 3 };
     let chunks = Layout::vertical([
-        Constraint::Length(3), // Title bar
-        Constraint::Min(8),    // Now playing
-        Constraint::Length(3), // Volume
-        Constraint::Length(3), // AVR status (surround mode, input)
-        Constraint::Length(3), // Controls
-        Constraint::Length(1), // Status bar
+        Constraint::Length(3),          // Title bar
+        Constraint::Min(8),             // Now playing
+        Constraint::Length(3),          // Volume
+        Constraint::Length(avr_height), // AVR status (surround mode, input, signal)
+        Constraint::Length(3),          // Controls
+        Constraint::Length(1),          // Status bar
     ])
     .split(frame.area());
 
@@ -26,7 +32,7 @@ fn render_title_bar(frame: &mut Frame, app: &App, area: Rect) {
     let player_name = app
         .current_player()
         .map(|p| p.name.as_str())
-        .unwrap_or("No Player");
+        .unwrap_or(app.messages.get(Msg::NoPlayer));
 
     let conn_status = match app.connection_state {
         ConnectionState::Connected => "●",
@@ -34,40 +40,79 @@ fn render_title_bar(frame: &mut Frame, app: &App, area: Rect) {
         ConnectionState::Disconnected => "○",
     };
 
-    let conn_color = match app.connection_state {
-        ConnectionState::Connected => Color::Green,
-        ConnectionState::Discovering => Color::Yellow,
-        ConnectionState::Disconnected => Color::Red,
+    let conn_style = match app.connection_state {
+        ConnectionState::Connected => app.theme.active,
+        ConnectionState::Discovering => app.theme.warning,
+        ConnectionState::Disconnected => app.theme.error,
     };
 
     // AVR connection indicator
     let avr_status = if app.avr_state.connected { "●" } else { "○" };
-    let avr_color = if app.avr_state.connected {
-        Color::Green
+    let avr_style = if app.avr_state.connected {
+        app.theme.active
     } else {
-        Color::DarkGray
+        app.theme.dim
     };
 
-    let title = Line::from(vec![
-        Span::styled(conn_status, Style::default().fg(conn_color)),
+    let mut title = Line::from(vec![
+        Span::styled(conn_status, conn_style),
         Span::raw(" HEOS  "),
-        Span::styled(avr_status, Style::default().fg(avr_color)),
+        Span::styled(avr_status, avr_style),
         Span::raw(" AVR  │  "),
         Span::styled(player_name, Style::default().bold()),
     ]);
+    if let Some(user) = &app.signed_in_user {
+        title.push_span(Span::styled(format!("  │  👤 {}", user), app.theme.dim));
+    }
+    if app.config.ui.follow_playback {
+        title.push_span(Span::styled(
+            format!("  ⇢ {}", app.messages.get(Msg::FollowIndicator)),
+            app.theme.dim,
+        ));
+    }
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(" HEOS TUI ")
         .title_alignment(Alignment::Center);
 
+    // Optional clock and elapsed play time in the top-right corner
+    let mut corner = Vec::new();
+    if app.config.ui.show_elapsed {
+        if let Some(since) = app.now_playing_since {
+            let elapsed = app.last_tick.saturating_duration_since(since);
+            corner.push(format!("⏱ {}", format_elapsed(elapsed)));
+        }
+    }
+    if app.config.ui.show_clock {
+        corner.push(app.clock.format("%H:%M").to_string());
+    }
+    if !corner.is_empty() {
+        let text = format!(" {} ", corner.join("  "));
+        block = block.title(Line::styled(text, app.theme.dim).right_aligned());
+    }
+
     let para = Paragraph::new(title).block(block).alignment(Alignment::Center);
 
     frame.render_widget(para, area);
 }
 
+/// "Song" or "Station" for the media types the UI knows, otherwise the type as sent
+pub fn media_type_label<'a>(app: &'a App, media_type: &'a str) -> &'a str {
+    match media_type {
+        "song" => app.messages.get(Msg::DetailsSong),
+        "station" => app.messages.get(Msg::Station),
+        other => other,
+    }
+}
+
 fn render_now_playing(frame: &mut Frame, app: &App, area: Rect) {
+    if app.connection_state == ConnectionState::Discovering {
+        render_discovery(frame, app, area);
+        return;
+    }
+
     let media = &app.player_state.now_playing;
 
     let play_icon = match app.player_state.play_state {
@@ -78,7 +123,7 @@ fn render_now_playing(frame: &mut Frame, app: &App, area: Rect) {
     };
 
     let song = if media.song.is_empty() {
-        "No media playing"
+        app.messages.get(Msg::NoMediaPlaying)
     } else {
         &media.song
     };
@@ -95,19 +140,47 @@ fn render_now_playing(frame: &mut Frame, app: &App, area: Rect) {
         &media.album
     };
 
+    // The service it's streaming from, since station titles are often ambiguous,
+    // followed by what kind of media it is and its quality where reported
+    let mut source_parts = Vec::new();
+    if let Some(source) = app.now_playing_source() {
+        source_parts.push(format!(
+            "{} {}",
+            browse::source_icon(source),
+            app.messages.format(Msg::ViaSource, &[&source.name])
+        ));
+    }
+    if !media.media_type.is_empty() {
+        source_parts.push(media_type_label(app, &media.media_type).to_string());
+    }
+    if let Some(quality) = media.quality_text() {
+        source_parts.push(quality);
+    }
+    let mut source_line = Line::styled(source_parts.join(" · "), app.theme.dim);
+    // Only rateable stations get the thumbs keys
+    if app.can_rate_track() {
+        source_line.push_span(Span::styled(
+            format!("  {}", app.messages.get(Msg::ThumbsHint)),
+            app.theme.accent,
+        ));
+    }
+
     let lines = vec![
         Line::from(vec![
-            Span::styled(play_icon, Style::default().fg(Color::Cyan)),
+            Span::styled(play_icon, app.theme.accent),
             Span::raw(" "),
-            Span::styled(song, Style::default().bold().fg(Color::White)),
+            Span::styled(song, app.theme.text.bold()),
         ]),
-        Line::from(""),
+        source_line,
         Line::from(vec![
-            Span::styled("Artist: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{}: ", app.messages.get(Msg::Artist)),
+                app.theme.dim,
+            ),
             Span::raw(artist),
         ]),
         Line::from(vec![
-            Span::styled("Album:  ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{}: ", app.messages.get(Msg::Album)), app.theme.dim),
             Span::raw(album),
         ]),
     ];
@@ -116,7 +189,10 @@ fn render_now_playing(frame: &mut Frame, app: &App, area: Rect) {
     let mut display_lines = lines;
     if !media.station.is_empty() {
         display_lines.push(Line::from(vec![
-            Span::styled("Station: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{}: ", app.messages.get(Msg::Station)),
+                app.theme.dim,
+            ),
             Span::raw(&media.station),
         ]));
     }
@@ -124,12 +200,110 @@ fn render_now_playing(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .title(" Now Playing ")
+        .title(format!(" {} ", app.messages.get(Msg::NowPlayingTitle)))
         .title_alignment(Alignment::Left);
 
     let para = Paragraph::new(display_lines).block(block);
 
     frame.render_widget(para, area);
+
+    // Progress bar on the block's last inner row
+    if let Some((position, duration)) = app.playback_progress() {
+        if area.height > 2 && area.width > 2 {
+            let bar_area = Rect {
+                x: area.x + 1,
+                y: area.y + area.height - 2,
+                width: area.width - 2,
+                height: 1,
+            };
+            frame.render_widget(
+                progress_line(app, position, duration, bar_area.width),
+                bar_area,
+            );
+        }
+    }
+}
+
+/// `1:23 ━━━━━━━────── 3:45`, filling the given width
+fn progress_line(app: &App, position: Duration, duration: Duration, width: u16) -> Line<'static> {
+    let elapsed = format_elapsed(position);
+    let total = format_elapsed(duration);
+    let bar_width = (width as usize).saturating_sub(elapsed.len() + total.len() + 2);
+    let filled = if duration.is_zero() {
+        0
+    } else {
+        (bar_width as f64 * position.as_secs_f64() / duration.as_secs_f64()).round() as usize
+    }
+    .min(bar_width);
+
+    Line::from(vec![
+        Span::styled(format!("{} ", elapsed), app.theme.dim),
+        Span::styled("━".repeat(filled), app.theme.accent),
+        Span::styled("─".repeat(bar_width - filled), app.theme.dim),
+        Span::styled(format!(" {}", total), app.theme.dim),
+    ])
+}
+
+/// Startup discovery progress, in place of Now Playing until a device is found
+fn render_discovery(frame: &mut Frame, app: &App, area: Rect) {
+    let discovery = &app.discovery;
+    let elapsed = app.last_tick.saturating_duration_since(discovery.started);
+
+    let mut lines = vec![
+        Line::styled(
+            app.messages.format(
+                Msg::DiscoverySearching,
+                &[
+                    &format_elapsed(elapsed),
+                    &discovery.round.max(1),
+                    &DISCOVERY_ROUNDS,
+                ],
+            ),
+            app.theme.warning,
+        ),
+        Line::from(""),
+        Line::styled(app.messages.get(Msg::DiscoverySearchTargets), app.theme.dim),
+    ];
+    for target in SEARCH_TARGETS {
+        let (mark, style) = if discovery.targets_sent.contains(target) {
+            ("✓", app.theme.active)
+        } else {
+            ("·", app.theme.dim)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", mark), style),
+            Span::raw(*target),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    let responders = if discovery.responders.is_empty() {
+        Span::styled(app.messages.get(Msg::DiscoveryNoResponders), app.theme.dim)
+    } else {
+        Span::raw(discovery.responders.join(", "))
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("{} ", app.messages.get(Msg::DiscoveryResponders)),
+            app.theme.dim,
+        ),
+        responders,
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        app.messages.get(Msg::DiscoveryCancelHint),
+        app.theme.dim,
+    ));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::DiscoveryTitle)))
+        .title_alignment(Alignment::Left);
+
+    let para = Paragraph::new(lines).block(block);
+
+    frame.render_widget(para, area);
 }
 
 fn render_volume(frame: &mut Frame, app: &App, area: Rect) {
@@ -137,31 +311,32 @@ fn render_volume(frame: &mut Frame, app: &App, area: Rect) {
     let is_muted = app.player_state.mute == MuteState::On;
 
     let mute_indicator = if is_muted {
-        Span::styled(" 🔇 MUTED ", Style::default().fg(Color::Red))
+        Span::styled(
+            format!(" 🔇 {} ", app.messages.get(Msg::Muted)),
+            app.theme.error,
+        )
     } else {
-        Span::styled(" 🔊 ", Style::default().fg(Color::Green))
+        Span::styled(" 🔊 ".to_string(), app.theme.active)
     };
 
-    let volume_text = format!("{}%", volume);
+    let volume_text = app.heos_volume_text();
 
     let gauge = Gauge::default()
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Volume "),
-        )
-        .gauge_style(
-            Style::default()
-                .fg(if is_muted { Color::DarkGray } else { Color::Cyan })
-                .bg(Color::Black),
+                .title(format!(" {} ", app.messages.get(Msg::VolumeTitle))),
         )
+        .gauge_style(if is_muted {
+            app.theme.gauge_muted
+        } else {
+            app.theme.gauge()
+        })
         .percent(volume as u16)
         .label(Span::styled(
             volume_text,
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+            app.theme.text.add_modifier(Modifier::BOLD),
         ));
 
     let chunks = Layout::horizontal([Constraint::Length(12), Constraint::Min(0)]).split(area);
@@ -188,28 +363,67 @@ fn render_avr_status(frame: &mut Frame, app: &App, area: Rect) {
     let input = if app.avr_state.input_source.is_empty() {
         "---".to_string()
     } else {
-        app.avr_state.input_source.clone()
+        app.avr_input_name(&app.avr_state.input_source).to_string()
     };
 
-    let avr_vol = format!("{}dB", app.avr_state.master_volume as i32 - 80);
-
-    let content = Line::from(vec![
-        Span::styled("[a]", Style::default().fg(Color::DarkGray)),
-        Span::raw(" Surround: "),
-        Span::styled(&surround, Style::default().fg(Color::Cyan)),
+    let avr_vol = app.avr_volume_text();
+
+    let mut status = Vec::new();
+    if app.avr_state.connected {
+        let (power, style) = if app.avr_state.power {
+            (Msg::SettingOn, app.theme.active)
+        } else {
+            (Msg::AvrPowerStandby, app.theme.warning)
+        };
+        status.push(Span::styled(
+            format!("⏻ {}", app.messages.get(power)),
+            style,
+        ));
+        if app.avr_state.http_fallback {
+            status.push(Span::styled(" HTTP", app.theme.dim));
+        }
+        status.push(Span::raw("  │  "));
+    }
+    if app.avr_state.headphones {
+        status.push(Span::styled("🎧 ", app.theme.accent));
+    }
+    status.extend([
+        Span::styled("[a]", app.theme.dim),
+        Span::raw(format!(" {}: ", app.messages.get(Msg::Surround))),
+        Span::styled(&surround, app.theme.accent),
         Span::raw("  │  "),
-        Span::styled("[w]", Style::default().fg(Color::DarkGray)),
-        Span::raw(" Sound  │  Input: "),
-        Span::styled(&input, Style::default().fg(Color::Yellow)),
-        Span::raw("  │  AVR Vol: "),
-        Span::styled(&avr_vol, Style::default().fg(Color::Green)),
+        Span::styled("[w]", app.theme.dim),
+        Span::raw(format!(" {}  │  ", app.messages.get(Msg::Sound))),
+        Span::styled("[C]", app.theme.dim),
+        Span::raw(format!(" {}: ", app.messages.get(Msg::Input))),
+        Span::styled(&input, app.theme.warning),
+        Span::raw(format!("  │  {}: ", app.messages.get(Msg::AvrVolume))),
+        Span::styled(&avr_vol, app.theme.active),
     ]);
+    if let Some(max) = app.avr_state.max_volume {
+        let max = ui::volume::avr(max, app.config.ui.avr_volume_unit);
+        status.push(Span::styled(
+            format!(" ({})", app.messages.format(Msg::AvrVolumeMax, &[&max])),
+            app.theme.dim,
+        ));
+    }
+    if app.sleep_timer_remaining().is_some() {
+        status.extend([
+            Span::raw("  │  ⏾ "),
+            Span::styled(ui::sleep_timer::remaining_text(app), app.theme.warning),
+        ]);
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(" AVR ");
 
+    let mut content = vec![Line::from(status)];
+    if app.avr_panel_expanded {
+        content.push(signal_line(app));
+    }
+
     let para = Paragraph::new(content)
         .block(block)
         .alignment(Alignment::Center);
@@ -217,6 +431,38 @@ fn render_avr_status(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(para, area);
 }
 
+/// Audio and video signal details for the expanded AVR panel
+fn signal_line(app: &App) -> Line<'static> {
+    let info = &app.avr_state.signal_info;
+    let field = |field| info.get(&field).map(String::as_str).unwrap_or("---");
+
+    // Sample rate comes in tenths of kHz: "480" is 48 kHz, "441" is 44.1 kHz
+    let sample_rate = info
+        .get(&SignalField::SampleRate)
+        .and_then(|rate| rate.parse::<f32>().ok())
+        .map(|rate| format!(" {} kHz", rate / 10.0))
+        .unwrap_or_default();
+
+    let mut video = format!(
+        "{} → {}",
+        field(SignalField::VideoInput),
+        field(SignalField::VideoOutput)
+    );
+    if let Some(hdr) = info.get(&SignalField::Hdr) {
+        video.push_str(&format!("  {}", hdr));
+    }
+
+    Line::from(vec![
+        Span::raw(format!("{}: ", app.messages.get(Msg::SignalAudio))),
+        Span::styled(
+            format!("{}{}", field(SignalField::AudioSignal), sample_rate),
+            app.theme.accent,
+        ),
+        Span::raw(format!("  │  {}: ", app.messages.get(Msg::SignalVideo))),
+        Span::styled(video, app.theme.accent),
+    ])
+}
+
 fn render_controls(frame: &mut Frame, app: &App, area: Rect) {
     let repeat_icon = match app.player_state.repeat {
         RepeatMode::Off => "↻",
@@ -224,9 +470,9 @@ fn render_controls(frame: &mut Frame, app: &App, area: Rect) {
         RepeatMode::OnOne => "🔂",
     };
 
-    let repeat_color = match app.player_state.repeat {
-        RepeatMode::Off => Color::DarkGray,
-        _ => Color::Green,
+    let repeat_style = match app.player_state.repeat {
+        RepeatMode::Off => app.theme.dim,
+        _ => app.theme.active,
     };
 
     let shuffle_icon = if app.player_state.shuffle == ShuffleMode::On {
@@ -235,33 +481,33 @@ fn render_controls(frame: &mut Frame, app: &App, area: Rect) {
         "⇉"
     };
 
-    let shuffle_color = if app.player_state.shuffle == ShuffleMode::On {
-        Color::Green
+    let shuffle_style = if app.player_state.shuffle == ShuffleMode::On {
+        app.theme.active
     } else {
-        Color::DarkGray
+        app.theme.dim
     };
 
     let controls = Line::from(vec![
-        Span::styled("[b]", Style::default().fg(Color::DarkGray)),
+        Span::styled("[b]", app.theme.dim),
         Span::raw(" ⏮ "),
-        Span::styled("[p]", Style::default().fg(Color::DarkGray)),
+        Span::styled("[p]", app.theme.dim),
         Span::raw(" ⏯ "),
-        Span::styled("[n]", Style::default().fg(Color::DarkGray)),
+        Span::styled("[n]", app.theme.dim),
         Span::raw(" ⏭  │  "),
-        Span::styled("[r]", Style::default().fg(Color::DarkGray)),
+        Span::styled("[r]", app.theme.dim),
         Span::raw(" "),
-        Span::styled(repeat_icon, Style::default().fg(repeat_color)),
+        Span::styled(repeat_icon, repeat_style),
         Span::raw("  "),
-        Span::styled("[z]", Style::default().fg(Color::DarkGray)),
+        Span::styled("[z]", app.theme.dim),
         Span::raw(" "),
-        Span::styled(shuffle_icon, Style::default().fg(shuffle_color)),
+        Span::styled(shuffle_icon, shuffle_style),
         Span::raw("  │  "),
-        Span::styled("[d]", Style::default().fg(Color::DarkGray)),
-        Span::raw(" Devices  "),
-        Span::styled("[u]", Style::default().fg(Color::DarkGray)),
-        Span::raw(" Queue  "),
-        Span::styled("[?]", Style::default().fg(Color::DarkGray)),
-        Span::raw(" Help"),
+        Span::styled("[d]", app.theme.dim),
+        Span::raw(format!(" {}  ", app.messages.get(Msg::Devices))),
+        Span::styled("[u]", app.theme.dim),
+        Span::raw(format!(" {}  ", app.messages.get(Msg::Queue))),
+        Span::styled("[?]", app.theme.dim),
+        Span::raw(format!(" {}", app.messages.get(Msg::Help))),
     ]);
 
     let block = Block::default()
@@ -276,14 +522,56 @@ fn render_controls(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
-    let status = app
-        .status_message
-        .as_deref()
-        .unwrap_or("Press ? for help");
+    // What the volume/transport keys act on: HEOS player and AVR zone
+    let player_name = app
+        .current_player()
+        .map(|p| p.name.as_str())
+        .unwrap_or(app.messages.get(Msg::NoPlayer));
 
-    let para = Paragraph::new(status)
-        .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
+    let mut target = Line::from(vec![
+        Span::styled(" ▸ ", app.theme.dim),
+        Span::styled(player_name, app.theme.accent),
+        Span::styled(" │ [Z] ", app.theme.dim),
+        Span::styled(app.avr_state.zone.display_name(), app.theme.accent),
+    ]);
+    // Zone 2/3 show their own level, since the AVR panel shows the main zone's
+    if let Some(zone) = app.avr_state.zones.get(&app.avr_state.zone) {
+        let level = match (zone.power, zone.volume) {
+            (false, _) => app.messages.get(Msg::SettingOff).to_string(),
+            (true, Some(vol)) => ui::volume::avr(vol, app.config.ui.avr_volume_unit),
+            (true, None) => "--".to_string(),
+        };
+        target.push_span(Span::styled(format!(" {}", level), app.theme.dim));
+    }
+    target.push_span(Span::raw(" "));
 
-    frame.render_widget(para, area);
+    let chunks = Layout::horizontal([
+        Constraint::Length(target.width() as u16),
+        Constraint::Min(0),
+    ])
+    .split(area);
+
+    let para = match ui::notice(app) {
+        Some((text, style)) => Paragraph::new(text).style(style),
+        None => {
+            let status = app
+                .status_message
+                .as_deref()
+                .unwrap_or(app.messages.get(Msg::StatusHint));
+            Paragraph::new(status).style(app.theme.dim)
+        }
+    }
+    .alignment(Alignment::Center);
+
+    frame.render_widget(Paragraph::new(target), chunks[0]);
+    frame.render_widget(para, chunks[1]);
+}
+
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
 }
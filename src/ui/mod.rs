@@ -1,11 +1,38 @@
+pub mod audyssey;
+pub mod avr_inputs;
+pub mod avr_presets;
+pub mod avr_quick_select;
+pub mod bookmarks;
 pub mod browse;
+pub mod browse_menu;
+pub mod compression;
+pub mod confirm;
 pub mod devices;
+pub mod dynamic_volume;
+pub mod favorites;
+pub mod group_mixer;
+pub mod groups;
+pub mod hdmi_monitor;
 pub mod help;
+pub mod history;
 pub mod inputs;
 pub mod main_view;
+pub mod now_playing;
+pub mod player_info;
+pub mod plugin_actions;
+pub mod prompt;
 pub mod queue;
+pub mod quickselects;
+pub mod search;
+pub mod service_options;
+pub mod sleep_timer;
 pub mod sound_settings;
+pub mod stats;
 pub mod surround;
+pub mod theme;
+pub mod tone_slider;
+pub mod upmixer;
+pub mod volume;
 
 use crate::app::{App, View};
 use ratatui::prelude::*;
@@ -19,6 +46,26 @@ pub fn render(frame: &mut Frame, app: &App) {
         }
         View::Queue => queue::render(frame, app),
         View::Browse => browse::render(frame, app),
+        View::BrowseMenu => {
+            browse::render(frame, app);
+            browse_menu::render(frame, app);
+        }
+        View::ServiceOptions => {
+            browse::render(frame, app);
+            service_options::render(frame, app);
+        }
+        View::SearchCriteria => {
+            browse::render(frame, app);
+            search::render(frame, app);
+        }
+        View::HdmiMonitor => {
+            main_view::render(frame, app);
+            hdmi_monitor::render(frame, app);
+        }
+        View::AvrInputs => {
+            main_view::render(frame, app);
+            avr_inputs::render(frame, app);
+        }
         View::Inputs => {
             main_view::render(frame, app);
             inputs::render(frame, app);
@@ -27,15 +74,106 @@ pub fn render(frame: &mut Frame, app: &App) {
             main_view::render(frame, app);
             surround::render(frame, app);
         }
+        View::Upmixers => {
+            main_view::render(frame, app);
+            upmixer::render(frame, app);
+        }
+        View::Groups => {
+            main_view::render(frame, app);
+            groups::render(frame, app);
+        }
+        View::GroupMixer => {
+            main_view::render(frame, app);
+            group_mixer::render(frame, app);
+        }
+        View::AvrPresets => {
+            main_view::render(frame, app);
+            avr_presets::render(frame, app);
+        }
+        View::AvrQuickSelect => {
+            main_view::render(frame, app);
+            avr_quick_select::render(frame, app);
+        }
         View::SoundSettings => {
             main_view::render(frame, app);
             sound_settings::render(frame, app);
         }
+        View::DynamicCompression => {
+            main_view::render(frame, app);
+            compression::render(frame, app);
+        }
+        View::ToneSlider => {
+            main_view::render(frame, app);
+            tone_slider::render(frame, app);
+        }
+        View::Audyssey => {
+            main_view::render(frame, app);
+            audyssey::render(frame, app);
+        }
+        View::DynamicVolume => {
+            main_view::render(frame, app);
+            audyssey::render(frame, app);
+            dynamic_volume::render(frame, app);
+        }
+        View::SleepTimer => {
+            main_view::render(frame, app);
+            sleep_timer::render(frame, app);
+        }
+        View::Bookmarks => {
+            main_view::render(frame, app);
+            bookmarks::render(frame, app);
+        }
+        View::PluginActions => {
+            main_view::render(frame, app);
+            plugin_actions::render(frame, app);
+        }
+        View::NowPlaying => {
+            main_view::render(frame, app);
+            now_playing::render(frame, app);
+        }
+        View::PlayerInfo => {
+            main_view::render(frame, app);
+            devices::render(frame, app);
+            player_info::render(frame, app);
+        }
+        View::QuickSelects => {
+            main_view::render(frame, app);
+            quickselects::render(frame, app);
+        }
+        View::Favorites => {
+            main_view::render(frame, app);
+            favorites::render(frame, app);
+        }
+        View::Stats => {
+            main_view::render(frame, app);
+            stats::render(frame, app);
+        }
+        View::History => {
+            main_view::render(frame, app);
+            history::render(frame, app);
+        }
         View::Help => {
             main_view::render(frame, app);
             help::render(frame, app);
         }
     }
+
+    if let Some(confirm) = app.confirm {
+        confirm::render(frame, app, confirm);
+    }
+
+    if let Some(prompt) = &app.prompt {
+        prompt::render(frame, app, prompt);
+    }
+}
+
+/// A pending key sequence or toast, shown in place of the status/instructions line
+pub fn notice(app: &App) -> Option<(String, Style)> {
+    match (app.chord.pending(), app.active_toast()) {
+        (Some(keys), _) => Some((format!(" {} … ", keys), app.theme.warning)),
+        (None, Some(toast)) => Some((format!(" {} ", toast), app.theme.accent)),
+        (None, None) => None,
+    }
 }
 
 pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
@@ -0,0 +1,66 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use crate::ui::main_view::media_type_label;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let media = &app.player_state.now_playing;
+    let source = app
+        .now_playing_source()
+        .map(|s| s.name.as_str())
+        .unwrap_or_else(|| app.messages.get(Msg::Unknown));
+
+    let sid = format!("{} ({})", media.sid, source);
+    let qid = media.qid.to_string();
+    let quality = media.quality_text().unwrap_or_default();
+    let fields: [(Msg, &str); 11] = [
+        (Msg::DetailsSong, &media.song),
+        (Msg::Artist, &media.artist),
+        (Msg::Album, &media.album),
+        (Msg::Station, &media.station),
+        (Msg::DetailsType, media_type_label(app, &media.media_type)),
+        (Msg::DetailsSource, &sid),
+        (Msg::DetailsQuality, &quality),
+        (Msg::DetailsQid, &qid),
+        (Msg::DetailsMid, &media.mid),
+        (Msg::DetailsImage, &media.image_url),
+        (
+            Msg::DetailsPlayer,
+            app.current_player().map(|p| p.name.as_str()).unwrap_or("-"),
+        ),
+    ];
+
+    let lines: Vec<Line> = fields
+        .iter()
+        .map(|(label, value)| {
+            let value = if value.is_empty() { "-" } else { value };
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<10}", app.messages.get(*label)),
+                    app.theme.warning,
+                ),
+                Span::styled(value.to_string(), app.theme.text),
+            ])
+        })
+        .collect();
+
+    let para = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", app.messages.get(Msg::DetailsTitle)))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(para, area);
+}
@@ -0,0 +1,64 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(player) = &app.player_info else {
+        return;
+    };
+    let area = centered_rect(50, 40, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let network = match player.network.as_str() {
+        "wired" => app.messages.get(Msg::NetworkWired),
+        "wifi" => app.messages.get(Msg::NetworkWifi),
+        other => other,
+    };
+    let lineout = match player.lineout {
+        1 => app.messages.get(Msg::LineoutVariable),
+        2 => app.messages.get(Msg::LineoutFixed),
+        _ => "",
+    };
+    let pid = player.pid.to_string();
+    let fields: [(Msg, &str); 8] = [
+        (Msg::PlayerInfoName, &player.name),
+        (Msg::PlayerInfoModel, &player.model),
+        (Msg::PlayerInfoVersion, &player.version),
+        (Msg::PlayerInfoIp, &player.ip),
+        (Msg::PlayerInfoNetwork, network),
+        (Msg::PlayerInfoSerial, &player.serial),
+        (Msg::PlayerInfoLineout, lineout),
+        (Msg::PlayerInfoPid, &pid),
+    ];
+
+    let lines: Vec<Line> = fields
+        .iter()
+        .map(|(label, value)| {
+            let value = if value.is_empty() { "-" } else { value };
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<10}", app.messages.get(*label)),
+                    app.theme.warning,
+                ),
+                Span::styled(value.to_string(), app.theme.text),
+            ])
+        })
+        .collect();
+
+    let para = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", app.messages.get(Msg::PlayerInfoTitle)))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(para, area);
+}
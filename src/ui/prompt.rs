@@ -0,0 +1,43 @@
+use crate::app::{App, Prompt};
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App, prompt: &Prompt) {
+    let popup = centered_rect(60, 20, frame.area());
+    let area = Rect {
+        height: popup.height.min(4),
+        ..popup
+    };
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(prompt.kind.title())))
+        .title_alignment(Alignment::Center)
+        .title_bottom(
+            Line::styled(
+                format!(" {} ", app.messages.get(Msg::PromptInstructions)),
+                app.theme.dim,
+            )
+            .centered(),
+        )
+        .style(app.theme.popup);
+
+    let text = if prompt.kind.is_secret() {
+        "•".repeat(prompt.input.chars().count())
+    } else {
+        prompt.input.clone()
+    };
+    let input = Line::from(vec![
+        Span::raw(" "),
+        Span::styled(text, app.theme.text),
+        Span::styled("█", app.theme.accent),
+    ]);
+
+    frame.render_widget(Paragraph::new(input).block(block), area);
+}
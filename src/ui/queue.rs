@@ -1,6 +1,10 @@
 use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui;
+use crate::ui::main_view::format_elapsed;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
+use std::time::Duration;
 
 pub fn render(frame: &mut Frame, app: &App) {
     let chunks = Layout::vertical([
@@ -11,7 +15,18 @@ pub fn render(frame: &mut Frame, app: &App) {
     .split(frame.area());
 
     // Header
-    let header = Paragraph::new(format!(" Queue ({} items)", app.queue.len()))
+    let header_text = match app.queue_durations() {
+        Some((total, remaining)) => app.messages.format(
+            Msg::QueueHeaderDurations,
+            &[
+                &app.queue.len(),
+                &format_span(total),
+                &format_span(remaining),
+            ],
+        ),
+        None => app.messages.format(Msg::QueueHeader, &[&app.queue.len()]),
+    };
+    let header = Paragraph::new(format!(" {}", header_text))
         .style(Style::default().bold())
         .block(
             Block::default()
@@ -32,18 +47,16 @@ pub fn render(frame: &mut Frame, app: &App) {
             let is_current = app.player_state.now_playing.qid == item.qid;
 
             let prefix = if is_current { "▶ " } else { "  " };
-            let content = format!(
-                "{}{:3}. {} - {}",
-                prefix,
-                i + 1,
-                item.song,
-                item.artist
-            );
+            let mut content = format!("{}{:3}. {} - {}", prefix, i + 1, item.song, item.artist);
+            if item.duration > 0 {
+                let length = Duration::from_millis(item.duration);
+                content.push_str(&format!("  ({})", format_elapsed(length)));
+            }
 
             let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                app.theme.highlight
             } else if is_current {
-                Style::default().fg(Color::Cyan)
+                app.theme.accent
             } else {
                 Style::default()
             };
@@ -57,18 +70,34 @@ pub fn render(frame: &mut Frame, app: &App) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Queue ")
+                .title(format!(" {} ", app.messages.get(Msg::Queue)))
                 .title_alignment(Alignment::Left),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
-    frame.render_widget(list, chunks[1]);
+    // Keep the selection in view on long queues
+    let mut state = ListState::default().with_selected(Some(app.queue_selected));
+    frame.render_stateful_widget(list, chunks[1], &mut state);
 
     // Instructions
-    let instructions = " ↑/↓ Navigate  Enter Play  Esc Back  c Clear queue ";
-    let instructions_para = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
+    let instructions_para = match ui::notice(app) {
+        Some((text, style)) => Paragraph::new(text).style(style),
+        None => {
+            let instructions = format!(" {} ", app.messages.get(Msg::QueueInstructions));
+            Paragraph::new(instructions).style(app.theme.dim)
+        }
+    }
+    .alignment(Alignment::Center);
 
     frame.render_widget(instructions_para, chunks[2]);
 }
+
+/// A rough length like `1h 23m` or `42m`
+fn format_span(span: Duration) -> String {
+    let minutes = (span.as_secs() + 30) / 60;
+    if minutes >= 60 {
+        format!("{}h {}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
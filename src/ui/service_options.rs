@@ -0,0 +1,64 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let popup = centered_rect(40, 40, frame.area());
+    // Fit the options plus borders and the instructions line
+    let area = Rect {
+        height: popup.height.min(app.service_options.len() as u16 + 3),
+        ..popup
+    };
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let title = app.browse_items.get(app.browse_selected).map_or_else(
+        || app.messages.get(Msg::BrowseTitle).to_string(),
+        |item| item.name.clone(),
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", title))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    let items: Vec<ListItem> = app
+        .service_options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            let style = if i == app.service_option_selected {
+                app.theme.highlight
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(format!("  {}", option.name)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::ServiceOptionsInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
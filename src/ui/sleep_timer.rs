@@ -0,0 +1,98 @@
+use crate::app::App;
+use crate::heos::SLEEP_TIMER_MINUTES;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(40, 40, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let current = app.avr_state.sleep_timer.map(|(minutes, _)| minutes);
+
+    let items: Vec<ListItem> = options()
+        .enumerate()
+        .map(|(i, minutes)| {
+            let is_highlighted = i == app.sleep_timer_selected;
+            let is_current = current == minutes;
+
+            let prefix = if is_current { "● " } else { "  " };
+            let content = format!("{}{}", prefix, option_name(app, minutes));
+
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else if is_current {
+                app.theme.active
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(
+                    " {} ",
+                    app.messages
+                        .format(Msg::SleepTimerTitle, &[&remaining_text(app)])
+                ))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
+
+/// Time left on the sleep timer in whole minutes, or "Off"
+pub fn remaining_text(app: &App) -> String {
+    match app.sleep_timer_remaining() {
+        Some(left) => app
+            .messages
+            .format(Msg::SleepTimerMinutes, &[&left.as_secs().div_ceil(60)]),
+        None => app.messages.get(Msg::SettingOff).to_string(),
+    }
+}
+
+pub fn option_name(app: &App, minutes: Option<u16>) -> String {
+    match minutes {
+        Some(minutes) => app.messages.format(Msg::SleepTimerMinutes, &[&minutes]),
+        None => app.messages.get(Msg::SettingOff).to_string(),
+    }
+}
+
+/// Off first, then the offered lengths
+fn options() -> impl Iterator<Item = Option<u16>> {
+    std::iter::once(None).chain(SLEEP_TIMER_MINUTES.iter().copied().map(Some))
+}
+
+pub fn get_option_at_index(index: usize) -> Option<Option<u16>> {
+    options().nth(index)
+}
+
+pub fn option_count() -> usize {
+    SLEEP_TIMER_MINUTES.len() + 1
+}
@@ -1,57 +1,106 @@
 use crate::app::App;
-use crate::ui::centered_rect;
+use crate::i18n::Msg;
+use crate::ui::{self, centered_rect};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SoundSetting {
-    BassUp,
-    BassDown,
-    TrebleUp,
-    TrebleDown,
+    Bass,
+    Treble,
     SubwooferUp,
     SubwooferDown,
-    DynamicEq,
+    LfeUp,
+    LfeDown,
+    BassSyncUp,
+    BassSyncDown,
+    LoudnessManagement,
+    CinemaEq,
+    Lfc,
+    LfcAmountUp,
+    LfcAmountDown,
+    DynamicCompression,
+    Restorer,
+    Imax,
+    Eco,
+    Audyssey,
     DialogEnhancer,
+    SleepTimer,
 }
 
 impl SoundSetting {
     pub fn all() -> &'static [SoundSetting] {
         &[
-            SoundSetting::BassUp,
-            SoundSetting::BassDown,
-            SoundSetting::TrebleUp,
-            SoundSetting::TrebleDown,
+            SoundSetting::Bass,
+            SoundSetting::Treble,
             SoundSetting::SubwooferUp,
             SoundSetting::SubwooferDown,
-            SoundSetting::DynamicEq,
+            SoundSetting::LfeUp,
+            SoundSetting::LfeDown,
+            SoundSetting::BassSyncUp,
+            SoundSetting::BassSyncDown,
+            SoundSetting::LoudnessManagement,
+            SoundSetting::CinemaEq,
+            SoundSetting::Lfc,
+            SoundSetting::LfcAmountUp,
+            SoundSetting::LfcAmountDown,
+            SoundSetting::DynamicCompression,
+            SoundSetting::Restorer,
+            SoundSetting::Imax,
+            SoundSetting::Eco,
+            SoundSetting::Audyssey,
             SoundSetting::DialogEnhancer,
+            SoundSetting::SleepTimer,
         ]
     }
 
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> Msg {
         match self {
-            SoundSetting::BassUp => "Bass +",
-            SoundSetting::BassDown => "Bass -",
-            SoundSetting::TrebleUp => "Treble +",
-            SoundSetting::TrebleDown => "Treble -",
-            SoundSetting::SubwooferUp => "Subwoofer +",
-            SoundSetting::SubwooferDown => "Subwoofer -",
-            SoundSetting::DynamicEq => "Dynamic EQ Toggle",
-            SoundSetting::DialogEnhancer => "Dialog Enhancer",
+            SoundSetting::Bass => Msg::Bass,
+            SoundSetting::Treble => Msg::Treble,
+            SoundSetting::SubwooferUp => Msg::SubwooferUp,
+            SoundSetting::SubwooferDown => Msg::SubwooferDown,
+            SoundSetting::LfeUp => Msg::LfeUp,
+            SoundSetting::LfeDown => Msg::LfeDown,
+            SoundSetting::BassSyncUp => Msg::BassSyncUp,
+            SoundSetting::BassSyncDown => Msg::BassSyncDown,
+            SoundSetting::LoudnessManagement => Msg::LoudnessManagement,
+            SoundSetting::CinemaEq => Msg::CinemaEq,
+            SoundSetting::Lfc => Msg::Lfc,
+            SoundSetting::LfcAmountUp => Msg::LfcAmountUp,
+            SoundSetting::LfcAmountDown => Msg::LfcAmountDown,
+            SoundSetting::DynamicCompression => Msg::DynamicCompression,
+            SoundSetting::Restorer => Msg::Restorer,
+            SoundSetting::Imax => Msg::Imax,
+            SoundSetting::Eco => Msg::Eco,
+            SoundSetting::Audyssey => Msg::Audyssey,
+            SoundSetting::DialogEnhancer => Msg::DialogEnhancer,
+            SoundSetting::SleepTimer => Msg::SleepTimer,
         }
     }
 
-    pub fn description(&self) -> &'static str {
+    pub fn description(&self) -> Msg {
         match self {
-            SoundSetting::BassUp => "Increase bass level",
-            SoundSetting::BassDown => "Decrease bass level",
-            SoundSetting::TrebleUp => "Increase treble level",
-            SoundSetting::TrebleDown => "Decrease treble level",
-            SoundSetting::SubwooferUp => "Increase subwoofer level",
-            SoundSetting::SubwooferDown => "Decrease subwoofer level",
-            SoundSetting::DynamicEq => "Toggle Audyssey Dynamic EQ",
-            SoundSetting::DialogEnhancer => "Enhance dialog clarity",
+            SoundSetting::Bass => Msg::BassDesc,
+            SoundSetting::Treble => Msg::TrebleDesc,
+            SoundSetting::SubwooferUp => Msg::SubwooferUpDesc,
+            SoundSetting::SubwooferDown => Msg::SubwooferDownDesc,
+            SoundSetting::LfeUp => Msg::LfeUpDesc,
+            SoundSetting::LfeDown => Msg::LfeDownDesc,
+            SoundSetting::BassSyncUp => Msg::BassSyncUpDesc,
+            SoundSetting::BassSyncDown => Msg::BassSyncDownDesc,
+            SoundSetting::LoudnessManagement => Msg::LoudnessManagementDesc,
+            SoundSetting::CinemaEq => Msg::CinemaEqDesc,
+            SoundSetting::Lfc => Msg::LfcDesc,
+            SoundSetting::LfcAmountUp => Msg::LfcAmountUpDesc,
+            SoundSetting::LfcAmountDown => Msg::LfcAmountDownDesc,
+            SoundSetting::DynamicCompression => Msg::DynamicCompressionDesc,
+            SoundSetting::Restorer => Msg::RestorerDesc,
+            SoundSetting::Imax => Msg::ImaxDesc,
+            SoundSetting::Eco => Msg::EcoDesc,
+            SoundSetting::Audyssey => Msg::AudysseyDesc,
+            SoundSetting::DialogEnhancer => Msg::DialogEnhancerDesc,
+            SoundSetting::SleepTimer => Msg::SleepTimerDesc,
         }
     }
 }
@@ -62,7 +111,7 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Clear the popup area
     frame.render_widget(Clear, area);
 
-    let settings = SoundSetting::all();
+    let settings = available(app);
 
     let items: Vec<ListItem> = settings
         .iter()
@@ -71,16 +120,88 @@ pub fn render(frame: &mut Frame, app: &App) {
             let is_highlighted = i == app.sound_setting_selected;
 
             let icon = match setting {
-                SoundSetting::BassUp | SoundSetting::TrebleUp | SoundSetting::SubwooferUp => "▲",
-                SoundSetting::BassDown | SoundSetting::TrebleDown | SoundSetting::SubwooferDown => "▼",
-                SoundSetting::DynamicEq => "◐",
+                SoundSetting::Bass | SoundSetting::Treble => "⇔",
+                SoundSetting::SubwooferUp
+                | SoundSetting::LfeUp
+                | SoundSetting::BassSyncUp
+                | SoundSetting::LfcAmountUp => "▲",
+                SoundSetting::SubwooferDown
+                | SoundSetting::LfeDown
+                | SoundSetting::BassSyncDown
+                | SoundSetting::LfcAmountDown => "▼",
+                SoundSetting::LoudnessManagement | SoundSetting::Lfc => "◐",
+                SoundSetting::CinemaEq => "🎬",
+                SoundSetting::Audyssey => "◎",
+                SoundSetting::DynamicCompression => "≋",
+                SoundSetting::Restorer => "✧",
+                SoundSetting::Imax => "◆",
+                SoundSetting::Eco => "♻",
                 SoundSetting::DialogEnhancer => "💬",
+                SoundSetting::SleepTimer => "⏾",
             };
 
-            let content = format!("  {} {}  ", icon, setting.display_name());
+            let mut content = format!("  {} {}  ", icon, app.messages.get(setting.display_name()));
+
+            // Current value, for settings the AVR reports back
+            let value = match setting {
+                SoundSetting::Bass => app.avr_state.bass.map(db),
+                SoundSetting::Treble => app.avr_state.treble.map(db),
+                SoundSetting::SubwooferUp | SoundSetting::SubwooferDown => {
+                    app.avr_state.subwoofer_level.map(db)
+                }
+                SoundSetting::LfeUp | SoundSetting::LfeDown => {
+                    app.avr_state.lfe_level.map(|level| db(f32::from(level)))
+                }
+                SoundSetting::DialogEnhancer => app.avr_state.dialog_enhancer.map(|level| {
+                    if level == 0 {
+                        on_off(app, false).to_string()
+                    } else {
+                        level.to_string()
+                    }
+                }),
+                SoundSetting::Audyssey => app
+                    .avr_state
+                    .dynamic_eq
+                    .map(|on| format!("{} {}", app.messages.get(Msg::DynamicEq), on_off(app, on))),
+                SoundSetting::BassSyncUp | SoundSetting::BassSyncDown => {
+                    app.avr_state.bass_sync.map(|level| level.to_string())
+                }
+                SoundSetting::LoudnessManagement => app
+                    .avr_state
+                    .loudness_management
+                    .map(|on| on_off(app, on).to_string()),
+                SoundSetting::CinemaEq => app
+                    .avr_state
+                    .cinema_eq
+                    .map(|on| on_off(app, on).to_string()),
+                SoundSetting::Lfc => app.avr_state.lfc.map(|on| on_off(app, on).to_string()),
+                SoundSetting::LfcAmountUp | SoundSetting::LfcAmountDown => {
+                    app.avr_state.lfc_amount.map(|amount| amount.to_string())
+                }
+                SoundSetting::DynamicCompression => app
+                    .avr_state
+                    .dynamic_compression
+                    .map(|drc| drc.display_name().to_string()),
+                SoundSetting::Restorer => app
+                    .avr_state
+                    .restorer
+                    .map(|mode| mode.display_name().to_string()),
+                SoundSetting::Imax => app
+                    .avr_state
+                    .imax
+                    .map(|mode| mode.display_name().to_string()),
+                SoundSetting::Eco => app
+                    .avr_state
+                    .eco
+                    .map(|mode| mode.display_name().to_string()),
+                SoundSetting::SleepTimer => Some(ui::sleep_timer::remaining_text(app)),
+            };
+            if let Some(value) = value {
+                content.push_str(&format!("[{}]  ", value));
+            }
 
             let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                app.theme.highlight
             } else {
                 Style::default()
             };
@@ -94,11 +215,11 @@ pub fn render(frame: &mut Frame, app: &App) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(" Sound Settings ")
+                .title(format!(" {} ", app.messages.get(Msg::SoundSettingsTitle)))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().bg(Color::Black)),
+                .style(app.theme.popup),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
     frame.render_widget(list, area);
 
@@ -111,15 +232,15 @@ pub fn render(frame: &mut Frame, app: &App) {
             height: 1,
         };
 
-        let desc = Paragraph::new(setting.description())
-            .style(Style::default().fg(Color::Cyan))
+        let desc = Paragraph::new(app.messages.get(setting.description()))
+            .style(app.theme.accent)
             .alignment(Alignment::Center);
 
         frame.render_widget(desc, desc_area);
     }
 
     // Instructions
-    let instructions = " ↑/↓ Navigate  Enter Apply  Esc Cancel ";
+    let instructions = format!(" {} ", app.messages.get(Msg::SoundSettingsInstructions));
     let instructions_area = Rect {
         x: area.x,
         y: area.y + area.height - 1,
@@ -128,16 +249,54 @@ pub fn render(frame: &mut Frame, app: &App) {
     };
 
     let instructions_para = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(app.theme.dim)
         .alignment(Alignment::Center);
 
     frame.render_widget(instructions_para, instructions_area);
 }
 
-pub fn get_setting_at_index(index: usize) -> Option<SoundSetting> {
-    SoundSetting::all().get(index).copied()
+/// A level relative to 0 dB with its sign, e.g. "+2 dB" or "-0.5 dB"
+pub fn db(level: f32) -> String {
+    if level == 0.0 {
+        "0 dB".to_string()
+    } else {
+        format!("{:+} dB", level)
+    }
+}
+
+fn on_off(app: &App, on: bool) -> &'static str {
+    app.messages
+        .get(if on { Msg::SettingOn } else { Msg::SettingOff })
+}
+
+/// Settings the connected AVR supports, in menu order
+fn available(app: &App) -> Vec<SoundSetting> {
+    SoundSetting::all()
+        .iter()
+        .copied()
+        .filter(|setting| match setting {
+            SoundSetting::Restorer => app.avr_state.restorer.is_some(),
+            SoundSetting::Imax => app.avr_state.imax.is_some(),
+            SoundSetting::Eco => app.avr_state.eco.is_some(),
+            // Speaker levels don't apply while listening on headphones
+            SoundSetting::SubwooferUp
+            | SoundSetting::SubwooferDown
+            | SoundSetting::LfeUp
+            | SoundSetting::LfeDown
+            | SoundSetting::BassSyncUp
+            | SoundSetting::BassSyncDown
+            | SoundSetting::Lfc
+            | SoundSetting::LfcAmountUp
+            | SoundSetting::LfcAmountDown => !app.avr_state.headphones,
+            _ => true,
+        })
+        .collect()
+}
+
+pub fn get_setting_at_index(app: &App, index: usize) -> Option<SoundSetting> {
+    available(app).get(index).copied()
 }
 
-pub fn setting_count() -> usize {
-    SoundSetting::all().len()
+pub fn setting_count(app: &App) -> usize {
+    available(app).len()
 }
@@ -0,0 +1,112 @@
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::stats::ListeningStats;
+use crate::ui::centered_rect;
+use crate::ui::main_view::format_elapsed;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Entries shown in each top list
+const TOP_COUNT: usize = 8;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 70, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(Msg::StatsTitle)))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::vertical([
+        Constraint::Length(3), // Session and all-time totals
+        Constraint::Min(4),    // Top lists
+        Constraint::Length(1), // Instructions
+    ])
+    .split(inner);
+
+    let stats = &app.stats;
+    let summary_line = |label: Msg, counters: &ListeningStats| {
+        let listened = stats.listened(counters, app.last_tick);
+        Line::from(vec![
+            Span::styled(
+                format!("  {:<10}", app.messages.get(label)),
+                app.theme.warning,
+            ),
+            Span::raw(app.messages.format(
+                Msg::StatsSummary,
+                &[&counters.tracks_played, &format_elapsed(listened)],
+            )),
+        ])
+    };
+    let summary = Paragraph::new(vec![
+        summary_line(Msg::StatsSession, &stats.session),
+        summary_line(Msg::StatsAllTime, &stats.total),
+    ]);
+    frame.render_widget(summary, chunks[0]);
+
+    let columns = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+    render_top(
+        frame,
+        app,
+        columns[0],
+        Msg::StatsTopArtists,
+        stats.total.top_artists(TOP_COUNT),
+    );
+    render_top(
+        frame,
+        app,
+        columns[1],
+        Msg::StatsTopStations,
+        stats.total.top_stations(TOP_COUNT),
+    );
+
+    let instructions = Paragraph::new(app.messages.get(Msg::StatsInstructions))
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+    frame.render_widget(instructions, chunks[2]);
+}
+
+/// A horizontal bar chart of play counts, or a placeholder when empty
+fn render_top(frame: &mut Frame, app: &App, area: Rect, title: Msg, entries: Vec<(&str, u64)>) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(title)));
+
+    if entries.is_empty() {
+        let empty = Paragraph::new(app.messages.get(Msg::StatsEmpty))
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let bars: Vec<Bar> = entries
+        .iter()
+        .map(|(name, count)| {
+            Bar::default()
+                .value(*count)
+                .label(Line::from(name.to_string()))
+                .style(app.theme.accent)
+                .value_style(app.theme.accent.add_modifier(Modifier::REVERSED))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(block)
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(1)
+        .bar_gap(0);
+
+    frame.render_widget(chart, area);
+}
@@ -1,5 +1,6 @@
 use crate::app::App;
-use crate::heos::SurroundMode;
+use crate::heos::{SurroundMode, SurroundParameter};
+use crate::i18n::Msg;
 use crate::ui::centered_rect;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
@@ -10,6 +11,10 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Clear the popup area
     frame.render_widget(Clear, area);
 
+    let params_height = SurroundParameter::all().len() as u16 + 2;
+    let [area, params_area] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(params_height)]).areas(area);
+
     let modes = SurroundMode::all();
 
     let items: Vec<ListItem> = modes
@@ -25,9 +30,9 @@ pub fn render(frame: &mut Frame, app: &App) {
             let content = format!("{}{}", prefix, mode.display_name());
 
             let style = if is_highlighted {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                app.theme.highlight
             } else if is_current {
-                Style::default().fg(Color::Green)
+                app.theme.active
             } else {
                 Style::default()
             };
@@ -37,7 +42,7 @@ pub fn render(frame: &mut Frame, app: &App) {
         .collect();
 
     let current_mode = if app.avr_state.surround_mode.is_empty() {
-        "Unknown".to_string()
+        app.messages.get(Msg::Unknown).to_string()
     } else {
         app.avr_state.surround_mode.clone()
     };
@@ -47,16 +52,20 @@ pub fn render(frame: &mut Frame, app: &App) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(format!(" Surround Mode [{}] ", current_mode))
+                .title(format!(
+                    " {} ",
+                    app.messages
+                        .format(Msg::SurroundModeTitle, &[&current_mode])
+                ))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().bg(Color::Black)),
+                .style(app.theme.popup),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(app.theme.highlight);
 
     frame.render_widget(list, area);
 
     // Instructions
-    let instructions = " ↑/↓ Navigate  Enter Select  Esc Cancel ";
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
     let instructions_area = Rect {
         x: area.x,
         y: area.y + area.height - 1,
@@ -65,10 +74,49 @@ pub fn render(frame: &mut Frame, app: &App) {
     };
 
     let instructions_para = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(app.theme.dim)
         .alignment(Alignment::Center);
 
     frame.render_widget(instructions_para, instructions_area);
+
+    render_parameters(frame, app, params_area);
+}
+
+/// Read-only DSP parameters of the current mode, below the mode list
+fn render_parameters(frame: &mut Frame, app: &App, area: Rect) {
+    let params = &app.avr_state.surround_parameters;
+
+    let lines: Vec<Line> = if params.is_empty() {
+        vec![Line::styled(
+            app.messages.get(Msg::SurroundParametersNone),
+            app.theme.dim,
+        )]
+    } else {
+        SurroundParameter::all()
+            .iter()
+            .filter_map(|param| params.get(param).map(|value| (param, value)))
+            .map(|(param, value)| {
+                // Levels come zero-padded ("03")
+                let value = value.parse::<u8>().map_or(value.clone(), |v| v.to_string());
+                Line::from(vec![
+                    Span::styled(format!("{}: ", param.display_name()), app.theme.dim),
+                    Span::raw(value),
+                ])
+            })
+            .collect()
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(
+            " {} ",
+            app.messages.get(Msg::SurroundParametersTitle)
+        ))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 pub fn get_mode_at_index(index: usize) -> Option<SurroundMode> {
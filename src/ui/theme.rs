@@ -0,0 +1,96 @@
+use crate::config::ThemePreset;
+use ratatui::prelude::*;
+
+/// Semantic styles used by every view, resolved once from the configured preset
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Regular body text
+    pub text: Style,
+    /// Hints, labels and key legends
+    pub dim: Style,
+    /// Section headings, play icon, volume gauge
+    pub accent: Style,
+    /// Highlighted row in lists
+    pub highlight: Style,
+    /// Currently active item, connected indicators, enabled toggles
+    pub active: Style,
+    /// Key names in help, in-progress states
+    pub warning: Style,
+    /// Disconnected and muted states, errors
+    pub error: Style,
+    /// Background of popups drawn over the main view
+    pub popup: Style,
+    /// Volume gauge while muted
+    pub gauge_muted: Style,
+}
+
+impl Theme {
+    /// Same theme with a different accent color
+    pub fn with_accent(self, color: Color) -> Self {
+        Self {
+            accent: self.accent.fg(color),
+            ..self
+        }
+    }
+
+    pub fn from_preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Default => Self::default_preset(),
+            ThemePreset::HighContrast => Self::high_contrast(),
+            ThemePreset::Mono => Self::mono(),
+        }
+    }
+
+    fn default_preset() -> Self {
+        Self {
+            text: Style::default().fg(Color::White),
+            dim: Style::default().fg(Color::DarkGray),
+            accent: Style::default().fg(Color::Cyan),
+            highlight: Style::default().bg(Color::DarkGray).fg(Color::White),
+            active: Style::default().fg(Color::Green),
+            warning: Style::default().fg(Color::Yellow),
+            error: Style::default().fg(Color::Red),
+            popup: Style::default().bg(Color::Black),
+            gauge_muted: Style::default().fg(Color::DarkGray).bg(Color::Black),
+        }
+    }
+
+    fn high_contrast() -> Self {
+        Self {
+            text: Style::default().fg(Color::White),
+            dim: Style::default().fg(Color::Gray),
+            accent: Style::default().fg(Color::LightCyan),
+            highlight: Style::default()
+                .bg(Color::Yellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+            active: Style::default().fg(Color::LightGreen),
+            warning: Style::default().fg(Color::LightYellow),
+            error: Style::default().fg(Color::LightRed),
+            popup: Style::default().bg(Color::Black),
+            gauge_muted: Style::default().fg(Color::Gray).bg(Color::Black),
+        }
+    }
+
+    fn mono() -> Self {
+        Self {
+            text: Style::default(),
+            dim: Style::default(),
+            accent: Style::default().add_modifier(Modifier::BOLD),
+            highlight: Style::default().add_modifier(Modifier::REVERSED),
+            active: Style::default().add_modifier(Modifier::BOLD),
+            warning: Style::default().add_modifier(Modifier::UNDERLINED),
+            error: Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            popup: Style::default(),
+            gauge_muted: Style::default().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Gauge fill style; the accent color on a black track
+    pub fn gauge(&self) -> Style {
+        match self.accent.fg {
+            Some(fg) => Style::default().fg(fg).bg(Color::Black),
+            None => self.accent,
+        }
+    }
+}
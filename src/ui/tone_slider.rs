@@ -0,0 +1,70 @@
+use crate::app::App;
+use crate::heos::Tone;
+use crate::i18n::Msg;
+use crate::ui::{centered_rect, sound_settings};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some((tone, level)) = app.tone_slider else {
+        return;
+    };
+
+    let area = centered_rect(40, 20, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    // One notch per dB, the knob on the pending level
+    let track: String = (-Tone::LIMIT..=Tone::LIMIT)
+        .map(|db| match db {
+            _ if db == level => '●',
+            0 => '┼',
+            _ => '─',
+        })
+        .collect();
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(format!("-{} ", Tone::LIMIT), app.theme.dim),
+            Span::styled(track, app.theme.accent),
+            Span::styled(format!(" +{}", Tone::LIMIT), app.theme.dim),
+        ]),
+        Line::styled(sound_settings::db(f32::from(level)), app.theme.active),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", app.messages.get(tone_name(tone))))
+        .title_alignment(Alignment::Center)
+        .style(app.theme.popup);
+
+    let para = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(para, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::ToneSliderInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
+
+pub fn tone_name(tone: Tone) -> Msg {
+    match tone {
+        Tone::Bass => Msg::Bass,
+        Tone::Treble => Msg::Treble,
+    }
+}
@@ -0,0 +1,73 @@
+use crate::app::App;
+use crate::heos::Upmixer;
+use crate::i18n::Msg;
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, frame.area());
+
+    // Clear the popup area
+    frame.render_widget(Clear, area);
+
+    let upmixers = Upmixer::all();
+
+    let items: Vec<ListItem> = upmixers
+        .iter()
+        .enumerate()
+        .map(|(i, upmixer)| {
+            let is_highlighted = i == app.upmixer_selected;
+            let is_current = upmixer.is_active(&app.avr_state.surround_mode);
+
+            let prefix = if is_current { "● " } else { "  " };
+            let content = format!("{}{}", prefix, upmixer.display_name());
+
+            let style = if is_highlighted {
+                app.theme.highlight
+            } else if is_current {
+                app.theme.active
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", app.messages.get(Msg::UpmixerTitle)))
+                .title_alignment(Alignment::Center)
+                .style(app.theme.popup),
+        )
+        .highlight_style(app.theme.highlight);
+
+    frame.render_widget(list, area);
+
+    // Instructions
+    let instructions = format!(" {} ", app.messages.get(Msg::PopupInstructions));
+    let instructions_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let instructions_para = Paragraph::new(instructions)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(instructions_para, instructions_area);
+}
+
+pub fn get_upmixer_at_index(index: usize) -> Option<Upmixer> {
+    Upmixer::all().get(index).copied()
+}
+
+pub fn upmixer_count() -> usize {
+    Upmixer::all().len()
+}
@@ -0,0 +1,41 @@
+use crate::config::{AvrVolumeUnit, HeosVolumeUnit};
+use crate::heos::AvrVolume;
+
+/// AVR master volume, in half steps, that corresponds to 0 dB (reference level)
+const AVR_REFERENCE: i32 = 80 * 2;
+
+/// Format a HEOS player volume (0-100) in the configured unit
+pub fn heos(level: u8, unit: HeosVolumeUnit) -> String {
+    match unit {
+        HeosVolumeUnit::Percent => format!("{}%", level),
+        HeosVolumeUnit::Db => {
+            // HEOS has no dB scale; map 0-100 linearly onto the AVR's -80..0 dB range
+            if level == 0 {
+                "-∞ dB".to_string()
+            } else {
+                format!("{:.0} dB", level as f32 * 0.8 - 80.0)
+            }
+        }
+    }
+}
+
+/// Format an AVR master volume (0-98 in 0.5 steps) in the configured unit
+pub fn avr(level: AvrVolume, unit: AvrVolumeUnit) -> String {
+    match unit {
+        AvrVolumeUnit::Absolute => level.to_string(),
+        AvrVolumeUnit::Db => avr_db(level),
+        AvrVolumeUnit::Both => format!("{} ({})", level, avr_db(level)),
+    }
+}
+
+/// dB relative to reference, in 0.5 steps like the volume itself
+fn avr_db(level: AvrVolume) -> String {
+    let halves = level.0 as i32 - AVR_REFERENCE;
+    let sign = if halves < 0 { "-" } else { "" };
+    let abs = halves.abs();
+    if abs % 2 == 1 {
+        format!("{}{}.5dB", sign, abs / 2)
+    } else {
+        format!("{}{}dB", sign, abs / 2)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::app::{App, ConnectionState};
+use crate::heos::PlayState;
+use serde_json::{json, Value};
+
+/// A state change worth telling external integrations about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateEvent {
+    TrackChanged,
+    PlayStateChanged(PlayState),
+    Error(String),
+    Connected,
+    Disconnected,
+    VolumeChanged { from: u8, to: u8 },
+}
+
+impl StateEvent {
+    /// Name used in configuration and payloads
+    pub fn name(&self) -> &'static str {
+        match self {
+            StateEvent::TrackChanged => "track",
+            StateEvent::PlayStateChanged(_) => "play_state",
+            StateEvent::Error(_) => "error",
+            StateEvent::Connected => "connected",
+            StateEvent::Disconnected => "disconnected",
+            StateEvent::VolumeChanged { .. } => "volume",
+        }
+    }
+
+    /// Event details plus the current player and media, as flat string fields
+    pub fn fields(&self, app: &App) -> Value {
+        let media = &app.player_state.now_playing;
+        let message = match self {
+            StateEvent::Error(message) => message.as_str(),
+            _ => "",
+        };
+        json!({
+            "event": self.name(),
+            "player": app.current_player().map(|p| p.name.as_str()).unwrap_or_default(),
+            "state": app.player_state.play_state.as_str(),
+            "song": media.song,
+            "artist": media.artist,
+            "album": media.album,
+            "station": media.station,
+            "image_url": media.image_url,
+            "volume": app.player_state.volume.to_string(),
+            "message": message,
+        })
+    }
+}
+
+/// Turns successive snapshots of the app state into [`StateEvent`]s
+#[derive(Debug, Default)]
+pub struct StateWatcher {
+    track: String,
+    play_state: PlayState,
+    error_count: u64,
+    connected: bool,
+    volume: Option<u8>,
+}
+
+impl StateWatcher {
+    pub fn changes(&mut self, app: &App) -> Vec<StateEvent> {
+        let mut events = Vec::new();
+
+        let media = &app.player_state.now_playing;
+        let track = format!("{}\u{1f}{}\u{1f}{}", media.mid, media.song, media.station);
+        if track != self.track {
+            self.track = track;
+            if !media.song.is_empty() || !media.station.is_empty() {
+                events.push(StateEvent::TrackChanged);
+            }
+        }
+
+        let play_state = app.player_state.play_state;
+        if play_state != self.play_state {
+            self.play_state = play_state;
+            if play_state != PlayState::Unknown {
+                events.push(StateEvent::PlayStateChanged(play_state));
+            }
+        }
+
+        let connected = app.connection_state == ConnectionState::Connected;
+        if connected != self.connected {
+            self.connected = connected;
+            events.push(if connected {
+                StateEvent::Connected
+            } else {
+                StateEvent::Disconnected
+            });
+        }
+
+        // The first reading is the starting level, not a change
+        let volume = app.player_state.volume;
+        match self.volume.replace(volume) {
+            Some(from) if from != volume => {
+                events.push(StateEvent::VolumeChanged { from, to: volume })
+            }
+            _ => {}
+        }
+
+        if app.error_count != self.error_count {
+            self.error_count = app.error_count;
+            let message = app.last_error.clone().unwrap_or_default();
+            events.push(StateEvent::Error(message));
+        }
+
+        events
+    }
+}
@@ -0,0 +1,59 @@
+use crate::app::App;
+use crate::config::WebhookConfig;
+use crate::watch::StateEvent;
+use serde_json::Value;
+use std::time::Duration;
+
+/// Sends configured webhooks for state events
+pub struct Webhooks {
+    hooks: Vec<WebhookConfig>,
+}
+
+impl Webhooks {
+    pub fn new(hooks: Vec<WebhookConfig>) -> Self {
+        Self { hooks }
+    }
+
+    /// POST the event to every webhook subscribed to it, in the background
+    pub fn notify(&self, event: &StateEvent, app: &App) {
+        let fields = event.fields(app);
+        for hook in &self.hooks {
+            if !hook.wants(event.name()) {
+                continue;
+            }
+            let body = match &hook.template {
+                Some(template) => render_template(template, &fields),
+                None => fields.to_string(),
+            };
+            let url = hook.url.clone();
+            let retries = hook.retries;
+            tokio::task::spawn_blocking(move || post_with_retry(&url, &body, retries));
+        }
+    }
+}
+
+/// Replace `{{field}}` with the field's JSON-escaped string value (without quotes)
+fn render_template(template: &str, fields: &Value) -> String {
+    let mut out = template.to_string();
+    if let Some(fields) = fields.as_object() {
+        for (key, value) in fields {
+            let text = value.as_str().unwrap_or_default();
+            let escaped = Value::from(text).to_string();
+            out = out.replace(&format!("{{{{{}}}}}", key), &escaped[1..escaped.len() - 1]);
+        }
+    }
+    out
+}
+
+fn post_with_retry(url: &str, body: &str, retries: u32) {
+    for attempt in 0..=retries {
+        let result = ureq::post(url)
+            .timeout(Duration::from_secs(5))
+            .set("Content-Type", "application/json")
+            .send_string(body);
+        if result.is_ok() {
+            return;
+        }
+        std::thread::sleep(Duration::from_secs(1 << attempt.min(4)));
+    }
+}